@@ -13,7 +13,13 @@ mod private
       CommandFormerStorage
     },
     help::{ HelpGeneratorFn, HelpGeneratorOptions, HelpVariants },
+    completion::private::{ Shell, completion_generate, completion_declare, completion_command },
+    parser::tokenizer::TokenizeError,
+    parser::parser::ParserError,
+    verifier::verifier::VerifierError,
   };
+  #[ cfg( feature = "repl" ) ]
+  use ca::repl::{ self, ReplOptions };
 
   // qqq : group uses
   use std::collections::HashSet;
@@ -47,18 +53,21 @@ mod private
   pub enum ValidationError
   {
     /// This variant is used to represent parser errors.
-    /// It carries a `String` payload that provides additional information about the error.
+    /// It carries the input and the error with the position of the offending word.
     #[ error( "The following input is not recognized: `{input}`.\nDetails: {error}" ) ]
     Parser
     {
       /// source of the program
       input : String,
       /// original error
-      error : wError,
+      error : ParserError,
     },
+    /// This variant is used to represent errors of splitting an input into words, like an unterminated quote.
+    #[ error( "Can not split the input into words.\nDetails: {0}" ) ]
+    Tokenizer( TokenizeError ),
     /// This variant represents errors that occur during grammar conversion.
     #[ error( "Can not identify a command.\nDetails: {0}" ) ]
-    Verifier( wError ),
+    Verifier( VerifierError ),
     /// This variant is used to represent errors that occur during executor conversion.
     #[ error( "Can not find a routine for a command.\nDetails: {0}" ) ]
     ExecutorConverter( wError ),
@@ -115,7 +124,7 @@ mod private
   /// ```
   #[ derive( Debug ) ]
   #[ derive( former::Former ) ]
-  #[ storage_fields( help_generator : HelpGeneratorFn, help_variants : HashSet< HelpVariants >, order : Order ) ]
+  #[ storage_fields( help_generator : HelpGeneratorFn, help_variants : HashSet< HelpVariants >, order : Order, with_completion : bool ) ]
   #[ mutator( custom ) ]
   // #[ debug ]
   pub struct CommandsAggregator
@@ -147,6 +156,9 @@ mod private
       let help_generator = std::mem::take( &mut ca.help_generator ).unwrap_or_default();
       let help_variants = std::mem::take( &mut ca.help_variants ).unwrap_or_else( || HashSet::from([ HelpVariants::All ]) );
 
+      // `.completion` is declared before help is generated to be listed by `.help`, its routine is added after to complete `.help` too
+      let completion = ca.with_completion.unwrap_or_default() && completion_declare( dictionary );
+
       if help_variants.contains( &HelpVariants::All )
       {
         HelpVariants::All.generate( &help_generator, dictionary, ca.order.unwrap_or_default() );
@@ -158,6 +170,11 @@ mod private
           help.generate( &help_generator, dictionary, ca.order.unwrap_or_default() );
         }
       }
+
+      if completion
+      {
+        completion_command( dictionary );
+      }
     }
   }
 
@@ -273,10 +290,12 @@ mod private
     where
       S : IntoInput
     {
-      let Input( ref program ) = program.into_input();
+      // spans of words are kept to point at the offending word of the line
+      let ( Input( ref program ), spans ) = program.into_spanned_input().map_err( | e | Error::Validation( ValidationError::Tokenizer( e ) ) )?;
 
-      let raw_program = self.parser.parse( program ).map_err( | e | Error::Validation( ValidationError::Parser { input : format!( "{:?}", program ), error : e } ) )?;
-      let grammar_program = self.verifier.to_program( &self.dictionary, raw_program ).map_err( | e | Error::Validation( ValidationError::Verifier( e ) ) )?;
+      let ( raw_program, positions ) = Parser::parse_positioned( program.clone(), spans.as_deref() )
+      .map_err( | e | Error::Validation( ValidationError::Parser { input : format!( "{:?}", program ), error : e } ) )?;
+      let grammar_program = self.verifier.to_program_at( &self.dictionary, raw_program, &positions ).map_err( | e | Error::Validation( ValidationError::Verifier( e ) ) )?;
 
       if let Some( callback ) = &self.callback_fn
       {
@@ -285,6 +304,47 @@ mod private
 
       self.executor.program( &self.dictionary, grammar_program ).map_err( | e | Error::Execution( e ) )
     }
+
+    /// Generates the script of completion of the commands for the shell.
+    ///
+    /// The same script is printed by the built-in `.completion` command, which is added by `.with_completion( true )` of the former.
+    pub fn completion( &self, program : &str, shell : Shell ) -> String
+    {
+      completion_generate( &self.dictionary, program, shell )
+    }
+
+    /// Runs an interactive session with default options, see `repl_with`.
+    #[ cfg( feature = "repl" ) ]
+    pub fn repl( &self ) -> Result< (), wError >
+    {
+      self.repl_with( ReplOptions::former().form() )
+    }
+
+    /// Runs an interactive session : reads programs line by line and performs them until the end of the input.
+    ///
+    /// The context of the executor lives through the whole session. Commands and properties are completed on `Tab`,
+    /// a line which ends with `\` is continued by the next one and `.exit` ends the session.
+    /// Errors of programs are printed and do not end the session.
+    ///
+    /// ```no_run
+    /// use wca::{ CommandsAggregator, ReplOptions };
+    ///
+    /// # fn main() -> Result< (), Box< dyn std::error::Error > > {
+    /// let ca = CommandsAggregator::former()
+    /// .command( "echo" )
+    ///   .hint( "prints hello" )
+    ///   .routine( || println!( "hello" ) )
+    ///   .end()
+    /// .perform();
+    ///
+    /// ca.repl_with( ReplOptions::former().history( ".echo_history" ).form() )?;
+    /// # Ok( () ) }
+    /// ```
+    #[ cfg( feature = "repl" ) ]
+    pub fn repl_with( &self, options : ReplOptions ) -> Result< (), wError >
+    {
+      repl::private::repl( &self.dictionary, options, | input | self.perform( input ) )
+    }
   }
 }
 
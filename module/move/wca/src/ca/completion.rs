@@ -0,0 +1,625 @@
+mod private
+{
+  use crate::*;
+  use ca::
+  {
+    Command,
+    CommandFormer,
+    Type,
+    grammar::command::ValueDescription,
+  };
+
+  use std::fmt;
+  use std::str::FromStr;
+  use iter_tools::Itertools;
+  use error::{ err, untyped::format_err };
+
+  /// Shells completion scripts can be generated for.
+  ///
+  /// ```
+  /// # use wca::Shell;
+  /// let shell : Shell = "zsh".parse().unwrap();
+  /// assert_eq!( Shell::Zsh, shell );
+  /// ```
+  #[ derive( Debug, Clone, Copy, PartialEq, Eq, Hash ) ]
+  pub enum Shell
+  {
+    /// Bash, the script is loaded with `source`.
+    Bash,
+    /// Zsh, the script is loaded with `source` or placed into a directory of `fpath` as `_program`.
+    Zsh,
+    /// Fish, the script is piped to `source` or placed into `~/.config/fish/completions`.
+    Fish,
+  }
+
+  impl FromStr for Shell
+  {
+    type Err = error::untyped::Error;
+
+    fn from_str( s : &str ) -> Result< Self, Self::Err >
+    {
+      match s
+      {
+        "bash" => Ok( Self::Bash ),
+        "zsh" => Ok( Self::Zsh ),
+        "fish" => Ok( Self::Fish ),
+        _ => Err( err!( "Unknown shell `{}`. Expected one of : bash, zsh, fish", s ) ),
+      }
+    }
+  }
+
+  impl fmt::Display for Shell
+  {
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      match self
+      {
+        Self::Bash => write!( f, "bash" ),
+        Self::Zsh => write!( f, "zsh" ),
+        Self::Fish => write!( f, "fish" ),
+      }
+    }
+  }
+
+  /// What a value of a subject or of a property completes to.
+  #[ derive( Debug, Clone, PartialEq, Eq ) ]
+  enum Values
+  {
+    /// Nothing is known about the value.
+    Any,
+    /// Paths of the file system.
+    Files,
+    /// One of the words.
+    Words( Vec< String > ),
+  }
+
+  impl From< &Type > for Values
+  {
+    fn from( kind : &Type ) -> Self
+    {
+      match kind
+      {
+        Type::Path => Self::Files,
+        Type::Bool => Self::Words( vec![ "true".into(), "false".into() ] ),
+        Type::Enum( values ) => Self::Words( values.clone() ),
+        _ => Self::Any,
+      }
+    }
+  }
+
+  /// Property in the form scripts complete it.
+  #[ derive( Debug ) ]
+  struct PropertyCompletion
+  {
+    /// Name of the property followed by its aliases.
+    names : Vec< String >,
+    hint : String,
+    values : Values,
+  }
+
+  /// Command in the form scripts complete it.
+  #[ derive( Debug ) ]
+  struct CommandCompletion
+  {
+    /// Phrase with the leading dot.
+    phrase : String,
+    hint : String,
+    properties : Vec< PropertyCompletion >,
+    /// Any of subjects is a path.
+    files : bool,
+  }
+
+  impl CommandCompletion
+  {
+    fn new( command : &Command, order : Order ) -> Self
+    {
+      let properties = command.properties( order ).into_iter().map( | ( name, description ) |
+      {
+        let aliases = command.properties_aliases.iter()
+        .filter( | ( _, original ) | *original == name )
+        .map( | ( alias, _ ) | alias.clone() )
+        .sorted();
+        PropertyCompletion
+        {
+          names : std::iter::once( name.clone() ).chain( aliases ).collect(),
+          hint : hint_line( &description.hint ),
+          values : Values::from( &description.kind ),
+        }
+      })
+      .collect();
+
+      Self
+      {
+        phrase : format!( ".{}", command.phrase ),
+        hint : hint_line( &command.hint ),
+        properties,
+        files : command.subjects.iter().any( | ValueDescription { kind, .. } | Values::from( kind ) == Values::Files ),
+      }
+    }
+
+    /// Properties which values complete to something.
+    fn valued( &self ) -> impl Iterator< Item = &PropertyCompletion >
+    {
+      self.properties.iter().filter( | p | p.values != Values::Any )
+    }
+
+    /// Names and aliases of all properties followed by the colon.
+    fn keys( &self ) -> impl Iterator< Item = ( String, &str ) >
+    {
+      self.properties.iter().flat_map( | p | p.names.iter().map( move | n | ( format!( "{n}:" ), p.hint.as_str() ) ) )
+    }
+  }
+
+  /// Hints are shown in a single line.
+  fn hint_line( hint : &str ) -> String
+  {
+    hint.lines().next().unwrap_or_default().trim().to_string()
+  }
+
+  /// Name of the program usable in names of shell functions.
+  fn identifier( program : &str ) -> String
+  {
+    program.chars().map( | c | if c.is_ascii_alphanumeric() { c } else { '_' } ).collect()
+  }
+
+  /// Quotes the text for a single-quoted string of bash or zsh.
+  fn sh_quote( text : &str ) -> String
+  {
+    format!( "'{}'", text.replace( '\'', r"'\''" ) )
+  }
+
+  /// Quotes the text for a single-quoted string of fish.
+  fn fish_quote( text : &str ) -> String
+  {
+    format!( "'{}'", text.replace( '\\', r"\\" ).replace( '\'', r"\'" ) )
+  }
+
+  /// Generates the script of completion of commands of the dictionary for the shell.
+  ///
+  /// Scripts complete phrases of commands after `.`, names and aliases of properties of the last command followed by `:`,
+  /// `true` and `false` as values of boolean properties and paths as values of path properties and as path subjects.
+  ///
+  /// ```
+  /// # use wca::{ CommandsAggregator, Shell, Type };
+  /// let ca = CommandsAggregator::former()
+  /// .command( "echo" )
+  ///   .hint( "prints all subjects and properties" )
+  ///   .subject().hint( "file" ).kind( Type::Path ).optional( true ).end()
+  ///   .property( "verbose" ).hint( "print more" ).kind( Type::Bool ).optional( true ).end()
+  ///   .routine( || println!( "echo" ) )
+  ///   .end()
+  /// .perform();
+  ///
+  /// let script = ca.completion( "my_program", Shell::Bash );
+  /// assert!( script.contains( ".echo" ) );
+  /// assert!( script.contains( "verbose:" ) );
+  /// ```
+  pub fn completion_generate( dictionary : &Dictionary, program : &str, shell : Shell ) -> String
+  {
+    let commands : Vec< _ > = dictionary.commands().into_iter()
+    .map( | ( _, command ) | CommandCompletion::new( command, dictionary.order ) )
+    .collect();
+
+    match shell
+    {
+      Shell::Bash => bash( &commands, program ),
+      Shell::Zsh => zsh( &commands, program ),
+      Shell::Fish => fish( &commands, program ),
+    }
+  }
+
+  /// Completes the word of the line which ends at the position, the same way the generated scripts do.
+  ///
+  /// Returns the position the word starts at and candidates to replace it with.
+  ///
+  /// ```
+  /// # use wca::{ Type, ca::completion::completion_candidates };
+  /// let dictionary = wca::Dictionary::former()
+  /// .command
+  /// (
+  ///   wca::Command::former()
+  ///   .phrase( "echo" )
+  ///   .property( "verbose" ).kind( Type::Bool ).optional( true ).end()
+  ///   .form()
+  /// )
+  /// .form();
+  ///
+  /// assert_eq!( ( 0, vec![ ".echo".to_string() ] ), completion_candidates( &dictionary, ".e", 2 ) );
+  /// assert_eq!( ( 6, vec![ "verbose:".to_string() ] ), completion_candidates( &dictionary, ".echo v", 7 ) );
+  /// assert_eq!( ( 6, vec![ "verbose:true".to_string() ] ), completion_candidates( &dictionary, ".echo verbose:t", 15 ) );
+  /// ```
+  pub fn completion_candidates( dictionary : &Dictionary, line : &str, pos : usize ) -> ( usize, Vec< String > )
+  {
+    let line = &line[ ..pos ];
+    let start = line.char_indices().rev().find( | ( _, c ) | c.is_whitespace() ).map_or( 0, | ( i, c ) | i + c.len_utf8() );
+    let word = &line[ start.. ];
+    let command = line[ ..start ].split_whitespace().filter( | w | w.starts_with( '.' ) ).last();
+
+    let command = match command.and_then( | c | c.strip_prefix( '.' ) )
+    {
+      Some( phrase ) if !word.starts_with( '.' ) => phrase.to_string(),
+      _ =>
+      {
+        let phrases = dictionary.commands().into_iter()
+        .map( | ( phrase, _ ) | format!( ".{phrase}" ) )
+        .filter( | phrase | phrase.starts_with( word ) )
+        .collect();
+        return ( start, phrases );
+      }
+    };
+    let Some( command ) = dictionary.command( &command ) else { return ( start, vec![] ) };
+    let command = CommandCompletion::new( command, dictionary.order );
+
+    let candidates = match word.split_once( ':' )
+    {
+      Some( ( name, value ) ) =>
+      {
+        let values = command.properties.iter()
+        .find( | p | p.names.iter().any( | n | n == name ) )
+        .map_or( Values::Any, | p | p.values.clone() );
+        match values
+        {
+          Values::Any => vec![],
+          Values::Files => files( value ),
+          Values::Words( words ) => words.into_iter().filter( | w | w.starts_with( value ) ).collect(),
+        }
+        .into_iter()
+        .map( | value | format!( "{name}:{value}" ) )
+        .collect()
+      }
+      None =>
+      {
+        let keys = command.keys().map( | ( key, _ ) | key ).filter( | key | key.starts_with( word ) );
+        let files = if command.files { files( word ) } else { vec![] };
+        keys.chain( files ).collect()
+      }
+    };
+
+    ( start, candidates )
+  }
+
+  /// Paths which start with the prefix, directories end with the separator. Hidden files are listed only if the prefix names them.
+  fn files( prefix : &str ) -> Vec< String >
+  {
+    let ( dir, name ) = match prefix.rfind( '/' )
+    {
+      Some( i ) => ( &prefix[ ..=i ], &prefix[ i + 1.. ] ),
+      None => ( "", prefix ),
+    };
+    let Ok( entries ) = std::fs::read_dir( if dir.is_empty() { "." } else { dir } ) else { return vec![] };
+
+    entries
+    .filter_map( Result::ok )
+    .filter_map( | entry |
+    {
+      let file = entry.file_name().into_string().ok()?;
+      if !file.starts_with( name ) || ( file.starts_with( '.' ) && !name.starts_with( '.' ) )
+      {
+        return None;
+      }
+      let separator = if entry.path().is_dir() { "/" } else { "" };
+      Some( format!( "{dir}{file}{separator}" ) )
+    })
+    .sorted()
+    .collect()
+  }
+
+  const BASH : &str = r#"# bash completion of `__PROGRAM__`
+# enable it with : source <( __PROGRAM__ .completion shell:bash )
+__FUNCTION__()
+{
+  local line="${COMP_LINE:0:COMP_POINT}"
+  local cur="${line##*[[:space:]]}"
+  local command="" word name file
+  local -a words
+  read -r -a words <<< "${line%"$cur"}"
+  for word in "${words[@]}"
+  do
+    [[ "$word" == .* ]] && command="$word"
+  done
+  COMPREPLY=()
+  if [[ -z "$command" || "$cur" == .* ]]
+  then
+    COMPREPLY=( $( compgen -W "__COMMANDS__" -- "$cur" ) )
+  else
+    case "$command" in
+__CASES__    esac
+  fi
+  # a property is followed by its value
+  if [[ ${#COMPREPLY[@]} -eq 1 && "${COMPREPLY[0]}" == *: ]]
+  then
+    compopt -o nospace
+  fi
+  # `:` separates words for bash, so only the part after it is replaced
+  if [[ "$cur" == *:* && "$COMP_WORDBREAKS" == *:* ]]
+  then
+    local prefix="${cur%"${cur##*:}"}"
+    COMPREPLY=( "${COMPREPLY[@]#"$prefix"}" )
+  fi
+}
+complete -F __FUNCTION__ __PROGRAM__
+"#;
+
+  fn bash( commands : &[ CommandCompletion ], program : &str ) -> String
+  {
+    let mut cases = String::new();
+    for command in commands.iter().filter( | c | c.files || !c.properties.is_empty() )
+    {
+      cases.push_str( &format!( "      {})\n        case \"$cur\" in\n", command.phrase ) );
+      for property in command.valued()
+      {
+        let pattern = property.names.iter().map( | n | format!( "{n}:*" ) ).join( "|" );
+        let values = match &property.values
+        {
+          Values::Words( words ) => format!( "COMPREPLY=( $( compgen -P \"$name:\" -W \"{}\" -- \"${{cur#*:}}\" ) )", words.join( " " ) ),
+          _ => "while IFS= read -r file; do COMPREPLY+=( \"$name:$file\" ); done < <( compgen -f -- \"${cur#*:}\" )".to_string(),
+        };
+        cases.push_str( &format!( "          {pattern})\n            name=\"${{cur%%:*}}\"\n            {values}\n            ;;\n" ) );
+      }
+      cases.push_str( "          *)\n" );
+      if !command.properties.is_empty()
+      {
+        let keys = command.keys().map( | ( k, _ ) | k ).join( " " );
+        cases.push_str( &format!( "            COMPREPLY=( $( compgen -W \"{keys}\" -- \"$cur\" ) )\n" ) );
+      }
+      if command.files
+      {
+        cases.push_str( "            compopt -o filenames\n" );
+        cases.push_str( "            while IFS= read -r file; do COMPREPLY+=( \"$file\" ); done < <( compgen -f -- \"$cur\" )\n" );
+      }
+      cases.push_str( "            ;;\n        esac\n        ;;\n" );
+    }
+
+    BASH
+    .replace( "__FUNCTION__", &format!( "_{}_completion", identifier( program ) ) )
+    .replace( "__PROGRAM__", program )
+    .replace( "__COMMANDS__", &commands.iter().map( | c | &c.phrase ).join( " " ) )
+    .replace( "__CASES__", &cases )
+  }
+
+  const ZSH : &str = r#"#compdef __PROGRAM__
+# enable it with : source <( __PROGRAM__ .completion shell:zsh )
+
+__FUNCTION__()
+{
+  local cur="${words[CURRENT]}" command="" i
+  for (( i = CURRENT - 1; i > 1; i-- ))
+  do
+    if [[ "${words[i]}" == .* ]]
+    then
+      command="${words[i]}"
+      break
+    fi
+  done
+  if [[ -z "$command" || "$cur" == .* ]]
+  then
+    local -a commands
+    commands=(
+__COMMANDS__    )
+    _describe -t commands 'command' commands
+    return
+  fi
+  local -a properties
+  case "$command" in
+__CASES__  esac
+}
+
+if [ "$funcstack[1]" = "__FUNCTION__" ]
+then
+  __FUNCTION__ "$@"
+else
+  compdef __FUNCTION__ __PROGRAM__
+fi
+"#;
+
+  fn zsh( commands : &[ CommandCompletion ], program : &str ) -> String
+  {
+    // colons separate names from descriptions for `_describe`
+    let describe = | name : &str, hint : &str | sh_quote( &format!( "{}:{hint}", name.replace( ':', r"\:" ) ) );
+
+    let mut list = String::new();
+    for command in commands
+    {
+      list.push_str( &format!( "      {}\n", describe( &command.phrase, &command.hint ) ) );
+    }
+
+    let mut cases = String::new();
+    for command in commands.iter().filter( | c | c.files || !c.properties.is_empty() )
+    {
+      cases.push_str( &format!( "    ({})\n      case \"$cur\" in\n", command.phrase ) );
+      for property in command.valued()
+      {
+        let pattern = property.names.iter().map( | n | format!( "{n}:*" ) ).join( "|" );
+        let values = match &property.values
+        {
+          Values::Words( words ) => format!( "compadd -- {}", words.join( " " ) ),
+          _ => "_files".to_string(),
+        };
+        cases.push_str( &format!( "        ({pattern})\n          compset -P '*:'\n          {values}\n          ;;\n" ) );
+      }
+      cases.push_str( "        (*)\n" );
+      if !command.properties.is_empty()
+      {
+        cases.push_str( "          properties=(\n" );
+        for ( key, hint ) in command.keys()
+        {
+          cases.push_str( &format!( "            {}\n", describe( &key, hint ) ) );
+        }
+        cases.push_str( "          )\n          _describe -t properties 'property' properties -S ''\n" );
+      }
+      if command.files
+      {
+        cases.push_str( "          _files\n" );
+      }
+      cases.push_str( "          ;;\n      esac\n      ;;\n" );
+    }
+
+    ZSH
+    .replace( "__FUNCTION__", &format!( "_{}", identifier( program ) ) )
+    .replace( "__PROGRAM__", program )
+    .replace( "__COMMANDS__", &list )
+    .replace( "__CASES__", &cases )
+  }
+
+  const FISH : &str = r#"# fish completion of `__PROGRAM__`
+# enable it with : __PROGRAM__ .completion shell:fish | source
+
+# the last command of the line
+function __FUNCTION___command
+  set -l tokens (commandline -opc)
+  set -e tokens[1]
+  set -l command
+  for token in $tokens
+    if string match -q -- '.*' "$token"
+      set command $token
+    end
+  end
+  echo $command
+end
+
+function __FUNCTION___commands
+  set -l command (__FUNCTION___command)
+  set -l token (commandline -ct)
+  test -z "$command"; or string match -q -- '.*' "$token"
+end
+
+# the last command is the first argument and the current token matches the second one, a key of a property if it is absent
+function __FUNCTION___using -a command pattern
+  set -l current (__FUNCTION___command)
+  set -l token (commandline -ct)
+  test "$current" = "$command"; or return 1
+  if test -n "$pattern"
+    string match -q -r -- $pattern "$token"
+  else
+    not string match -q -r -- '^\.|:' "$token"
+  end
+end
+
+# arguments prefixed by the key of the current property
+function __FUNCTION___values
+  set -l token (commandline -ct)
+  set -l name (string split -m 1 : -- "$token")[1]
+  for value in $argv
+    echo "$name:$value"
+  end
+end
+
+function __FUNCTION___files
+  set -l token (commandline -ct)
+  set -l name (string split -m 1 : -- "$token")[1]
+  for file in (__fish_complete_path (string replace -r -- '^[^:]*:' '' "$token"))
+    echo "$name:$file"
+  end
+end
+
+complete -c __PROGRAM__ -f
+__COMPLETES__"#;
+
+  fn fish( commands : &[ CommandCompletion ], program : &str ) -> String
+  {
+    let function = format!( "__{}", identifier( program ) );
+    let complete = | condition : String, arguments : String | format!( "complete -c {program} -n {} {arguments}\n", fish_quote( &condition ) );
+
+    let mut completes = String::new();
+    for command in commands
+    {
+      completes.push_str( &complete( format!( "{function}_commands" ), format!( "-a {} -d {}", fish_quote( &command.phrase ), fish_quote( &command.hint ) ) ) );
+    }
+    for command in commands
+    {
+      let using = format!( "{function}_using {}", command.phrase );
+      for ( key, hint ) in command.keys()
+      {
+        completes.push_str( &complete( using.clone(), format!( "-a {} -d {}", fish_quote( &key ), fish_quote( hint ) ) ) );
+      }
+      for property in command.valued()
+      {
+        let condition = format!( "{using} '^({}):'", property.names.iter().join( "|" ) );
+        let values = match &property.values
+        {
+          Values::Words( words ) => format!( "({function}_values {})", words.join( " " ) ),
+          _ => format!( "({function}_files)" ),
+        };
+        completes.push_str( &complete( condition, format!( "-a {}", fish_quote( &values ) ) ) );
+      }
+      if command.files
+      {
+        completes.push_str( &complete( using, "-F".to_string() ) );
+      }
+    }
+
+    FISH
+    .replace( "__FUNCTION__", &function )
+    .replace( "__PROGRAM__", program )
+    .replace( "__COMPLETES__", &completes )
+  }
+
+  /// Description of the `.completion` command without a routine.
+  fn completion_former() -> CommandFormer
+  {
+    Command::former()
+    .hint( "prints a script of completion of commands for a shell" )
+    .long_hint( "prints a script of completion of commands for a shell\n  enable it with `source <( program .completion shell:bash )` or `program .completion shell:fish | source`" )
+    .property( "shell" )
+      .hint( "bash, zsh or fish" )
+      .kind( Type::String )
+      .optional( false )
+      .end()
+    .property( "program" )
+      .hint( "name of the completed program, the name of the executable if not specified" )
+      .kind( Type::String )
+      .optional( true )
+      .end()
+    .phrase( "completion" )
+  }
+
+  /// Registers the description of the `.completion` command without a routine, so commands generated from the dictionary know about it.
+  ///
+  /// Returns `false` and registers nothing if the dictionary already has a command with such a phrase.
+  pub( crate ) fn completion_declare( dictionary : &mut Dictionary ) -> bool
+  {
+    if dictionary.command( &"completion".to_string() ).is_some()
+    {
+      return false;
+    }
+    dictionary.register( completion_former().form() );
+
+    true
+  }
+
+  /// Registers the `.completion` command which prints the script of completion of all commands of the dictionary, the command itself included.
+  ///
+  /// Replaces the description registered by `completion_declare`.
+  pub( crate ) fn completion_command( dictionary : &mut Dictionary )
+  {
+    let grammar = dictionary.clone();
+
+    let routine = move | o : VerifiedCommand |
+    {
+      let shell : String = o.props.get_owned( "shell" ).ok_or_else( || format_err!( "Property `shell` is required. Expected one of : bash, zsh, fish" ) )?;
+      let shell : Shell = shell.parse()?;
+      let program = match o.props.get_owned::< _, String >( "program" )
+      {
+        Some( program ) => program,
+        None => std::env::current_exe()
+        .ok()
+        .and_then( | path | path.file_stem().map( | name | name.to_string_lossy().to_string() ) )
+        .ok_or_else( || format_err!( "Can not get the name of the program. Specify it with the `program` property" ) )?,
+      };
+      print!( "{}", completion_generate( &grammar, &program, shell ) );
+
+      Ok::< _, error::untyped::Error >( () )
+    };
+
+    dictionary.register( completion_former().routine( routine ).form() );
+  }
+}
+
+//
+
+crate::mod_interface!
+{
+  exposed use Shell;
+  own use completion_generate;
+  own use completion_candidates;
+}
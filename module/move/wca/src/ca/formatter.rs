@@ -55,7 +55,7 @@ mod private
       .map
       (
         |( number, subj )|
-        format!( "\n- {}subject_{number} - {} `[{:?}]`", if subj.optional { "`< optional >` " } else { "" }, subj.hint, subj.kind )
+        format!( "\n- {}subject_{number} - {} `[{}]`", if subj.optional { "`< optional >` " } else { "" }, subj.hint, subj.kind )
       )
       .join( "\n" );
       let full_properties = cmd
@@ -64,7 +64,7 @@ mod private
       .map
       (
         |( name, value )|
-        format!( "\n- {}{} - {} `[{:?}]`", if value.optional { "`< optional >` " } else { "" }, value.hint, name, value.kind )
+        format!( "\n- {}{} - {} `[{}]`", if value.optional { "`< optional >` " } else { "" }, value.hint, name, value.kind )
       )
       .join( "\n" );
       // aaa : for Bohdan : toooooo log lines. 130 is max
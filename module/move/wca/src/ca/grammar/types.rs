@@ -6,9 +6,14 @@ mod private
     Display,
     Formatter
   };
+  use std::collections::HashMap;
+  use std::hash::Hash;
+  use std::sync::Arc;
+  #[ cfg( feature = "type_duration" ) ]
+  use std::time::Duration;
   // use wtools;
   // use wtools::{ error::Result, err };
-  use error::err;
+  use error::{ err, untyped::format_err };
   use iter_tools::Itertools;
 
   /// Available types that can be converted to a `Value`
@@ -29,6 +34,7 @@ mod private
   /// In the above example, the `Type` enum is used to represent the expected type of the value for a property. The `Number` type is chosen, and the raw value is parsed and validated to ensure it matches this type.
   ///
   #[ derive( Debug, Clone, PartialEq, Eq ) ]
+  #[ non_exhaustive ]
   pub enum Type
   {
     /// String
@@ -41,15 +47,153 @@ mod private
     Bool,
     /// List of some type values separated a delimiter character
     List( Box< Type >, char ),
+    /// One of the values, the help and the completion list them
+    Enum( Vec< String > ),
+    /// Signed integer, bounds are inclusive
+    Int
+    {
+      /// The least value
+      min : Option< i64 >,
+      /// The greatest value
+      max : Option< i64 >,
+    },
+    /// Unsigned integer, bounds are inclusive
+    UInt
+    {
+      /// The least value
+      min : Option< u64 >,
+      /// The greatest value
+      max : Option< u64 >,
+    },
+    /// Duration like `90s`, `1h 30m` or `2days`, requires the `type_duration` feature
+    #[ cfg( feature = "type_duration" ) ]
+    Duration,
+    /// Absolute URL, requires the `type_url` feature
+    #[ cfg( feature = "type_url" ) ]
+    Url,
+    /// Pairs `key=value` of some types separated by commas
+    Map( Box< Type >, Box< Type > ),
+    /// Type defined by the user
+    Custom( CustomType ),
+  }
+
+  impl Display for Type
+  {
+    fn fmt( &self, f : &mut Formatter< '_ > ) -> std::fmt::Result
+    {
+      match self
+      {
+        Self::String => write!( f, "String" ),
+        Self::Number => write!( f, "Number" ),
+        Self::Path => write!( f, "Path" ),
+        Self::Bool => write!( f, "Bool" ),
+        Self::List( kind, delimeter ) => write!( f, "List({kind}, {delimeter:?})" ),
+        Self::Enum( values ) => write!( f, "Enum({})", values.join( "|" ) ),
+        Self::Int { min, max } => write!( f, "Int{}", bounds( min, max ) ),
+        Self::UInt { min, max } => write!( f, "UInt{}", bounds( min, max ) ),
+        #[ cfg( feature = "type_duration" ) ]
+        Self::Duration => write!( f, "Duration" ),
+        #[ cfg( feature = "type_url" ) ]
+        Self::Url => write!( f, "Url" ),
+        Self::Map( key, value ) => write!( f, "Map({key}, {value})" ),
+        Self::Custom( custom ) => write!( f, "{}", custom.name ),
+      }
+    }
+  }
+
+  /// Bounds of an integer as a range, empty if there are none.
+  fn bounds< T : Display >( min : &Option< T >, max : &Option< T > ) -> String
+  {
+    match ( min, max )
+    {
+      ( None, None ) => String::new(),
+      ( min, max ) => format!
+      (
+        "({}..{})",
+        min.as_ref().map( ToString::to_string ).unwrap_or_default(),
+        max.as_ref().map( | max | format!( "={max}" ) ).unwrap_or_default(),
+      ),
+    }
   }
 
   /// Can be implemented for something that represents a type of value
+  ///
+  /// Implement it for `Value` to describe a type of your own, see `CustomType`.
   pub trait TryCast< T >
   {
     /// return casted value
     fn try_cast( &self, value : String ) -> error::untyped::Result< T >;
   }
 
+  /// Type of values defined by the user.
+  ///
+  /// ```
+  /// # use wca::{ Type, Value, TryCast, CustomType };
+  /// use wca::tool::error::untyped::{ Result, format_err };
+  ///
+  /// struct Even;
+  ///
+  /// impl TryCast< Value > for Even
+  /// {
+  ///   fn try_cast( &self, value : String ) -> Result< Value >
+  ///   {
+  ///     match value.parse::< i64 >()
+  ///     {
+  ///       Ok( n ) if n % 2 == 0 => Ok( Value::Int( n ) ),
+  ///       _ => Err( format_err!( "`{value}` is not an even number" ) ),
+  ///     }
+  ///   }
+  /// }
+  ///
+  /// let kind = Type::Custom( CustomType::new( "Even", Even ) );
+  /// assert_eq!( Value::Int( 4 ), kind.try_cast( "4".into() ).unwrap() );
+  /// assert!( kind.try_cast( "5".into() ).is_err() );
+  /// assert_eq!( "Even", kind.to_string() );
+  /// ```
+  #[ derive( Clone ) ]
+  pub struct CustomType
+  {
+    name : String,
+    cast : Arc< dyn TryCast< Value > + Send + Sync >,
+  }
+
+  impl CustomType
+  {
+    /// Type with the name the help shows and the cast of raw values.
+    pub fn new< Name, Cast >( name : Name, cast : Cast ) -> Self
+    where
+      Name : Into< String >,
+      Cast : TryCast< Value > + Send + Sync + 'static,
+    {
+      Self { name : name.into(), cast : Arc::new( cast ) }
+    }
+
+    /// Name of the type.
+    pub fn name( &self ) -> &str
+    {
+      &self.name
+    }
+  }
+
+  impl std::fmt::Debug for CustomType
+  {
+    fn fmt( &self, f : &mut Formatter< '_ > ) -> std::fmt::Result
+    {
+      f.debug_tuple( "CustomType" ).field( &self.name ).finish()
+    }
+  }
+
+  // types are the same if they have the same name and share the cast
+  impl PartialEq for CustomType
+  {
+    fn eq( &self, other : &Self ) -> bool
+    {
+      self.name == other.name && Arc::as_ptr( &self.cast ).cast::< () >() == Arc::as_ptr( &other.cast ).cast::< () >()
+    }
+  }
+
+  impl Eq for CustomType {}
+
   /// Container for a `Value` of a specific type
   ///
   /// Uses for represent of subjects and properties in Commands( E.g. `VerifiedCommand`, `ExecutableCommand_` )
@@ -81,6 +225,7 @@ mod private
   /// assert_eq!( 3, number );
   /// ```
   #[ derive( Debug, Clone, PartialEq ) ]
+  #[ non_exhaustive ]
   pub enum Value
   {
     /// String value
@@ -93,6 +238,18 @@ mod private
     Bool( bool ),
     /// List
     List( Vec< Value > ),
+    /// Signed integer
+    Int( i64 ),
+    /// Unsigned integer
+    UInt( u64 ),
+    /// Duration
+    #[ cfg( feature = "type_duration" ) ]
+    Duration( Duration ),
+    /// URL
+    #[ cfg( feature = "type_url" ) ]
+    Url( url::Url ),
+    /// Pairs of keys and values in the order of the input
+    Map( Vec< ( Value, Value ) > ),
   }
 
   impl Display for Value
@@ -122,6 +279,29 @@ mod private
           let list = list.iter().map( | element | element.to_string() ).join( "," ); // qqq : don't hardcode ", " find way to get original separator
           write!( f, "{list}" )?;
         }
+        Value::Int( n ) =>
+        {
+          write!( f, "{n}" )?;
+        }
+        Value::UInt( n ) =>
+        {
+          write!( f, "{n}" )?;
+        }
+        #[ cfg( feature = "type_duration" ) ]
+        Value::Duration( d ) =>
+        {
+          write!( f, "{}", humantime::format_duration( *d ) )?;
+        }
+        #[ cfg( feature = "type_url" ) ]
+        Value::Url( url ) =>
+        {
+          write!( f, "{url}" )?;
+        }
+        Value::Map( map ) =>
+        {
+          let map = map.iter().map( |( key, value )| format!( "{key}={value}" ) ).join( "," );
+          write!( f, "{map}" )?;
+        }
       }
       Ok( () )
     }
@@ -148,16 +328,34 @@ mod private
     };
   }
 
+  // numbers are casted from any numeric value
+  macro_rules! number_into_impl
+  {
+    ( $( $kind : ty ),+ ) =>
+    {
+      $(
+        impl From< Value > for $kind
+        {
+          fn from( value : Value ) -> Self
+          {
+            match value
+            {
+              Value::Number( value ) => value as $kind,
+              Value::Int( value ) => value as $kind,
+              Value::UInt( value ) => value as $kind,
+              _ => panic!( "Unknown cast variant. Got `{value:?}` and try to cast to `{}`", stringify!( $kind ) )
+            }
+          }
+        }
+      )+
+    };
+  }
+
+  number_into_impl!( u32, u64, usize, i32, i64, isize, f32, f64 );
+
   // makes from Value variant an native value
   value_into_impl!
   {
-    Value::Number =>
-      u32 => | value | value as u32,
-      u64 => | value | value as u64,
-      i32 => | value | value as i32,
-      i64 => | value | value as i64,
-      f32 => | value | value as f32,
-      f64 => | value | value;
     Value::Bool =>
       bool => | value | value;
     Value::String =>
@@ -167,6 +365,20 @@ mod private
       std::path::PathBuf => | value | value
   }
 
+  #[ cfg( feature = "type_duration" ) ]
+  value_into_impl!
+  {
+    Value::Duration =>
+      Duration => | value | value
+  }
+
+  #[ cfg( feature = "type_url" ) ]
+  value_into_impl!
+  {
+    Value::Url =>
+      url::Url => | value | value
+  }
+
   impl< T : From< Value > > From< Value > for Vec< T >
   {
     fn from( value : Value ) -> Self
@@ -179,6 +391,31 @@ mod private
     }
   }
 
+  impl< K, V > From< Value > for HashMap< K, V >
+  where
+    K : From< Value > + Eq + Hash,
+    V : From< Value >,
+  {
+    fn from( value : Value ) -> Self
+    {
+      match value
+      {
+        Value::Map( value ) => value.into_iter().map( |( k, v )| ( k.into(), v.into() ) ).collect(),
+        _ => panic!( "Unknown cast variant. Got `{value:?}` and try to cast to `HashMap<{}, {}>`", std::any::type_name::< K >(), std::any::type_name::< V >() )
+      }
+    }
+  }
+
+  /// Checks that the integer is within the inclusive bounds.
+  fn in_bounds< T : PartialOrd + Display >( value : T, min : &Option< T >, max : &Option< T > ) -> error::untyped::Result< T >
+  {
+    if min.as_ref().is_some_and( | min | value < *min ) || max.as_ref().is_some_and( | max | value > *max )
+    {
+      return Err( err!( "`{}` is out of range {}", value, bounds( min, max ).trim_start_matches( '(' ).trim_end_matches( ')' ) ) );
+    }
+    Ok( value )
+  }
+
   impl TryCast< Value > for Type
   {
     fn try_cast( &self, value : String ) -> error::untyped::Result< Value >
@@ -198,6 +435,36 @@ mod private
           // qqq : avoid using fish notation whenever possible. review whole crate
           Ok( Value::List( values ) )
         },
+        Self::Enum( values ) if values.contains( &value ) => Ok( Value::String( value ) ),
+        Self::Enum( values ) => Err( err!( "`{}` is not one of: {}", value, values.join( ", " ) ) ),
+        Self::Int { min, max } =>
+        {
+          let n = value.parse().map_err( | _ | format_err!( "Can not parse integer from `{}`", value ) )?;
+          in_bounds( n, min, max ).map( Value::Int )
+        },
+        Self::UInt { min, max } =>
+        {
+          let n = value.parse().map_err( | _ | format_err!( "Can not parse unsigned integer from `{}`", value ) )?;
+          in_bounds( n, min, max ).map( Value::UInt )
+        },
+        #[ cfg( feature = "type_duration" ) ]
+        Self::Duration => humantime::parse_duration( &value ).map_err( | e | err!( "Can not parse duration from `{}`: {}", value, e ) ).map( Value::Duration ),
+        #[ cfg( feature = "type_url" ) ]
+        Self::Url => url::Url::parse( &value ).map_err( | e | err!( "Can not parse URL from `{}`: {}", value, e ) ).map( Value::Url ),
+        Self::Map( key, kind ) =>
+        {
+          let pairs = value
+          .split( ',' )
+          .filter( | pair | !pair.is_empty() )
+          .map( | pair |
+          {
+            let ( k, v ) = pair.split_once( '=' ).ok_or_else( || format_err!( "Expected `key=value`, found `{}`", pair ) )?;
+            Ok( ( key.try_cast( k.into() )?, kind.try_cast( v.into() )? ) )
+          })
+          .collect::< error::untyped::Result< Vec< _ > > >()?;
+          Ok( Value::Map( pairs ) )
+        },
+        Self::Custom( custom ) => custom.cast.try_cast( value ),
       }
     }
   }
@@ -210,4 +477,5 @@ crate::mod_interface!
   exposed use Type;
   exposed use Value;
   exposed use TryCast;
+  exposed use CustomType;
 }
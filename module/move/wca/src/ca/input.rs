@@ -1,5 +1,9 @@
 mod private
 {
+  use crate::*;
+  use ca::parser::tokenizer::{ tokenize, TokenizeError };
+
+  use core::ops::Range;
   use std::io;
   use std::io::Write;
 
@@ -30,44 +34,68 @@ mod private
   {
     /// Converts the implementing type into an `Input` instance.
     ///
+    /// Strings are split into words by `tokenize`, so quotes and backslashes keep whitespaces inside of a word.
+    ///
     /// # Examples
     ///
     /// Basic usage:
     ///
     /// ```
-    /// use wca::IntoInput;
+    /// use wca::{ Input, IntoInput };
     ///
     /// let string_input: &str = "example string";
-    /// let input_struct = string_input.into_input();
+    /// let input_struct = string_input.into_input().unwrap();
     ///
-    /// let owned_string_input: String = "owned example".to_string();
-    /// let owned_input_struct = owned_string_input.into_input();
+    /// let owned_string_input: String = r#"owned "quoted example""#.to_string();
+    /// let Input( words ) = owned_string_input.into_input().unwrap();
+    /// assert_eq!( vec![ "owned", "quoted example" ], words );
     /// ```
-    fn into_input( self ) -> Input;
+    fn into_input( self ) -> Result< Input, TokenizeError >;
+
+    /// Converts the implementing type into an `Input` instance along with spans of the words in the original string.
+    ///
+    /// Spans are unknown if the words are not split from a string.
+    fn into_spanned_input( self ) -> Result< ( Input, Option< Vec< Range< usize > > > ), TokenizeError >
+    where
+      Self : Sized,
+    {
+      Ok( ( self.into_input()?, None ) )
+    }
   }
 
   impl IntoInput for &str
   {
+    fn into_input( self ) -> Result< Input, TokenizeError >
+    {
+      self.into_spanned_input().map( |( input, _ )| input )
+    }
 
-    fn into_input( self ) -> Input
+    fn into_spanned_input( self ) -> Result< ( Input, Option< Vec< Range< usize > > > ), TokenizeError >
     {
-      Input( self.split( ' ' ).map( ToString::to_string ).collect() )
+      let ( words, spans ) = tokenize( self )?.into_iter().map( | token | ( token.value, token.span ) ).unzip();
+      Ok( ( Input( words ), Some( spans ) ) )
     }
   }
 
   impl IntoInput for String
   {
-    fn into_input( self ) -> Input
+    fn into_input( self ) -> Result< Input, TokenizeError >
+    {
+      self.as_str().into_input()
+    }
+
+    fn into_spanned_input( self ) -> Result< ( Input, Option< Vec< Range< usize > > > ), TokenizeError >
     {
-      Input( self.split( ' ' ).map( ToString::to_string ).collect() )
+      self.as_str().into_spanned_input()
     }
   }
 
+  // words are already split, by a shell for example
   impl IntoInput for Vec< String >
   {
-    fn into_input( self ) -> Input
+    fn into_input( self ) -> Result< Input, TokenizeError >
     {
-      Input( self )
+      Ok( Input( self ) )
     }
   }
 
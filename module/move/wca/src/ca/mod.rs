@@ -26,5 +26,10 @@ crate::mod_interface!
   layer help;
   /// Responsible for generating Markdown formatted documentation for commands
   layer formatter;
+  /// Generates scripts of completion of commands for shells.
+  layer completion;
+  /// Interactive session which reads and performs programs line by line.
+  #[ cfg( feature = "repl" ) ]
+  layer repl;
 
 }
@@ -10,4 +10,7 @@ crate::mod_interface!
   /// program containing multiple parsed commands. The input list of arguments is transformed into a structured format,
   /// allowing the program to efficiently handle and manipulate the parsed commands.
   layer parser;
+
+  /// Splits a line of input into words, handling quotes and escapes the way a shell does.
+  layer tokenizer;
 }
@@ -3,8 +3,95 @@ mod private
   use crate::*;
 
   use std::collections::HashMap;
+  use core::{ fmt, ops::Range };
 
-  use error::{ return_err };
+  use error::for_lib::*;
+  use ca::parser::tokenizer::{ tokenize, TokenizeError };
+
+  /// Position of a word of an input.
+  #[ derive( Debug, Clone, PartialEq, Eq ) ]
+  pub struct WordPosition
+  {
+    /// Index of the word.
+    pub index : usize,
+    /// Bytes of the line the word is taken from. Known if the parser splits the line into words itself.
+    pub span : Option< Range< usize > >,
+  }
+
+  impl fmt::Display for WordPosition
+  {
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      match &self.span
+      {
+        Some( span ) => write!( f, "at position {}", span.start ),
+        None => write!( f, "at word {}", self.index ),
+      }
+    }
+  }
+
+  /// Errors of parsing.
+  #[ derive( Error, Debug, Clone, PartialEq, Eq ) ]
+  pub enum ParserError
+  {
+    /// A program starts with a word which is not a command.
+    #[ error( "Unexpected input: Expected a command, found: `{word}` {position}" ) ]
+    CommandExpected
+    {
+      /// The word.
+      word : String,
+      /// Where the word is.
+      position : WordPosition,
+    },
+    /// A property is not followed by a value.
+    #[ error( "Unexpected input '{name}' {position}: Detected a possible property key preceding the ':' character. However, no corresponding value was found." ) ]
+    PropertyValueMissing
+    {
+      /// Name of the property.
+      name : String,
+      /// Where the name is.
+      position : WordPosition,
+    },
+    /// A subject follows properties.
+    #[ error( "Unexpected input: Expected `command` or `property`, found: `{word}` {position}" ) ]
+    Unexpected
+    {
+      /// The word.
+      word : String,
+      /// Where the word is.
+      position : WordPosition,
+    },
+    /// A line can not be split into words.
+    #[ error( "{0}" ) ]
+    Tokenizer( TokenizeError ),
+  }
+
+  impl ParserError
+  {
+    /// Position of the offending word, unknown if the line can not be split into words.
+    pub fn position( &self ) -> Option< &WordPosition >
+    {
+      match self
+      {
+        Self::CommandExpected { position, .. } | Self::PropertyValueMissing { position, .. } | Self::Unexpected { position, .. } => Some( position ),
+        Self::Tokenizer( _ ) => None,
+      }
+    }
+
+    fn with_spans( mut self, spans : &[ Range< usize > ] ) -> Self
+    {
+      if let Self::CommandExpected { position, .. } | Self::PropertyValueMissing { position, .. } | Self::Unexpected { position, .. } = &mut self
+      {
+        position.span = spans.get( position.index ).cloned();
+      }
+      self
+    }
+  }
+
+  fn at( index : usize ) -> WordPosition
+  {
+    WordPosition { index, span : None }
+  }
 
   /// `Parser` is a struct used for parsing data.
   #[ derive( Debug ) ]
@@ -21,23 +108,45 @@ mod private
     /// # Returns
     ///
     /// Returns a `Result` with a `Program` containing the parsed commands if successful, or an error if parsing fails.
-    // qqq : use typed error
-    pub fn parse< As, A >( &self, args : As ) -> error::untyped::Result< Program< ParsedCommand > >
+    pub fn parse< As, A >( &self, args : As ) -> Result< Program< ParsedCommand >, ParserError >
     where
       As : IntoIterator< Item = A >,
       A : Into< String >,
     {
-      let args = args.into_iter().map( Into::into ).collect::< Vec< _ > >();
+      Self::parse_positioned( args.into_iter().map( Into::into ).collect(), None ).map( |( program, _ )| program )
+    }
+
+    /// Splits the line into words with `tokenize` and parses them, see `parse`. Positions of errors have spans of words in the line.
+    ///
+    /// ```
+    /// use wca::Parser;
+    ///
+    /// let program = Parser.parse_line( r#".config.add path:"my feeds.toml""# ).unwrap();
+    /// assert_eq!( "my feeds.toml", program.commands[ 0 ].properties[ "path" ] );
+    /// ```
+    pub fn parse_line( &self, line : &str ) -> Result< Program< ParsedCommand >, ParserError >
+    {
+      let tokens = tokenize( line ).map_err( ParserError::Tokenizer )?;
+      let spans : Vec< _ > = tokens.iter().map( | token | token.span.clone() ).collect();
+      Self::parse_positioned( tokens.into_iter().map( | token | token.value ).collect(), Some( &spans ) ).map( |( program, _ )| program )
+    }
+
+    /// Parses the words like `parse` and returns positions of names of the commands as well. Positions have spans if spans of the words are given.
+    pub( crate ) fn parse_positioned( args : Vec< String >, spans : Option< &[ Range< usize > ] > )
+    -> Result< ( Program< ParsedCommand >, Vec< WordPosition > ), ParserError >
+    {
       let mut commands = vec![];
+      let mut positions = vec![];
       let mut i = 0;
       while i < args.len()
       {
-        let ( command, relative_pos ) = Self::parse_command( &args[ i.. ] )?;
+        let ( command, relative_pos ) = Self::parse_command( &args, i ).map_err( | e | e.with_spans( spans.unwrap_or_default() ) )?;
+        positions.push( WordPosition { index : i, span : spans.and_then( | spans | spans.get( i ).cloned() ) } );
         i += relative_pos;
         commands.push( command );
       }
 
-      Ok( Program { commands } )
+      Ok( ( Program { commands }, positions ) )
     }
 
     // with dot at the beginning
@@ -53,19 +162,15 @@ mod private
       }
     }
 
-    // returns ParsedCommand and relative position of the last parsed item
-    // qqq : use typed error
-    fn parse_command( args : &[ String ] ) -> error::untyped::Result< ( ParsedCommand, usize ) >
+    // parses the command which starts at the word `start`, returns ParsedCommand and relative position of the last parsed item
+    fn parse_command( all_args : &[ String ], start : usize ) -> Result< ( ParsedCommand, usize ), ParserError >
     {
-      if args.is_empty() {
-        return_err!( "Unexpected behaviour: Try to parse command without input" );
-      }
-
+      let args = &all_args[ start.. ];
       let mut i = 0;
 
       if !Self::valid_command_name( &args[ i ] )
       {
-        return_err!( "Unexpected input: Expected a command, found: `{}`", args[ i ] );
+        return Err( ParserError::CommandExpected { word : args[ i ].clone(), position : at( start ) } );
       }
       let name = match args[ i ].strip_prefix( '.' ).unwrap()
       {
@@ -74,7 +179,7 @@ mod private
         other => other,
       };
       i += 1;
-      let ( subjects, properties, relative_pos ) = Self::parse_command_args( &args[ i .. ] )?;
+      let ( subjects, properties, relative_pos ) = Self::parse_command_args( all_args, start + i )?;
 
       i += relative_pos;
 
@@ -90,10 +195,10 @@ mod private
       ))
     }
 
-    // returns ( subjects, properties, relative_end_pos )
-    // qqq : use typed error
-    fn parse_command_args( args : &[ String ] ) -> error::untyped::Result< ( Vec< String >, HashMap< String, String >, usize ) >
+    // parses arguments which start at the word `start`, returns ( subjects, properties, relative_end_pos )
+    fn parse_command_args( all_args : &[ String ], start : usize ) -> Result< ( Vec< String >, HashMap< String, String >, usize ), ParserError >
     {
+      let args = &all_args[ start.. ];
       let mut i = 0;
 
       let mut subjects = vec![];
@@ -125,7 +230,7 @@ mod private
           // prop:
           else
           {
-            return_err!( "Unexpected input '{}': Detected a possible property key preceding the ':' character. However, no corresponding value was found.", item );
+            return Err( ParserError::PropertyValueMissing { name : name.to_string(), position : at( start + i ) } );
           }
         }
         // prop : value | prop :value
@@ -146,13 +251,13 @@ mod private
           // :
           else
           {
-            return_err!( "Unexpected input '{} :': Detected a possible property key preceding the ':' character. However, no corresponding value was found.", item );
+            return Err( ParserError::PropertyValueMissing { name : item.clone(), position : at( start + i ) } );
           }
         }
 
         else if !properties_turn { subjects.push( item.to_string() ); }
 
-        else { return_err!( "Unexpected input: Expected `command` or `property`, found: `{}`", item ); }
+        else { return Err( ParserError::Unexpected { word : item.clone(), position : at( start + i ) } ); }
         i += 1;
       }
 
@@ -166,4 +271,6 @@ mod private
 crate::mod_interface!
 {
   exposed use Parser;
+  exposed use ParserError;
+  exposed use WordPosition;
 }
@@ -0,0 +1,124 @@
+mod private
+{
+  use crate::*;
+
+  use core::ops::Range;
+  use error::for_lib::*;
+
+  /// A word of an input with quotes and escapes resolved.
+  #[ derive( Debug, Clone, PartialEq, Eq ) ]
+  pub struct Token
+  {
+    /// The word.
+    pub value : String,
+    /// Bytes of the input the word is taken from, quotes and backslashes included.
+    pub span : Range< usize >,
+  }
+
+  /// Errors of splitting an input into words.
+  #[ derive( Error, Debug, Clone, PartialEq, Eq ) ]
+  pub enum TokenizeError
+  {
+    /// A quote is not closed till the end of the input.
+    #[ error( "Unterminated quote `{quote}` opened at position {position}" ) ]
+    UnterminatedQuote
+    {
+      /// The quote, `"` or `'`.
+      quote : char,
+      /// Byte of the input the quote is at.
+      position : usize,
+    },
+    /// The input ends with a backslash.
+    #[ error( "Backslash at position {position} escapes nothing" ) ]
+    TrailingEscape
+    {
+      /// Byte of the input the backslash is at.
+      position : usize,
+    },
+  }
+
+  /// Splits the input into words the way a shell does.
+  ///
+  /// - words are separated by whitespaces;
+  /// - a backslash escapes the next character, a whitespace or a quote among others;
+  /// - nothing is special between single quotes;
+  /// - between double quotes a backslash escapes only `"` and `\`, so Windows paths stay as is;
+  /// - quoted and unquoted parts of a word are joined, so `path:"my feeds.toml"` is the single word `path:my feeds.toml`.
+  ///
+  /// ```
+  /// use wca::ca::parser::tokenizer::tokenize;
+  ///
+  /// let words : Vec< _ > = tokenize( r#".config.add path:"my feeds.toml" 'a:b' c\ d"# ).unwrap().into_iter().map( | t | t.value ).collect();
+  /// assert_eq!( vec![ ".config.add", "path:my feeds.toml", "a:b", "c d" ], words );
+  ///
+  /// assert!( tokenize( r#".config.add path:"my feeds.toml"# ).is_err() );
+  /// ```
+  pub fn tokenize( input : &str ) -> Result< Vec< Token >, TokenizeError >
+  {
+    let mut tokens = vec![];
+    let mut chars = input.char_indices().peekable();
+    // the word being read and the byte it starts at
+    let mut current : Option< ( String, usize ) > = None;
+
+    while let Some( ( i, c ) ) = chars.next()
+    {
+      if c.is_whitespace()
+      {
+        if let Some( ( value, start ) ) = current.take()
+        {
+          tokens.push( Token { value, span : start..i } );
+        }
+        continue;
+      }
+
+      let ( value, _ ) = current.get_or_insert_with( || ( String::new(), i ) );
+      match c
+      {
+        '\\' => match chars.next()
+        {
+          Some( ( _, escaped ) ) => value.push( escaped ),
+          None => return Err( TokenizeError::TrailingEscape { position : i } ),
+        },
+        '\'' => loop
+        {
+          match chars.next()
+          {
+            Some( ( _, '\'' ) ) => break,
+            Some( ( _, c ) ) => value.push( c ),
+            None => return Err( TokenizeError::UnterminatedQuote { quote : '\'', position : i } ),
+          }
+        },
+        '"' => loop
+        {
+          match chars.next()
+          {
+            Some( ( _, '"' ) ) => break,
+            Some( ( _, '\\' ) ) if matches!( chars.peek(), Some( ( _, '"' | '\\' ) ) ) =>
+            {
+              let ( _, escaped ) = chars.next().unwrap();
+              value.push( escaped );
+            }
+            Some( ( _, c ) ) => value.push( c ),
+            None => return Err( TokenizeError::UnterminatedQuote { quote : '"', position : i } ),
+          }
+        },
+        c => value.push( c ),
+      }
+    }
+    if let Some( ( value, start ) ) = current
+    {
+      tokens.push( Token { value, span : start..input.len() } );
+    }
+
+    Ok( tokens )
+  }
+}
+
+//
+
+crate::mod_interface!
+{
+  exposed use Token;
+  exposed use TokenizeError;
+  own use tokenize;
+}
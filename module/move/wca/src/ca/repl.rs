@@ -0,0 +1,176 @@
+mod private
+{
+  use crate::*;
+  use ca::completion::private::completion_candidates;
+  use ca::parser::tokenizer::{ tokenize, TokenizeError };
+
+  use std::{ fs, io };
+  use std::path::PathBuf;
+  use former::Former;
+  use error::untyped::Error as wError;
+  use rustyline::
+  {
+    Editor,
+    Helper,
+    completion::Completer,
+    highlight::Highlighter,
+    hint::Hinter,
+    history::{ DefaultHistory, History },
+    validate::Validator,
+    error::ReadlineError,
+  };
+
+  /// Options of the interactive session.
+  ///
+  /// ```
+  /// use wca::ReplOptions;
+  ///
+  /// let options = ReplOptions::former()
+  /// .prompt( "unitore> " )
+  /// .history( std::env::temp_dir().join( "unitore_history" ) )
+  /// .form();
+  /// ```
+  #[ derive( Debug, Former ) ]
+  pub struct ReplOptions
+  {
+    /// Prompt of the first line of an input.
+    #[ former( default = "> ".to_string() ) ]
+    pub prompt : String,
+    /// Prompt of the next lines of a multi-line input.
+    #[ former( default = "... ".to_string() ) ]
+    pub continuation_prompt : String,
+    /// File the history is loaded from at the start and stored to after every input. The history is kept in memory only if not specified.
+    pub history : Option< PathBuf >,
+  }
+
+  /// Completes lines of the session from the dictionary.
+  struct ReplHelper< 'a >
+  {
+    dictionary : &'a Dictionary,
+  }
+
+  impl Completer for ReplHelper< '_ >
+  {
+    type Candidate = String;
+
+    fn complete( &self, line : &str, pos : usize, _ : &rustyline::Context< '_ > ) -> rustyline::Result< ( usize, Vec< String > ) >
+    {
+      Ok( completion_candidates( self.dictionary, line, pos ) )
+    }
+  }
+
+  impl Hinter for ReplHelper< '_ >
+  {
+    type Hint = String;
+  }
+
+  impl Highlighter for ReplHelper< '_ > {}
+
+  impl Validator for ReplHelper< '_ > {}
+
+  impl Helper for ReplHelper< '_ > {}
+
+  /// Reads inputs of the user and passes them to the function until the end of the input.
+  ///
+  /// A line which ends with `\` or with an unterminated quote is continued by the next one. `.exit` ends the session unless the dictionary has such a command.
+  /// Errors of inputs are printed and the session goes on, only errors of the terminal end it.
+  pub( crate ) fn repl< F >( dictionary : &Dictionary, options : ReplOptions, mut perform : F ) -> Result< (), wError >
+  where
+    F : FnMut( &str ) -> Result< (), ca::aggregator::private::Error >,
+  {
+    let mut editor = Editor::< ReplHelper< '_ >, DefaultHistory >::new()?;
+    editor.set_helper( Some( ReplHelper { dictionary } ) );
+    if let Some( history ) = &options.history
+    {
+      match editor.load_history( history )
+      {
+        Err( ReadlineError::Io( e ) ) if e.kind() == io::ErrorKind::NotFound => {}
+        result => result?,
+      }
+    }
+    let exit = dictionary.command( &"exit".to_string() ).is_none();
+
+    loop
+    {
+      let Some( input ) = input_read( &mut editor, &options )? else { break };
+      let input = input.trim();
+      if input.is_empty()
+      {
+        continue;
+      }
+      editor.add_history_entry( input )?;
+      if let Some( history ) = &options.history
+      {
+        history_store( &mut editor, history )?;
+      }
+      if exit && input == ".exit"
+      {
+        break;
+      }
+
+      if let Err( e ) = perform( input )
+      {
+        eprintln!( "{e}" );
+      }
+    }
+
+    Ok( () )
+  }
+
+  /// Reads lines of a single input, `None` at the end of the input. An interrupted input is dropped.
+  fn input_read< H : Helper, I : History >( editor : &mut Editor< H, I >, options : &ReplOptions ) -> Result< Option< String >, wError >
+  {
+    let mut input = String::new();
+    loop
+    {
+      let prompt = if input.is_empty() { &options.prompt } else { &options.continuation_prompt };
+      match editor.readline( prompt )
+      {
+        Ok( line ) =>
+        {
+          match line.strip_suffix( '\\' )
+          {
+            Some( line ) =>
+            {
+              input.push_str( line );
+              input.push( ' ' );
+            }
+            None =>
+            {
+              input.push_str( &line );
+              // a quoted word goes on in the next line
+              if let Err( TokenizeError::UnterminatedQuote { .. } ) = tokenize( &input )
+              {
+                input.push( '\n' );
+                continue;
+              }
+              return Ok( Some( input ) );
+            }
+          }
+        }
+        Err( ReadlineError::Interrupted ) => input.clear(),
+        Err( ReadlineError::Eof ) => return Ok( None ),
+        Err( e ) => return Err( e.into() ),
+      }
+    }
+  }
+
+  /// Stores the history to the file, its directory is created if needed.
+  fn history_store< H : Helper >( editor : &mut Editor< H, DefaultHistory >, history : &PathBuf ) -> Result< (), wError >
+  {
+    if let Some( dir ) = history.parent().filter( | d | !d.as_os_str().is_empty() )
+    {
+      fs::create_dir_all( dir )?;
+    }
+    editor.save_history( history )?;
+
+    Ok( () )
+  }
+}
+
+//
+
+crate::mod_interface!
+{
+  exposed use ReplOptions;
+}
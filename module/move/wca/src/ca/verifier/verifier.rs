@@ -7,10 +7,154 @@ mod private
   use std::collections::HashMap;
   use indexmap::IndexMap;
   // use wtools::{ error, error::Result, err };
-  use error::err;
+  use error::for_lib::*;
+  use ca::parser::parser::WordPosition;
   use ca::help::private::{ HelpGeneratorOptions, LevelOfDetail, generate_help_content };
   // xxx
 
+  /// Errors of verification of commands against the dictionary.
+  #[ derive( Error, Debug, Clone, PartialEq, Eq ) ]
+  pub enum VerifierError
+  {
+    /// The dictionary has no command with the phrase.
+    #[ error( "Command `.{name}`{} not found. {}", located( .position ), suggestion_or( .suggestion, "Please use `.` command to see the list of available commands." ) ) ]
+    UnknownCommand
+    {
+      /// Phrase of the command.
+      name : String,
+      /// The nearest phrase of the dictionary.
+      suggestion : Option< String >,
+      /// Where the command is in the input, known if the program is performed by `CommandsAggregator`.
+      position : Option< WordPosition >,
+    },
+    /// The command has no property with the name, and the property can not be a subject.
+    #[ error( "Command `.{command}`{} has no property `{name}`. {}", located( .position ), suggestion_or( .suggestion, "" ) ) ]
+    UnknownProperty
+    {
+      /// Phrase of the command.
+      command : String,
+      /// Name of the property.
+      name : String,
+      /// The nearest name or alias of a property of the command.
+      suggestion : Option< String >,
+      /// Where the command is in the input, known if the program is performed by `CommandsAggregator`.
+      position : Option< WordPosition >,
+    },
+    /// The command has fewer subjects.
+    #[ error( "`{command}` command{} with specified subjects not found. Command info: `{help}`", located( .position ) ) ]
+    SubjectsMismatch
+    {
+      /// Phrase of the command.
+      command : String,
+      /// Help of the command.
+      help : String,
+      /// Where the command is in the input, known if the program is performed by `CommandsAggregator`.
+      position : Option< WordPosition >,
+    },
+    /// A required subject is missing or has a value of another type.
+    #[ error( "Missing not optional subject {index} of `.{command}`{}", located( .position ) ) ]
+    MissingSubject
+    {
+      /// Phrase of the command.
+      command : String,
+      /// Index of the subject in the description of the command.
+      index : usize,
+      /// Where the command is in the input, known if the program is performed by `CommandsAggregator`.
+      position : Option< WordPosition >,
+    },
+    /// A subject matches no subject of the command.
+    #[ error( "Can not identify a subject of `.{command}`{}: `{value}`", located( .position ) ) ]
+    UnexpectedSubject
+    {
+      /// Phrase of the command.
+      command : String,
+      /// The subject.
+      value : String,
+      /// Where the command is in the input, known if the program is performed by `CommandsAggregator`.
+      position : Option< WordPosition >,
+    },
+    /// A value of a property can not be casted to its type.
+    #[ error( "Invalid value `{value}` of property `{name}` of `.{command}`{}: {reason}", located( .position ) ) ]
+    InvalidProperty
+    {
+      /// Phrase of the command.
+      command : String,
+      /// Name of the property.
+      name : String,
+      /// The value.
+      value : String,
+      /// Why the value can not be casted.
+      reason : String,
+      /// Where the command is in the input, known if the program is performed by `CommandsAggregator`.
+      position : Option< WordPosition >,
+    },
+  }
+
+  impl VerifierError
+  {
+    /// Position of the command in the input, if it is known.
+    pub fn position( &self ) -> Option< &WordPosition >
+    {
+      match self
+      {
+        Self::UnknownCommand { position, .. }
+        | Self::UnknownProperty { position, .. }
+        | Self::SubjectsMismatch { position, .. }
+        | Self::MissingSubject { position, .. }
+        | Self::UnexpectedSubject { position, .. }
+        | Self::InvalidProperty { position, .. } => position.as_ref(),
+      }
+    }
+
+    fn at( mut self, at : Option< WordPosition > ) -> Self
+    {
+      match &mut self
+      {
+        Self::UnknownCommand { position, .. }
+        | Self::UnknownProperty { position, .. }
+        | Self::SubjectsMismatch { position, .. }
+        | Self::MissingSubject { position, .. }
+        | Self::UnexpectedSubject { position, .. }
+        | Self::InvalidProperty { position, .. } => *position = at,
+      }
+      self
+    }
+  }
+
+  fn located( position : &Option< WordPosition > ) -> String
+  {
+    position.as_ref().map_or_else( String::new, | position | format!( " {position}" ) )
+  }
+
+  fn suggestion_or( suggestion : &Option< String >, otherwise : &str ) -> String
+  {
+    suggestion.as_ref().map_or_else( || otherwise.to_string(), | s | format!( "Maybe you mean `{s}`?" ) )
+  }
+
+  /// The candidate nearest to the input by the edit distance, if it is near enough to be a typo.
+  #[ cfg( feature = "on_unknown_suggest" ) ]
+  fn nearest< 'a, I >( candidates : I, input : &str ) -> Option< String >
+  where
+    I : IntoIterator< Item = &'a str >,
+  {
+    use textdistance::{ Algorithm, DamerauLevenshtein };
+    let distance = DamerauLevenshtein::default();
+    let limit = ( input.chars().count() / 3 ).max( 2 );
+    candidates.into_iter()
+    .map( | candidate | ( distance.for_str( candidate, input ).val(), candidate ) )
+    .filter( |( d, _ )| *d <= limit )
+    .min_by_key( |( d, _ )| *d )
+    .map( |( _, candidate )| candidate.to_string() )
+  }
+
+  #[ cfg( not( feature = "on_unknown_suggest" ) ) ]
+  fn nearest< 'a, I >( _candidates : I, _input : &str ) -> Option< String >
+  where
+    I : IntoIterator< Item = &'a str >,
+  {
+    None
+  }
+
   /// Converts a `ParsedCommand` to a `VerifiedCommand` by performing validation and type casting on values.
   ///
   /// ```
@@ -48,37 +192,35 @@ mod private
       dictionary : &Dictionary,
       raw_program : Program< ParsedCommand >
     )
-    -> error::untyped::Result< Program< VerifiedCommand > >
-    // qqq : use typed error
+    -> Result< Program< VerifiedCommand >, VerifierError >
+    {
+      self.to_program_at( dictionary, raw_program, &[] )
+    }
+
+    /// Converts raw program like `to_program`, errors have positions of the commands, which are given in order of the commands.
+    pub( crate ) fn to_program_at
+    (
+      &self,
+      dictionary : &Dictionary,
+      raw_program : Program< ParsedCommand >,
+      positions : &[ WordPosition ],
+    )
+    -> Result< Program< VerifiedCommand >, VerifierError >
     {
       let commands = raw_program.commands
       .into_iter()
-      .map( | n | self.to_command( dictionary, n ) )
-      .collect::< error::untyped::Result< Vec< VerifiedCommand > > >()?;
+      .enumerate()
+      .map( |( i, n )| self.to_command( dictionary, n ).map_err( | e | e.at( positions.get( i ).cloned() ) ) )
+      .collect::< Result< Vec< VerifiedCommand >, _ > >()?;
 
       Ok( Program { commands } )
     }
 
-    #[ cfg( feature = "on_unknown_suggest" ) ]
-    fn suggest_command< 'a >( dictionary : &'a Dictionary, user_input: &str ) -> Option< &'a str >
-    {
-      use textdistance::{ Algorithm, JaroWinkler };
-      let jaro = JaroWinkler::default();
-      let sim = dictionary
-      .commands
-      .iter()
-      .map( |( name, c )| ( jaro.for_str( name.as_str(), user_input ).nsim(), c ) )
-      .max_by( |( s1, _ ), ( s2, _ )| s1.total_cmp( s2 ) );
-      if let Some(( sim, variant )) = sim
-      {
-        if sim > 0.0
-        {
-          let phrase = &variant.phrase;
-          return Some( phrase );
-        }
-      }
-
-      None
+    /// Property of the command nearest to the unknown one.
+    fn suggest_property( command : &Command, name : &str ) -> Option< String >
+    {
+      let names = command.properties.keys().chain( command.properties_aliases.keys() ).map( String::as_str );
+      nearest( names, name )
     }
 
     fn get_count_from_properties
@@ -109,45 +251,52 @@ mod private
       if Self::is_valid_command_variant( expected_subjects_count, raw_subjects_count, possible_subjects_count ) { Some( variant ) } else { None }
     }
 
-    // qqq : use typed error
     fn extract_subjects( command : &Command, raw_command : &ParsedCommand, used_properties : &[ &String ] )
     ->
-    error::untyped::Result< Vec< Value > >
+    Result< Vec< Value >, VerifierError >
     {
       let mut subjects = vec![];
 
+      // unknown properties are subjects with a colon, the key is kept to report the property if it is not a subject either
+      let mut unknown_properties = raw_command.properties.iter()
+      .filter( |( key, _ )| !used_properties.contains( key ) )
+      .collect::< Vec< _ > >();
+      unknown_properties.sort();
       let all_subjects = raw_command
-      .subjects.clone().into_iter()
-      .chain
-      (
-        raw_command.properties.iter()
-        .filter( |( key, _ )| !used_properties.contains( key ) )
-        .map( |( key, value )| format!( "{key}:{value}" ) )
-      )
+      .subjects.iter().map( | value | ( value.clone(), None ) )
+      .chain( unknown_properties.into_iter().map( |( key, value )| ( format!( "{key}:{value}" ), Some( key ) ) ) )
       .collect::< Vec< _ > >();
       let mut rc_subjects_iter = all_subjects.iter();
       let mut current = rc_subjects_iter.next();
 
-      for ValueDescription { kind, optional, .. } in &command.subjects
+      for ( index, ValueDescription { kind, optional, .. } ) in command.subjects.iter().enumerate()
       {
-        let value = match current.and_then( | v | kind.try_cast( v.clone() ).ok() )
+        let value = match current.and_then( |( v, _ )| kind.try_cast( v.clone() ).ok() )
         {
           Some( v ) => v,
           None if *optional => continue,
-          _ => return Err( err!( "Missing not optional subject" ) ),
+          _ => return Err( VerifierError::MissingSubject { command : command.phrase.clone(), index, position : None } ),
         };
         subjects.push( value );
         current = rc_subjects_iter.next();
       }
-      if let Some( value ) = current { return Err( err!( "Can not identify a subject: `{}`", value ) ) }
-
-      Ok( subjects )
+      match current
+      {
+        Some( ( _, Some( key ) ) ) if !command.properties.is_empty() => Err( VerifierError::UnknownProperty
+        {
+          command : command.phrase.clone(),
+          name : ( *key ).clone(),
+          suggestion : Self::suggest_property( command, key ),
+          position : None,
+        }),
+        Some( ( value, _ ) ) => Err( VerifierError::UnexpectedSubject { command : command.phrase.clone(), value : value.clone(), position : None } ),
+        None => Ok( subjects ),
+      }
     }
 
-    // qqq : use typed error
     fn extract_properties( command: &Command, raw_command : HashMap< String, String > )
     ->
-    error::untyped::Result< HashMap< String, Value > >
+    Result< HashMap< String, Value >, VerifierError >
     {
       raw_command.into_iter()
       .filter_map
@@ -163,9 +312,10 @@ mod private
       .map
       (
         |( value_description, key, value )|
-        value_description.kind.try_cast( value ).map( | v | ( key.clone(), v ) )
+        value_description.kind.try_cast( value.clone() ).map( | v | ( key.clone(), v ) )
+        .map_err( | e | VerifierError::InvalidProperty { command : command.phrase.clone(), name : key, value, reason : e.to_string(), position : None } )
       )
-      .collect::< error::untyped::Result< HashMap< _, _ > > >()
+      .collect::< Result< HashMap< _, _ >, _ > >()
     }
 
     fn group_properties_and_their_aliases< 'a, Ks >( aliases : &'a HashMap< String, String >, used_keys :  Ks ) -> Vec< &String >
@@ -192,10 +342,10 @@ mod private
     /// Converts raw command to grammatically correct
     ///
     /// Make sure that this command is described in the grammar and matches it(command itself and all it options too).
-    // qqq : use typed error
+    /// Unknown phrases and properties are reported with the nearest ones of the dictionary if the `on_unknown_suggest` feature is enabled.
     pub fn to_command( &self, dictionary : &Dictionary, raw_command : ParsedCommand )
     ->
-    error::untyped::Result< VerifiedCommand >
+    Result< VerifiedCommand, VerifierError >
     {
       if raw_command.name.ends_with( '.' ) | raw_command.name.ends_with( ".?" )
       {
@@ -208,25 +358,40 @@ mod private
         });
       }
       let command = dictionary.command( &raw_command.name )
-      .ok_or_else::< error::untyped::Error, _ >
+      .ok_or_else
       (
-        ||
+        || VerifierError::UnknownCommand
         {
-          #[ cfg( feature = "on_unknown_suggest" ) ]
-          if let Some( phrase ) = Self::suggest_command( dictionary, &raw_command.name )
-          { return err!( "Command not found. Maybe you mean `.{}`?", phrase ) }
-          err!( "Command not found. Please use `.` command to see the list of available commands." )
+          name : raw_command.name.clone(),
+          suggestion : nearest( dictionary.commands.keys().map( String::as_str ), &raw_command.name ).map( | phrase | format!( ".{phrase}" ) ),
+          position : None,
         }
       )?;
 
       let Some( cmd ) = Self::check_command( command, &raw_command ) else
       {
-        error::untyped::bail!
-        (
-          "`{}` command with specified subjects not found. Command info: `{}`",
-          &raw_command.name,
-          generate_help_content( dictionary, HelpGeneratorOptions::former().for_commands([ dictionary.command( &raw_command.name ).unwrap() ]).command_prefix( "." ).subject_detailing( LevelOfDetail::Detailed ).form() ).strip_suffix( "  " ).unwrap()
-        );
+        // too many subjects if unknown properties are taken as subjects, so the first of them is likely misspelled
+        let mut unknown = raw_command.properties.keys()
+        .filter( | k | !( command.properties.contains_key( *k ) || command.properties_aliases.contains_key( *k ) ) )
+        .collect::< Vec< _ > >();
+        unknown.sort();
+        if let Some( name ) = unknown.first().filter( | _ | raw_command.subjects.len() <= command.subjects.len() )
+        {
+          return Err( VerifierError::UnknownProperty
+          {
+            command : raw_command.name.clone(),
+            name : ( *name ).clone(),
+            suggestion : Self::suggest_property( command, name ),
+            position : None,
+          });
+        }
+        let help = generate_help_content( dictionary, HelpGeneratorOptions::former().for_commands([ command ]).command_prefix( "." ).subject_detailing( LevelOfDetail::Detailed ).form() );
+        return Err( VerifierError::SubjectsMismatch
+        {
+          command : raw_command.name.clone(),
+          help : help.strip_suffix( "  " ).unwrap_or( &help ).to_string(),
+          position : None,
+        });
       };
 
       let properties = Self::extract_properties( cmd, raw_command.properties.clone() )?;
@@ -249,4 +414,5 @@ mod private
 crate::mod_interface!
 {
   exposed use Verifier;
+  exposed use VerifierError;
 }
@@ -121,17 +121,10 @@ tests_impls!
 
     a_id!( (), ca.perform( command ).unwrap() );
 
-    let wrong_command = r#".command ./path:to_dir "#;
+    // the trailing space does not make an empty word
+    let command_with_space = r#".command ./path:to_dir "#;
 
-    a_true!
-    (
-      matches!
-      (
-        ca.perform( wrong_command ),
-        Err( Error::Validation( ValidationError::Parser { .. } ) )
-      ),
-      "It is a sentence that can not be parsed: `/path:to_dir`"
-    );
+    a_id!( (), ca.perform( command_with_space ).unwrap() );
   }
 
   fn string_subject_with_colon()
@@ -216,6 +209,64 @@ tests_impls!
     a_id!( (), executor.command( dictionary, grammar_command ).unwrap() );
   }
 
+  fn quoted_values()
+  {
+    let ca = CommandsAggregator::former()
+    .command( "config.add" )
+      .hint( "hint" )
+      .long_hint( "long_hint" )
+      .subject().hint( "Any string." ).kind( Type::String ).optional( true ).end()
+      .property( "path" ).hint( "Path to a config." ).kind( Type::Path ).optional( true ).end()
+      .routine( | o : VerifiedCommand |
+      {
+        assert_eq!( "it's a subject", o.args.get_owned::< String >( 0 ).unwrap() );
+        assert_eq!( std::path::PathBuf::from( "my feeds:1.toml" ), o.props.get_owned::< _, std::path::PathBuf >( "path" ).unwrap() );
+      })
+      .end()
+    .perform();
+
+    a_id!( (), ca.perform( r#".config.add it\'s\ a\ subject path:"my feeds:1.toml""# ).unwrap() );
+    a_id!( (), ca.perform( r#".config.add "it's a subject" path: 'my feeds:1.toml'"# ).unwrap() );
+
+    a_true!
+    (
+      matches!
+      (
+        ca.perform( r#".config.add path:"my feeds.toml"# ),
+        Err( Error::Validation( ValidationError::Tokenizer( the_module::TokenizeError::UnterminatedQuote { quote : '"', position : 17 } ) ) )
+      ),
+      "Expected the unterminated quote at position 17."
+    );
+  }
+
+  fn error_positions()
+  {
+    let ca = CommandsAggregator::former()
+    .command( "command" )
+      .hint( "hint" )
+      .long_hint( "long_hint" )
+      .property( "count" ).hint( "A number." ).kind( Type::Number ).optional( true ).end()
+      .routine( || println!( "Command" ) )
+      .end()
+    .perform();
+    let at = | index, span | the_module::WordPosition { index, span };
+
+    // words of a line have spans
+    let Err( Error::Validation( ValidationError::Parser { error, .. } ) ) = ca.perform( ".command  count:" ) else { panic!( "Expected ValidationError::Parser." ) };
+    a_id!( Some( &at( 1, Some( 10..16 ) ) ), error.position() );
+
+    let Err( Error::Validation( ValidationError::Verifier( error ) ) ) = ca.perform( r#".command "count:many""# ) else { panic!( "Expected ValidationError::Verifier." ) };
+    a_id!( Some( &at( 0, Some( 0..8 ) ) ), error.position() );
+
+    let Err( Error::Validation( ValidationError::Verifier( error ) ) ) = ca.perform( ".command count:1 .comand" ) else { panic!( "Expected ValidationError::Verifier." ) };
+    a_id!( Some( &at( 2, Some( 17..24 ) ) ), error.position() );
+    a_true!( error.to_string().starts_with( "Command `.comand` at position 17 not found." ) );
+
+    // words split by a shell have only indices
+    let Err( Error::Validation( ValidationError::Verifier( error ) ) ) = ca.perform( vec![ ".command".to_string(), "count:1".into(), ".comand".into() ] ) else { panic!( "Expected ValidationError::Verifier." ) };
+    a_id!( Some( &at( 2, None ) ), error.position() );
+  }
+
   // qqq : make the following test work
   fn subject_with_spaces()
   {
@@ -246,5 +297,7 @@ tests_index!
   string_subject_with_colon,
   no_prop_subject_with_colon,
   optional_prop_subject_with_colon,
+  quoted_values,
+  error_positions,
   subject_with_spaces,
 }
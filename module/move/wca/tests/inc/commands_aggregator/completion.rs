@@ -0,0 +1,166 @@
+use super::*;
+use the_module::Shell;
+use the_module::ca::completion::completion_candidates;
+
+//
+
+fn aggregator() -> the_module::CommandsAggregator
+{
+  CommandsAggregator::former()
+  .command( "publish" )
+    .hint( "publishes packages" )
+    .subject().hint( "Path to a package." ).kind( Type::Path ).optional( true ).end()
+    .property( "dry" ).hint( "Don't publish, only show the plan." ).kind( Type::Bool ).optional( true ).alias( "d" ).end()
+    .property( "channel" ).hint( "Channel of the toolchain." ).kind( Type::String ).optional( true ).end()
+    .routine( || println!( "publish" ) )
+    .end()
+  .command( "list" )
+    .hint( "lists packages" )
+    .property( "manifest" ).hint( "Path to a manifest." ).kind( Type::Path ).optional( true ).end()
+    .routine( || println!( "list" ) )
+    .end()
+  .with_completion( true )
+  .perform()
+}
+
+tests_impls!
+{
+  fn bash()
+  {
+    let script = aggregator().completion( "will", Shell::Bash );
+
+    a_true!( script.contains( r#"compgen -W ".publish .list .completion .help""# ) );
+    a_true!( script.contains( r#"compgen -W "dry: d: channel:" -- "$cur""# ) );
+    // values of the bool property and of its alias
+    a_true!( script.contains( "dry:*|d:*)" ) );
+    a_true!( script.contains( r#"compgen -P "$name:" -W "true false""# ) );
+    // path property and path subject
+    a_true!( script.contains( "manifest:*)" ) );
+    a_true!( script.contains( r#"compgen -f -- "$cur""# ) );
+    a_true!( script.contains( "complete -F _will_completion will" ) );
+  }
+
+  fn zsh()
+  {
+    let script = aggregator().completion( "my-program", Shell::Zsh );
+
+    a_true!( script.starts_with( "#compdef my-program\n" ) );
+    a_true!( script.contains( "'.publish:publishes packages'" ) );
+    a_true!( script.contains( r"'dry\::Don'\''t publish, only show the plan.'" ) );
+    a_true!( script.contains( r"'d\::Don'\''t publish, only show the plan.'" ) );
+    a_true!( script.contains( "compadd -- true false" ) );
+    a_true!( script.contains( "_files" ) );
+    a_true!( script.contains( "compdef _my_program my-program" ) );
+  }
+
+  fn fish()
+  {
+    let script = aggregator().completion( "will", Shell::Fish );
+
+    a_true!( script.contains( r"complete -c will -n '__will_commands' -a '.publish' -d 'publishes packages'" ) );
+    a_true!( script.contains( r"complete -c will -n '__will_using .publish' -a 'd:' -d 'Don\'t publish, only show the plan.'" ) );
+    a_true!( script.contains( r"complete -c will -n '__will_using .publish \'^(dry|d):\'' -a '(__will_values true false)'" ) );
+    a_true!( script.contains( r"complete -c will -n '__will_using .list \'^(manifest):\'' -a '(__will_files)'" ) );
+    a_true!( script.contains( "complete -c will -n '__will_using .publish' -F" ) );
+    a_false!( script.contains( "complete -c will -n '__will_using .list' -F" ) );
+  }
+
+  fn candidates()
+  {
+    let dictionary = &the_module::Dictionary::former()
+    .command
+    (
+      the_module::Command::former()
+      .phrase( "publish" )
+      .subject().hint( "Path to a package." ).kind( Type::Path ).optional( true ).end()
+      .property( "dry" ).hint( "Don't publish, only show the plan." ).kind( Type::Bool ).optional( true ).alias( "d" ).end()
+      .property( "manifest" ).hint( "Path to a manifest." ).kind( Type::Path ).optional( true ).end()
+      .property( "channel" ).hint( "Toolchain." ).kind( Type::Enum( vec![ "stable".into(), "nightly".into() ] ) ).optional( true ).end()
+      .form()
+    )
+    .command( the_module::Command::former().phrase( "list" ).form() )
+    .form();
+    let complete = | line : &str | completion_candidates( dictionary, line, line.len() );
+
+    a_id!( ( 0, vec![ ".publish".to_string(), ".list".to_string() ] ), complete( "" ) );
+    a_id!( ( 9, vec![ ".list".to_string() ] ), complete( ".publish .l" ) );
+    a_id!( ( 9, vec![ "dry:".to_string() ] ), complete( ".publish dr" ) );
+    a_id!( ( 9, vec![ "d:true".to_string(), "d:false".to_string() ] ), complete( ".publish d:" ) );
+    a_id!( ( 9, vec![ "manifest:src/ca/".to_string() ] ), complete( ".publish manifest:src/c" ) );
+    a_id!( ( 9, vec![ "channel:nightly".to_string() ] ), complete( ".publish channel:n" ) );
+    // path subject
+    a_id!( ( 9, vec![ "src/ca/".to_string() ] ), complete( ".publish src/c" ) );
+    // the last command is completed
+    a_id!( ( 15, vec![] as Vec< String > ), complete( ".publish .list d" ) );
+  }
+
+  fn command()
+  {
+    let ca = aggregator();
+
+    a_id!( (), ca.perform( ".completion shell:bash program:will" ).unwrap() );
+    a_id!( (), ca.perform( ".completion shell:fish" ).unwrap() );
+    a_true!( matches!( ca.perform( ".completion shell:powershell" ), Err( Error::Execution( _ ) ) ) );
+    a_true!( matches!( ca.perform( ".completion" ), Err( Error::Execution( _ ) ) ) );
+  }
+
+  fn command_of_user()
+  {
+    let ca = CommandsAggregator::former()
+    .command( "completion" )
+      .hint( "own completion" )
+      .routine( || println!( "own completion" ) )
+      .end()
+    .with_completion( true )
+    .perform();
+
+    a_id!( (), ca.perform( ".completion" ).unwrap() );
+  }
+
+  fn opt_in()
+  {
+    let ca = CommandsAggregator::former()
+    .command( "list" )
+      .hint( "lists packages" )
+      .routine( || println!( "list" ) )
+      .end()
+    .perform();
+
+    a_true!( matches!( ca.perform( ".completion shell:bash" ), Err( Error::Validation( _ ) ) ) );
+  }
+
+  fn help_lists_command()
+  {
+    let phrases = std::sync::Arc::new( std::sync::Mutex::new( vec![] ) );
+    let captured = phrases.clone();
+    let ca = CommandsAggregator::former()
+    .command( "list" )
+      .hint( "lists packages" )
+      .routine( || println!( "list" ) )
+      .end()
+    .help( move | dictionary, _ |
+    {
+      *captured.lock().unwrap() = dictionary.commands().into_iter().map( |( phrase, _ )| phrase.clone() ).collect();
+      String::new()
+    })
+    .with_completion( true )
+    .perform();
+
+    a_id!( (), ca.perform( ".help" ).unwrap() );
+    a_true!( phrases.lock().unwrap().contains( &"completion".to_string() ) );
+  }
+}
+
+//
+
+tests_index!
+{
+  bash,
+  zsh,
+  fish,
+  candidates,
+  command,
+  command_of_user,
+  opt_in,
+  help_lists_command,
+}
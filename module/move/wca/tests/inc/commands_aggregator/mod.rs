@@ -14,3 +14,6 @@ use the_module::
 mod basic;
 mod callback;
 mod help;
+mod completion;
+#[ cfg( feature = "repl" ) ]
+mod repl;
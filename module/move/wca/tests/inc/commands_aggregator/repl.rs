@@ -0,0 +1,72 @@
+use std::fs::{ self, DirBuilder, File };
+use std::io::Write;
+use std::path::Path;
+use std::process::{ Command, Stdio };
+
+fn start_with_input< P : AsRef< Path > >( path : P, input : &str ) -> String
+{
+  let mut child = Command::new( "cargo" )
+  .args( [ "r", "-q" ] )
+  .stdin( Stdio::piped() )
+  .stdout( Stdio::piped() )
+  .stderr( Stdio::piped() )
+  .current_dir( path )
+  .spawn()
+  .unwrap();
+  child.stdin.take().unwrap().write_all( input.as_bytes() ).unwrap();
+  let output = child.wait_with_output().unwrap();
+
+  String::from_utf8( output.stdout ).unwrap()
+}
+
+#[ test ]
+fn repl_keeps_context_and_history()
+{
+  let temp = assert_fs::TempDir::new().unwrap();
+
+  let toml = format!
+  (
+    r#"[package]
+name = "wca_repl_test"
+version = "0.1.0"
+edition = "2021"
+[dependencies]
+wca = {{ path = "{}", features = [ "repl" ] }}"#,
+    env!( "CARGO_MANIFEST_DIR" ).replace( "\\", "/" )
+  );
+
+  let main = r#"use std::sync::{ Arc, Mutex };
+  use wca::{ Context, ReplOptions, Type, VerifiedCommand };
+  fn main(){
+   let ca = wca::CommandsAggregator::former()
+   .command( "count" )
+     .hint( "increments the counter" )
+     .routine( | ctx : Context | { let counter : Arc< Mutex< i32 > > = ctx.get().unwrap(); *counter.lock().unwrap() += 1; println!( "count {}", counter.lock().unwrap() ) } )
+     .end()
+   .command( "echo" )
+     .hint( "prints subjects" )
+     .subject().hint( "Subject" ).kind( Type::List( Type::String.into(), ',' ) ).optional( true ).end()
+     .subject().hint( "Subject" ).kind( Type::String ).optional( true ).end()
+     .routine( | o : VerifiedCommand | { println!( "echo {:?}", o.args.0 ) } )
+     .end()
+   .with_context( Mutex::new( 0 ) )
+   .perform();
+
+   ca.repl_with( ReplOptions::former().history( "history/repl" ).form() ).unwrap();
+   }
+  "#;
+  File::create( temp.path().join( "Cargo.toml" ) ).unwrap().write_all( toml.as_bytes() ).unwrap();
+  DirBuilder::new().create( temp.join( "src" ) ).unwrap();
+  File::create( temp.path().join( "src" ).join( "main.rs" ) ).unwrap().write_all( main.as_bytes() ).unwrap();
+
+  let result = start_with_input( temp.path(), ".count\n.unknown\n.count\n.echo a,b \\\nc\n.exit\n.count\n" );
+
+  assert!( result.contains( "count 1" ), "{result}" );
+  assert!( result.contains( "count 2" ), "{result}" );
+  assert!( !result.contains( "count 3" ), "{result}" );
+  assert!( result.contains( r#"echo [List([String("a"), String("b")]), String("c")]"# ), "{result}" );
+
+  let history = fs::read_to_string( temp.path().join( "history" ).join( "repl" ) ).unwrap();
+  assert!( history.contains( ".echo a,b  c" ), "{history}" );
+  assert!( history.contains( ".exit" ), "{history}" );
+}
@@ -386,6 +386,75 @@ tests_impls!
     a_true!( grammar_command.args.0.is_empty() );
     a_id!( HashMap::from_iter([ ( "property".to_string(), Value::String( "value".to_string() ) ) ]), grammar_command.props.0 );
   }
+
+  fn errors()
+  {
+    let parser = Parser;
+    let dictionary = &Dictionary::former()
+    .command
+    (
+      wca::Command::former()
+      .hint( "hint" )
+      .long_hint( "long_hint" )
+      .phrase( "command" )
+      .property( "property" ).hint( "string property" ).kind( Type::String ).optional( true ).alias( "p" ).end()
+      .property( "count" ).hint( "number property" ).kind( Type::Number ).optional( true ).end()
+      .form()
+    )
+    .command
+    (
+      wca::Command::former()
+      .hint( "hint" )
+      .long_hint( "long_hint" )
+      .phrase( "number" )
+      .subject().hint( "number" ).kind( Type::Number ).end()
+      .form()
+    )
+    .command
+    (
+      wca::Command::former()
+      .hint( "hint" )
+      .long_hint( "long_hint" )
+      .phrase( "optional" )
+      .subject().hint( "number" ).kind( Type::Number ).optional( true ).end()
+      .form()
+    )
+    .form();
+    let verifier = Verifier;
+    let verify = | args : &[ &str ] | verifier.to_command( dictionary, parser.parse( args.iter().copied() ).unwrap().commands.remove( 0 ) ).unwrap_err();
+    // suggestions are made only with the `on_unknown_suggest` feature
+    let suggestion = | s : &str | if cfg!( feature = "on_unknown_suggest" ) { Some( s.to_string() ) } else { None };
+
+    // misspelled command
+    let error = verify( &[ ".comand" ] );
+    a_id!( VerifierError::UnknownCommand { name : "comand".into(), suggestion : suggestion( ".command" ), position : None }, error );
+    #[ cfg( feature = "on_unknown_suggest" ) ]
+    a_id!( "Command `.comand` not found. Maybe you mean `.command`?", error.to_string() );
+
+    // nothing similar
+    let error = verify( &[ ".xyzxyzxyz" ] );
+    a_id!( VerifierError::UnknownCommand { name : "xyzxyzxyz".into(), suggestion : None, position : None }, error );
+
+    // misspelled property
+    let error = verify( &[ ".command", "propety:value" ] );
+    a_id!( VerifierError::UnknownProperty { command : "command".into(), name : "propety".into(), suggestion : suggestion( "property" ), position : None }, error );
+
+    // the command has no subjects
+    let error = verify( &[ ".command", "subject" ] );
+    a_true!( matches!( error, VerifierError::SubjectsMismatch { ref command, .. } if command == "command" ) );
+
+    // value of a property of another type
+    let error = verify( &[ ".command", "count:many" ] );
+    a_true!( matches!( error, VerifierError::InvalidProperty { ref name, ref value, .. } if name == "count" && value == "many" ) );
+
+    // required subject of another type
+    let error = verify( &[ ".number", "many" ] );
+    a_id!( VerifierError::MissingSubject { command : "number".into(), index : 0, position : None }, error );
+
+    // optional subject of another type
+    let error = verify( &[ ".optional", "many" ] );
+    a_id!( VerifierError::UnexpectedSubject { command : "optional".into(), value : "many".into(), position : None }, error );
+  }
 }
 
 //
@@ -402,4 +471,5 @@ tests_index!
   property_type_check,
   property_with_list,
   alias_property,
+  errors,
 }
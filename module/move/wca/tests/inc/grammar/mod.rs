@@ -5,7 +5,7 @@ use the_module::
 
   Type, Value,
   Dictionary,
-  Verifier,
+  Verifier, VerifierError,
 };
 
 mod from_command;
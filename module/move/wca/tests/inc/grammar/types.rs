@@ -1,5 +1,6 @@
 use super::*;
-use wca::TryCast;
+use wca::{ TryCast, CustomType };
+use std::collections::HashMap;
 
 //
 
@@ -140,6 +141,121 @@ tests_impls!
     // a_id!( origin_string, string.to_string() );
   }
 
+  fn enumeration()
+  {
+    let kind = Type::Enum( vec![ "stable".into(), "nightly".into() ] );
+
+    let value = kind.try_cast( "nightly".into() ).unwrap();
+    a_id!( Value::String( "nightly".into() ), value );
+
+    let inner_value : String = value.into();
+    a_id!( "nightly", inner_value );
+
+    // not one of the values
+    let error = kind.try_cast( "beta".into() ).unwrap_err();
+    a_id!( "`beta` is not one of: stable, nightly", error.to_string() );
+  }
+
+  fn integers()
+  {
+    // signed
+    let kind = Type::Int { min : Some( -10 ), max : Some( 10 ) };
+
+    let number = kind.try_cast( "-3".into() ).unwrap();
+    a_id!( Value::Int( -3 ), number );
+
+    let inner_number : i32 = number.clone().into();
+    a_id!( -3, inner_number );
+
+    let inner_number : f64 = number.into();
+    a_id!( -3.0, inner_number );
+
+    // bounds are inclusive
+    a_true!( kind.try_cast( "10".into() ).is_ok() );
+    a_id!( "`11` is out of range -10..=10", kind.try_cast( "11".into() ).unwrap_err().to_string() );
+    a_true!( kind.try_cast( "-11".into() ).is_err() );
+
+    // not an integer
+    a_true!( kind.try_cast( "3.14".into() ).is_err() );
+
+    // unsigned
+    let kind = Type::UInt { min : Some( 1 ), max : None };
+
+    let number = kind.try_cast( "42".into() ).unwrap();
+    a_id!( Value::UInt( 42 ), number );
+
+    let inner_number : usize = number.into();
+    a_id!( 42, inner_number );
+
+    a_true!( kind.try_cast( "0".into() ).is_err() );
+    a_true!( kind.try_cast( "-1".into() ).is_err() );
+    a_true!( Type::UInt { min : None, max : None }.try_cast( u64::MAX.to_string() ).is_ok() );
+  }
+
+  fn map()
+  {
+    let kind = Type::Map( Type::String.into(), Type::UInt { min : None, max : None }.into() );
+
+    let map = kind.try_cast( "a=1,b=2".into() ).unwrap();
+    a_id!
+    (
+      Value::Map( vec![ ( Value::String( "a".into() ), Value::UInt( 1 ) ), ( Value::String( "b".into() ), Value::UInt( 2 ) ) ] ),
+      map
+    );
+    a_id!( "a=1,b=2", map.to_string() );
+
+    let inner_map : HashMap< String, u64 > = map.into();
+    a_id!( HashMap::from_iter([ ( "a".to_string(), 1 ), ( "b".to_string(), 2 ) ]), inner_map );
+
+    // empty
+    a_id!( Value::Map( vec![] ), kind.try_cast( "".into() ).unwrap() );
+
+    // without a value
+    a_true!( kind.try_cast( "a=1,b".into() ).is_err() );
+    // value of another type
+    a_true!( kind.try_cast( "a=x".into() ).is_err() );
+  }
+
+  fn custom()
+  {
+    struct Even;
+
+    impl TryCast< Value > for Even
+    {
+      fn try_cast( &self, value : String ) -> error::untyped::Result< Value >
+      {
+        match value.parse::< i64 >()
+        {
+          Ok( n ) if n % 2 == 0 => Ok( Value::Int( n ) ),
+          _ => Err( error::untyped::format_err!( "`{value}` is not an even number" ) ),
+        }
+      }
+    }
+
+    let kind = Type::Custom( CustomType::new( "Even", Even ) );
+    a_id!( Value::Int( 4 ), kind.try_cast( "4".into() ).unwrap() );
+    a_true!( kind.try_cast( "5".into() ).is_err() );
+
+    // in a list
+    let list = Type::List( kind.clone().into(), ',' ).try_cast( "2,4".into() ).unwrap();
+    a_id!( Value::List( vec![ Value::Int( 2 ), Value::Int( 4 ) ] ), list );
+
+    a_id!( kind.clone(), kind );
+    a_true!( Type::Custom( CustomType::new( "Even", Even ) ) != kind );
+  }
+
+  fn type_display()
+  {
+    a_id!( "Number", Type::Number.to_string() );
+    a_id!( "List(String, ',')", Type::List( Type::String.into(), ',' ).to_string() );
+    a_id!( "Enum(stable|nightly)", Type::Enum( vec![ "stable".into(), "nightly".into() ] ).to_string() );
+    a_id!( "Int", Type::Int { min : None, max : None }.to_string() );
+    a_id!( "Int(-10..=10)", Type::Int { min : Some( -10 ), max : Some( 10 ) }.to_string() );
+    a_id!( "UInt(1..)", Type::UInt { min : Some( 1 ), max : None }.to_string() );
+    a_id!( "UInt(..=100)", Type::UInt { min : None, max : Some( 100 ) }.to_string() );
+    a_id!( "Map(String, Bool)", Type::Map( Type::String.into(), Type::Bool.into() ).to_string() );
+  }
+
 }
 
 //
@@ -152,4 +268,66 @@ tests_index!
   boolean,
   values_list,
   values_list_display,
+  enumeration,
+  integers,
+  map,
+  custom,
+  type_display,
+}
+
+#[ cfg( feature = "type_duration" ) ]
+mod duration
+{
+  use super::*;
+  use std::time::Duration;
+
+  tests_impls!
+  {
+    fn duration()
+    {
+      let duration = Type::Duration.try_cast( "1h 30m".into() ).unwrap();
+      a_id!( Value::Duration( Duration::from_secs( 5400 ) ), duration );
+
+      let inner_duration : Duration = duration.clone().into();
+      a_id!( Duration::from_secs( 5400 ), inner_duration );
+      a_id!( "1h 30m", duration.to_string() );
+
+      a_id!( Value::Duration( Duration::from_millis( 1500 ) ), Type::Duration.try_cast( "1500ms".into() ).unwrap() );
+
+      // without unit
+      a_true!( Type::Duration.try_cast( "90".into() ).is_err() );
+      a_id!( "Map(String, Duration)", Type::Map( Type::String.into(), Type::Duration.into() ).to_string() );
+    }
+  }
+
+  tests_index!
+  {
+    duration,
+  }
+}
+
+#[ cfg( feature = "type_url" ) ]
+mod url
+{
+  use super::*;
+
+  tests_impls!
+  {
+    fn url()
+    {
+      let url = Type::Url.try_cast( "https://github.com/Wandalen/wTools".into() ).unwrap();
+
+      let inner_url : ::url::Url = url.clone().into();
+      a_id!( Some( "github.com" ), inner_url.host_str() );
+      a_id!( "https://github.com/Wandalen/wTools", url.to_string() );
+
+      // relative
+      a_true!( Type::Url.try_cast( "Wandalen/wTools".into() ).is_err() );
+    }
+  }
+
+  tests_index!
+  {
+    url,
+  }
 }
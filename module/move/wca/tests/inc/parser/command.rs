@@ -377,6 +377,27 @@ tests_impls!
       parser.parse( [ ".command.?" ] ).unwrap().commands[ 0 ]
     );
   }
+
+  fn errors()
+  {
+    let parser = Parser;
+
+    // subject after properties
+    let error = parser.parse( [ ".command", "prop:value", "subject" ] ).unwrap_err();
+    a_id!( ParserError::Unexpected { word : "subject".into(), position : WordPosition { index : 2, span : None } }, error );
+
+    // property without a value in the second command
+    let error = parser.parse( [ ".first", ".second", "prop:" ] ).unwrap_err();
+    a_id!( ParserError::PropertyValueMissing { name : "prop".into(), position : WordPosition { index : 2, span : None } }, error );
+
+    // positions of words of a line
+    let error = parser.parse_line( ".command prop:value 'a subject'" ).unwrap_err();
+    a_id!( Some( &WordPosition { index : 2, span : Some( 20..31 ) } ), error.position() );
+
+    let error = parser.parse_line( "  command" ).unwrap_err();
+    a_id!( ParserError::CommandExpected { word : "command".into(), position : WordPosition { index : 0, span : Some( 2..9 ) } }, error );
+    a_id!( "Unexpected input: Expected a command, found: `command` at position 2", error.to_string() );
+  }
 }
 
 //
@@ -391,4 +412,5 @@ tests_index!
   list_in_property,
   string_value,
   dot_command,
+  errors,
 }
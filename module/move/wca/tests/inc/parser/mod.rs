@@ -3,8 +3,9 @@ use wca::
 {
   Program, ParsedCommand,
 
-  Parser,
+  Parser, ParserError, WordPosition,
 };
 
 mod command;
 mod program;
+mod tokenizer;
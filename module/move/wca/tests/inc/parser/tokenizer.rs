@@ -0,0 +1,88 @@
+use super::*;
+use the_module::{ Token, TokenizeError, ca::parser::tokenizer::tokenize };
+
+//
+
+fn words( input : &str ) -> Vec< String >
+{
+  tokenize( input ).unwrap().into_iter().map( | t | t.value ).collect()
+}
+
+tests_impls!
+{
+  fn whitespaces()
+  {
+    a_id!( Vec::< String >::new(), words( "" ) );
+    a_id!( Vec::< String >::new(), words( "  \t " ) );
+    a_id!( vec![ ".command", "subject", "prop:value" ], words( "  .command \t subject  prop:value " ) );
+  }
+
+  fn quotes()
+  {
+    a_id!( vec![ ".command", "two words", "it's" ], words( r#".command "two words" "it's""# ) );
+    a_id!( vec![ ".command", r#"say "hi""# ], words( r#".command 'say "hi"'"# ) );
+    // parts of a word are joined
+    a_id!( vec![ "path:my feeds.toml" ], words( r#"path:"my feeds.toml""# ) );
+    a_id!( vec![ "path:my feeds.toml" ], words( r#"path:'my feeds'.toml"# ) );
+    a_id!( vec![ "url:http://localhost:8080" ], words( r#"url:"http://localhost:8080""# ) );
+    a_id!( vec![ "" ], words( r#""""# ) );
+  }
+
+  fn escapes()
+  {
+    a_id!( vec![ "two words", "it's", r#"say "hi""# ], words( r#"two\ words it\'s say\ \"hi\""# ) );
+    // between double quotes only quotes and backslashes are escaped
+    a_id!( vec![ r#"C:\dir\"quoted"\\"# ], words( r#""C:\dir\\\"quoted\"\\\\""# ) );
+    // nothing is escaped between single quotes
+    a_id!( vec![ r"C:\dir\" ], words( r"'C:\dir\'" ) );
+  }
+
+  fn spans()
+  {
+    a_id!
+    (
+      vec!
+      [
+        Token { value : ".command".into(), span : 0..8 },
+        Token { value : "path:my feeds".into(), span : 10..25 },
+      ],
+      tokenize( r#".command  path:"my feeds""# ).unwrap()
+    );
+  }
+
+  fn errors()
+  {
+    a_id!( Err( TokenizeError::UnterminatedQuote { quote : '"', position : 14 } ), tokenize( r#".command path:"my feeds"# ) );
+    a_id!( Err( TokenizeError::UnterminatedQuote { quote : '\'', position : 15 } ), tokenize( r#".command 'it\'s'"# ) );
+    a_id!( Err( TokenizeError::TrailingEscape { position : 9 } ), tokenize( r".command \" ) );
+  }
+
+  fn parse_line()
+  {
+    let parser = Parser;
+
+    a_id!
+    (
+      ParsedCommand
+      {
+        name : "config.add".into(),
+        subjects : vec![ "my feeds.toml".into() ],
+        properties : HashMap::from_iter([ ( "format".into(), "toml file".into() ) ]),
+      },
+      parser.parse_line( r#".config.add "my feeds.toml" format:'toml file'"# ).unwrap().commands[ 0 ]
+    );
+    a_true!( parser.parse_line( r#".config.add "my feeds.toml"# ).is_err() );
+  }
+}
+
+//
+
+tests_index!
+{
+  whitespaces,
+  quotes,
+  escapes,
+  spans,
+  errors,
+  parse_line,
+}
@@ -0,0 +1,89 @@
+/// Internal namespace.
+mod private
+{
+  use crate::*;
+
+  use std::fmt;
+  use former::Former;
+  use error::
+  {
+    ErrWith,
+    untyped::{ Error, Context },
+  };
+
+  /// Options for `changelog_renew`.
+  #[ derive( Debug, Former ) ]
+  pub struct ChangelogRenewOptions
+  {
+    /// Directory of a package or of a workspace. Changelogs of all packages inside the directory are renewed.
+    crate_dir : CrateDir,
+    /// Title of the written section. By default `Unreleased`.
+    title : Option< String >,
+    /// Do not write files, only report what would be written.
+    #[ former( default = false ) ]
+    dry : bool,
+  }
+
+  /// Report of `changelog_renew`.
+  ///
+  /// Serialized as `{ packages : [ { file, section : { title, changes : [ { kind, scope?, description, commit } ] }, written } ] }`.
+  #[ derive( Debug, Default, Clone, serde::Serialize ) ]
+  pub struct ChangelogRenewReport
+  {
+    /// Reports of renewed packages.
+    pub packages : Vec< changelog::ChangelogReport >,
+  }
+
+  impl fmt::Display for ChangelogRenewReport
+  {
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      if self.packages.is_empty()
+      {
+        return write!( f, "No packages found" );
+      }
+      for package in &self.packages
+      {
+        writeln!( f, "{package}" )?;
+      }
+
+      Ok( () )
+    }
+  }
+
+  /// Writes changes made since the last release into `Changelog.md` of each package.
+  // qqq : typed error
+  pub fn changelog_renew( o : ChangelogRenewOptions ) -> ResultWithReport< ChangelogRenewReport, Error >
+  {
+    let mut report = ChangelogRenewReport::default();
+    let ChangelogRenewOptions { crate_dir, title, dry } = o;
+    let workspace = Workspace::try_from( crate_dir.clone() )
+    .context( "Failed to find workspace" )
+    .err_with_report( &report )?;
+    let root = crate_dir.clone().absolute_path();
+    let packages = workspace
+    .packages()
+    .filter( | p | p.manifest_file().is_ok_and( | m | m.inner().starts_with( &root ) ) );
+
+    for package in packages
+    {
+      let package_dir = package.crate_dir().err_with_report( &report )?;
+      let version = package.version().to_string();
+      let title = title.clone().unwrap_or_else( || changelog::UNRELEASED.into() );
+      let section = changelog::section_collect( &package_dir, package.name(), &version, &title )
+      .err_with_report( &report )?;
+      let written = changelog::section_write( &package_dir, section, dry ).err_with_report( &report )?;
+      report.packages.push( written );
+    }
+
+    Ok( report )
+  }
+}
+
+crate::mod_interface!
+{
+  orphan use ChangelogRenewOptions;
+  orphan use ChangelogRenewReport;
+  /// Renew changelogs of packages.
+  orphan use changelog_renew;
+}
@@ -4,8 +4,10 @@ mod private
 
   use std::
   {
+    fmt,
+    str,
     fs::File,
-    io::{ Write, Read },
+    io::Read,
   };
 
   use path::{ Path };
@@ -38,21 +40,106 @@ mod private
     Template( #[ from ] TemplateError ),
     #[ error( "Render error: {0}" ) ]
     Render( #[ from ] RenderError ),
+    #[ error( "{0}" ) ]
+    Drift( #[ from ] drift::DriftError ),
+  }
+
+  /// Kind of a CI/CD system to generate a pipeline for.
+  #[ derive( Debug, Default, Clone, Copy, PartialEq, Eq ) ]
+  pub enum CiCdProvider
+  {
+    /// Workflows of GitHub Actions in `.github/workflows`.
+    #[ default ]
+    GitHub,
+    /// Pipeline of GitLab CI in `.gitlab-ci.yml`.
+    GitLab,
+    /// Plain pipeline in `ci.mk`, runs with `make -f ci.mk`.
+    Makefile,
+  }
+
+  impl str::FromStr for CiCdProvider
+  {
+    type Err = error::untyped::Error;
+
+    fn from_str( s : &str ) -> Result< Self, Self::Err >
+    {
+      let value = match s
+      {
+        "github" => Self::GitHub,
+        "gitlab" => Self::GitLab,
+        "makefile" => Self::Makefile,
+        e => return Err( err!( "Unknown provider '{}'. Available values : [github, gitlab, makefile]", e ) )
+      };
+
+      Ok( value )
+    }
+  }
+
+  impl fmt::Display for CiCdProvider
+  {
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      match self
+      {
+        Self::GitHub => write!( f, "github" ),
+        Self::GitLab => write!( f, "gitlab" ),
+        Self::Makefile => write!( f, "makefile" ),
+      }
+    }
+  }
+
+  /// Data of a module for templates of pipelines.
+  #[ derive( Debug, serde::Serialize ) ]
+  struct ModuleData
+  {
+    /// Name of the package.
+    name : String,
+    /// Name of the job of the module : `test_{name}` in snake case.
+    job : String,
+    /// Path to the manifest relative to the root of the workspace.
+    manifest_path : String,
+    /// Directory of the module relative to the root of the workspace.
+    crate_dir : String,
+  }
+
+  /// Data for templates of pipelines of GitLab and Makefile.
+  #[ derive( Debug, serde::Serialize ) ]
+  struct PipelineData
+  {
+    /// Path of the repository on its host, if it is known.
+    repository : Option< String >,
+    /// Modules of the workspace.
+    modules : Vec< ModuleData >,
   }
 
   // qqq : for Petro : should return Report and typed error in Result
   /// Generate workflows for modules in .github/workflows directory.
-  pub fn cicd_renew( base_path : &Path ) -> Result< (), CiCdGenerateError >
+  ///
+  /// For `CiCdProvider::GitLab` the pipeline is written to `.gitlab-ci.yml`, for `CiCdProvider::Makefile` to `ci.mk`,
+  /// in the root of the workspace. Both have a job per module built from the same data as workflows of GitHub.
+  ///
+  /// If `check` is set nothing is written : the action fails with a unified diff of each file which differs from the generated one.
+  pub fn cicd_renew( base_path : &Path, provider : CiCdProvider, check : bool ) -> Result< (), CiCdGenerateError >
   {
+    let mut files = drift::GeneratedFiles::new( check );
     let workspace_cache = Workspace::try_from( CrateDir::try_from( base_path )? )?;
     let packages = workspace_cache.packages();
-    let username_and_repository = &username_and_repository
+    let repository_url = repository_url
     (
       &workspace_cache.workspace_root().join( "Cargo.toml" ).to_path_buf().try_into()?, // qqq
       packages.clone(),
       // packages.as_slice(),
-    )?;
+    );
     let workspace_root : &Path = &workspace_cache.workspace_root();
+
+    if provider != CiCdProvider::GitHub
+    {
+      let repository = repository_url.ok().and_then( | url | url::repo_path_extract( &url ) );
+      pipeline_write( &mut files, workspace_root, packages, repository, provider )?;
+      return Ok( files.finish()? );
+    }
+    let username_and_repository = &UsernameAndRepository( url::git_info_extract( &repository_url? )? );
+
     // find directory for workflows
     let workflow_root = workspace_root.join( ".github" ).join( "workflows" );
     // map packages name's to naming standard
@@ -135,14 +222,17 @@ mod private
       let manifest_file = manifest_file.trim_start_matches( '/' );
       data.insert( "manifest_path", manifest_file );
       let content = handlebars.render( "module_push", &data )?;
-      file_write( &workflow_file_name, &content )?;
+      files.write( &workflow_file_name, &content )?;
 
-      println!( "file_write : {:?}", &workflow_file_name )
+      if !files.is_check()
+      {
+        println!( "file_write : {:?}", &workflow_file_name )
+      }
     }
 
     dbg!( &workflow_root );
 
-    file_write
+    files.write
     (
       &workflow_root
       .join("appropriate_branch.yml" ),
@@ -157,7 +247,7 @@ mod private
       "alpha",
       "beta"
     );
-    file_write
+    files.write
     (
       &workflow_root.join( "appropriate_branch_beta.yml" ),
       &handlebars.render( "appropraite_branch_for", &data )?
@@ -172,7 +262,7 @@ mod private
       "master"
     );
 
-    file_write
+    files.write
     (
       &workflow_root.join( "appropriate_branch_master.yml" ),
       &handlebars.render( "appropraite_branch_for", &data )?
@@ -183,12 +273,12 @@ mod private
     data.insert( "group_branch", "beta" );
     data.insert( "branch", "alpha" );
 
-    file_write
+    files.write
     (
       &workflow_root.join( "auto_merge_to_beta.yml" ),
       &handlebars.render( "auto_merge_to", &data )?
     )?;
-    file_write
+    files.write
     (
       &workflow_root.join( "auto_pr.yml" ),
       include_str!( "../../template/workflow/auto_pr.yml" )
@@ -218,7 +308,7 @@ mod private
     data.insert( "src_branch", "${{ github.ref_name }}" );
     data.insert( "dest_branch", "alpha" );
 
-    file_write
+    files.write
     (
       &workflow_root.join( "auto_pr_to_alpha.yml" ),
       &handlebars.render( "auto_pr_to", &data )?
@@ -232,7 +322,7 @@ mod private
     data.insert( "src_branch", "alpha" );
     data.insert( "dest_branch", "beta" );
 
-    file_write
+    files.write
     (
       &workflow_root.join( "auto_pr_to_beta.yml" ),
       &handlebars.render( "auto_pr_to", &data )?
@@ -246,13 +336,13 @@ mod private
     data.insert( "src_branch", "beta" );
     data.insert( "dest_branch", "master" );
 
-    file_write
+    files.write
     (
       &workflow_root.join( "auto_pr_to_master.yml" ),
       &handlebars.render( "auto_pr_to", &data )?
     )?;
 
-    file_write
+    files.write
     (
       &workflow_root.join( "runs_clean.yml" ),
       include_str!( "../../template/workflow/rust_clean.yml" )
@@ -261,48 +351,87 @@ mod private
     let mut data = BTreeMap::new();
     data.insert( "username_and_repository", username_and_repository.0.as_str() );
 
-    file_write
+    files.write
     (
       &workflow_root.join( "standard_rust_pull_request.yml" ),
       &handlebars.render( "standard_rust_pull_request", &data )?
     )?;
 
-    file_write
+    files.write
     (
       &workflow_root.join( "standard_rust_push.yml" ),
       include_str!( "../../template/workflow/standard_rust_push.yml" )
     )?;
 
-    file_write
+    files.write
     (
       &workflow_root.join( "for_pr_rust_push.yml" ),
       include_str!( "../../template/workflow/for_pr_rust_push.yml" )
     )?;
 
-    file_write
+    files.write
     (
       &workflow_root.join( "standard_rust_scheduled.yml" ),
       include_str!( "../../template/workflow/standard_rust_scheduled.yml" )
     )?;
 
-    file_write
+    files.write
     (
       &workflow_root.join( "standard_rust_status.yml" ),
       include_str!( "../../template/workflow/standard_rust_status.yml" )
     )?;
 
-    file_write
+    files.write
     (
       &workflow_root.join( "status_checks_rules_update.yml" ),
       include_str!( "../../template/workflow/status_checks_rules_update.yml" )
     )?;
 
-    file_write
+    files.write
     (
       &workflow_root.join( "Readme.md" ),
       include_str!( "../../template/workflow/Readme.md" )
     )?;
 
+    Ok( files.finish()? )
+  }
+
+  /// Writes the pipeline of GitLab CI or of Makefile with a job per module.
+  fn pipeline_write< 'a >
+  (
+    files : &mut drift::GeneratedFiles,
+    workspace_root : &Path,
+    packages : impl Iterator< Item = WorkspacePackageRef< 'a > >,
+    repository : Option< String >,
+    provider : CiCdProvider,
+  )
+  -> Result< (), CiCdGenerateError >
+  {
+    let root = path::normalize( workspace_root );
+    let mut modules = vec![];
+    for package in packages
+    {
+      let manifest_file = package.manifest_file()?;
+      let Ok( relative ) = manifest_file.strip_prefix( &root ) else { continue };
+      let crate_dir = relative.parent().map( | p | p.to_string_lossy().replace( '\\', "/" ) ).unwrap_or_default();
+      let manifest_path = relative.to_string_lossy().replace( '\\', "/" );
+      let name = package.name().to_string();
+      modules.push( ModuleData { job : format!( "test_{}", name.to_case( Case::Snake ) ), name, manifest_path, crate_dir } );
+    }
+    let data = PipelineData { repository, modules };
+
+    let mut handlebars = handlebars::Handlebars::new();
+    handlebars.register_escape_fn( handlebars::no_escape );
+    let ( template, file ) = match provider
+    {
+      CiCdProvider::GitLab => ( include_str!( "../../template/workflow/gitlab_ci.hbs" ), ".gitlab-ci.yml" ),
+      CiCdProvider::Makefile => ( include_str!( "../../template/workflow/ci_makefile.hbs" ), "ci.mk" ),
+      CiCdProvider::GitHub => unreachable!( "workflows of GitHub are not a single pipeline" ),
+    };
+    handlebars.register_template_string( "pipeline", template )?;
+    let file = workspace_root.join( file );
+    files.write( &file, handlebars.render( "pipeline", &data )? )?;
+
     Ok( () )
   }
 
@@ -326,38 +455,20 @@ mod private
     data
   }
 
-  /// Create and write or rewrite content in file.
-  pub fn file_write( filename : &Path, content : &str ) -> error::untyped::Result< () > // qqq : use typed error
-  {
-    if let Some( folder ) = filename.parent()
-    {
-      match std::fs::create_dir_all( folder )
-      {
-        Ok( _ ) => {},
-        Err( e ) if e.kind() == std::io::ErrorKind::AlreadyExists => {},
-        Err( e ) => return Err( e.into() ),
-      }
-    }
-
-    let mut file = File::create( filename )?;
-    file.write_all( content.as_bytes() )?;
-    Ok( () )
-  }
-
   #[derive( Debug ) ]
   struct UsernameAndRepository( String );
 
-  /// Searches and extracts the username and repository name from the repository URL.
+  /// Searches and extracts the URL of the repository.
   /// The repository URL is first sought in the Cargo.toml file of the workspace;
   /// if not found there, it is then searched in the Cargo.toml file of the module.
-  /// If it is still not found, the search continues in the GitHub remotes.
-  /// Result looks like this: `Wandalen/wTools`
-  fn username_and_repository< 'a >
+  /// If it is still not found, the search continues in the git remotes.
+  /// Result looks like this: `https://github.com/Wandalen/wTools`
+  fn repository_url< 'a >
   (
     cargo_toml_path : &AbsolutePath,
     packages : impl Iterator< Item = WorkspacePackageRef< 'a > >,
   )
-  -> error::untyped::Result< UsernameAndRepository >
+  -> error::untyped::Result< String >
   // qqq : use typed error
   {
       let mut contents = String::new();
@@ -373,8 +484,6 @@ mod private
       if let Some( url ) = url
       {
         return url::repo_url_extract( &url )
-        .and_then( | url | url::git_info_extract( &url ).ok() )
-        .map( UsernameAndRepository )
         .ok_or_else( || err!( "Fail to parse repository url from workspace Cargo.toml"))
       }
       else
@@ -391,8 +500,6 @@ mod private
         }
         return url
         .and_then( | url | url::repo_url_extract( &url ) )
-        .and_then( | url | url::git_info_extract( &url ).ok() )
-        .map( UsernameAndRepository )
         .ok_or_else( || err!( "Fail to extract repository url") )
       }
     }
@@ -402,4 +509,5 @@ mod private
 crate::mod_interface!
 {
   exposed use cicd_renew;
+  exposed use CiCdProvider;
 }
@@ -0,0 +1,102 @@
+/// Internal namespace.
+mod private
+{
+  use crate::*;
+
+  use std::fmt;
+  use former::Former;
+  use error::
+  {
+    ErrWith,
+    untyped::{ Error, Context, format_err },
+  };
+
+  /// Options for `dependencies_check`.
+  #[ derive( Debug, Former ) ]
+  pub struct DependenciesCheckOptions
+  {
+    /// Directory of a package or of a workspace. Packages inside the directory are checked.
+    crate_dir : CrateDir,
+  }
+
+  /// Report of `dependencies_check`.
+  ///
+  /// Serialized as `{ unused : [ { package, name, table, location? } ], duplicated : [ { name, versions, locations } ] }`,
+  /// where a location is `{ file, line }`.
+  #[ derive( Debug, Default, Clone, serde::Serialize ) ]
+  pub struct DependenciesCheckReport
+  {
+    /// Dependencies which are not referenced from sources.
+    pub unused : Vec< dependency_usage::UnusedDependency >,
+    /// Dependencies with several semver incompatible versions in the lockfile.
+    pub duplicated : Vec< dependency_usage::DuplicatedDependency >,
+  }
+
+  impl fmt::Display for DependenciesCheckReport
+  {
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      if self.unused.is_empty() && self.duplicated.is_empty()
+      {
+        return write!( f, "No problems found" );
+      }
+      if !self.unused.is_empty()
+      {
+        writeln!( f, "Unused dependencies :" )?;
+        for dependency in &self.unused
+        {
+          writeln!( f, "  {dependency}" )?;
+        }
+      }
+      if !self.duplicated.is_empty()
+      {
+        writeln!( f, "Dependencies with semver incompatible versions :" )?;
+        for dependency in &self.duplicated
+        {
+          write!( f, "{dependency}" )?;
+        }
+      }
+
+      Ok( () )
+    }
+  }
+
+  /// Finds dependencies which are never referenced from sources of packages
+  /// and dependencies which are resolved to several semver incompatible versions in `Cargo.lock`.
+  ///
+  /// Returns an error if anything is found.
+  // qqq : typed error
+  pub fn dependencies_check( o : DependenciesCheckOptions ) -> ResultWithReport< DependenciesCheckReport, Error >
+  {
+    let mut report = DependenciesCheckReport::default();
+    let workspace = Workspace::try_from( o.crate_dir.clone() )
+    .context( "Failed to find workspace" )
+    .err_with_report( &report )?;
+    let root = o.crate_dir.absolute_path();
+    let packages = workspace
+    .packages()
+    .filter( | p | p.manifest_file().is_ok_and( | m | m.inner().starts_with( &root ) ) );
+
+    for package in packages.clone()
+    {
+      report.unused.extend( dependency_usage::unused( &package ).err_with_report( &report )? );
+    }
+    report.duplicated = dependency_usage::duplicated( &workspace, packages ).err_with_report( &report )?;
+
+    if !report.unused.is_empty() || !report.duplicated.is_empty()
+    {
+      let e = format_err!( "Found {} unused and {} duplicated dependencies", report.unused.len(), report.duplicated.len() );
+      return Err(( report, e ));
+    }
+
+    Ok( report )
+  }
+}
+
+crate::mod_interface!
+{
+  orphan use DependenciesCheckOptions;
+  orphan use DependenciesCheckReport;
+  /// Find unused and duplicated dependencies.
+  orphan use dependencies_check;
+}
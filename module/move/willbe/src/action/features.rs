@@ -23,7 +23,9 @@ mod private
   }
 
   /// Represents a report about features available in the package
-  #[ derive( Debug, Default ) ]
+  ///
+  /// Serialized as `{ with_features_deps, inner : { package : { feature : [ dependency ] } } }`.
+  #[ derive( Debug, Default, serde::Serialize ) ]
   pub struct FeaturesReport
   {
     /// Flag to turn off/on displaying feature dependencies - "feature: [deps...]"
@@ -0,0 +1,136 @@
+/// Internal namespace.
+mod private
+{
+  use crate::*;
+
+  use std::fmt;
+  use former::Former;
+  use error::
+  {
+    ErrWith,
+    untyped::{ Error, Context, format_err },
+  };
+
+  /// Options for `lint`.
+  #[ derive( Debug, Former ) ]
+  pub struct LintOptions
+  {
+    /// Directory of a package or of a workspace. Manifests of all packages inside the directory are checked.
+    crate_dir : CrateDir,
+    /// Fix problems which can be fixed automatically and write manifests.
+    #[ former( default = false ) ]
+    fix : bool,
+  }
+
+  /// Report of `lint`.
+  ///
+  /// Serialized as `{ issues : [ { package, kind, details, fixable, fixed } ] }`.
+  #[ derive( Debug, Default, Clone, serde::Serialize ) ]
+  pub struct LintReport
+  {
+    /// Problems found in manifests.
+    pub issues : Vec< lint::LintIssue >,
+  }
+
+  impl LintReport
+  {
+    /// Count of problems which were not fixed.
+    pub fn unresolved( &self ) -> usize
+    {
+      self.issues.iter().filter( | i | !i.fixed ).count()
+    }
+  }
+
+  impl fmt::Display for LintReport
+  {
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      if self.issues.is_empty()
+      {
+        return write!( f, "No problems found" );
+      }
+      for issue in &self.issues
+      {
+        writeln!( f, "{issue}" )?;
+      }
+      let fixed = self.issues.len() - self.unresolved();
+      let fixable = self.issues.iter().filter( | i | i.fixable && !i.fixed ).count();
+      write!( f, "Problems : {}, fixed : {fixed}", self.issues.len() )?;
+      if fixable > 0
+      {
+        write!( f, ", fixable : {fixable} ( use `fix:1` )" )?;
+      }
+
+      Ok( () )
+    }
+  }
+
+  /// Checks hygiene of manifests of packages : inheritance of dependencies from the workspace,
+  /// required fields, versions of path dependencies, references of features and presence of `Readme.md`.
+  ///
+  /// Returns an error if any problem is left unfixed.
+  ///
+  /// If Cargo can not load the workspace, only the manifest of the package in `crate_dir` is checked.
+  // qqq : typed error
+  pub fn lint( o : LintOptions ) -> ResultWithReport< LintReport, Error >
+  {
+    let mut report = LintReport::default();
+    let LintOptions { crate_dir, fix } = o;
+    let ( workspace_manifest_file, manifest_files ) = match Workspace::try_from( crate_dir.clone() )
+    {
+      Ok( workspace ) =>
+      {
+        let root = crate_dir.clone().absolute_path();
+        let manifest_files = workspace
+        .packages()
+        .filter_map( | p | p.manifest_file().ok() )
+        .filter( | m | m.clone().inner().starts_with( &root ) )
+        .collect::< Vec< _ > >();
+        ( lint::workspace_manifest_file( &workspace ), manifest_files )
+      }
+      // a broken manifest of the package is the likely reason
+      Err( e ) =>
+      {
+        let manifest_file = ManifestFile::from( crate_dir.clone() );
+        let is_package = Manifest::try_from( manifest_file.clone() ).is_ok_and( | m | m.package_is() );
+        if !is_package
+        {
+          return Err(( report, Error::from( e ).context( "Failed to find workspace" ) ));
+        }
+        ( lint::workspace_manifest_find( crate_dir.as_ref() ), vec![ manifest_file ] )
+      }
+    };
+    let workspace_manifest = ManifestFile::try_from( workspace_manifest_file )
+    .map_err( Error::from )
+    .and_then( | m | Manifest::try_from( m ).map_err( Error::from ) )
+    .context( "Failed to read manifest of the workspace" )
+    .err_with_report( &report )?;
+
+    for manifest_file in manifest_files
+    {
+      let mut manifest = Manifest::try_from( manifest_file ).err_with_report( &report )?;
+      let issues = lint::lint( &workspace_manifest, &mut manifest, fix );
+      if issues.iter().any( | i | i.fixed )
+      {
+        manifest.store().err_with_report( &report )?;
+      }
+      report.issues.extend( issues );
+    }
+
+    if report.unresolved() > 0
+    {
+      let count = report.unresolved();
+      return Err(( report, format_err!( "Found {count} problem(s) in manifests" ) ));
+    }
+
+    Ok( report )
+  }
+}
+
+crate::mod_interface!
+{
+  orphan use LintOptions;
+  orphan use LintReport;
+  /// Check hygiene of manifests.
+  orphan use lint;
+}
@@ -17,6 +17,7 @@ mod private
     untyped::{ Context, format_err },
   };
   use tool::{ TreePrinter, ListNodeReport };
+  use iter::Itertools;
 
   /// Args for `list` action.
   #[ derive( Debug, Default, Copy, Clone ) ]
@@ -27,6 +28,10 @@ mod private
     Tree,
     /// Topologically sorted list.
     Topological,
+    /// Graph in the DOT language of Graphviz.
+    Dot,
+    /// Graph in the Mermaid flowchart syntax.
+    Mermaid,
   }
 
   impl str::FromStr for ListFormat
@@ -39,7 +44,9 @@ mod private
       {
         "tree" => ListFormat::Tree,
         "toposort" => ListFormat::Topological,
-        e => return Err( err!( "Unknown format '{}'. Available values : [tree, toposort]", e ))
+        "dot" => ListFormat::Dot,
+        "mermaid" => ListFormat::Mermaid,
+        e => return Err( err!( "Unknown format '{}'. Available values : [tree, toposort, dot, mermaid]", e ))
       };
 
       Ok( value )
@@ -52,12 +59,14 @@ mod private
   /// - `Primary`: This category represents primary dependencies.
   /// - `Dev`: This category represents development dependencies.
   /// - `Build`: This category represents build-time dependencies.
-  #[ derive( Debug, Copy, Clone, Hash, Eq, PartialEq ) ]
+  #[ derive( Debug, Copy, Clone, Hash, Eq, PartialEq, serde::Serialize ) ]
+  #[ serde( rename_all = "lowercase" ) ]
   pub enum DependencyCategory
   {
     /// Represents the primary dependencies, i.e., libraries or packages that
     /// are required for your code to run. These are typically listed in your
     /// `Cargo.toml`'s `[dependencies]` section.
+    #[ serde( rename = "normal" ) ]
     Primary,
     /// Represents the development dependencies. These are used for compiling
     /// tests, examples, or benchmarking code. They are not used when compiling
@@ -70,12 +79,26 @@ mod private
     Build,
   }
 
+  impl fmt::Display for DependencyCategory
+  {
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      match self
+      {
+        Self::Primary => write!( f, "normal" ),
+        Self::Dev => write!( f, "dev" ),
+        Self::Build => write!( f, "build" ),
+      }
+    }
+  }
+
   /// Enum representing the source of a dependency.
   ///
   /// This enum has the following values :
   /// * `Local` - Represents a dependency located locally.
   /// * `Remote` - Represents a dependency fetched from a remote source.
-  #[ derive( Debug, Copy, Clone, Hash, Eq, PartialEq ) ]
+  #[ derive( Debug, Copy, Clone, Hash, Eq, PartialEq, serde::Serialize ) ]
+  #[ serde( rename_all = "lowercase" ) ]
   pub enum DependencySource
   {
     /// Represents a dependency that is located on the local file system.
@@ -261,14 +284,149 @@ mod private
   //   }
   // }
 
+  /// Node of a dependency graph.
+  #[ derive( Debug, Clone, PartialEq, Eq, serde::Serialize ) ]
+  pub struct GraphNode
+  {
+    /// Name of the package. Unique in the graph.
+    pub name : String,
+    /// Version of a local package or version requirement of a remote one, if requested.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub version : Option< String >,
+    /// Directory of a local package, if requested.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub crate_dir : Option< CrateDir >,
+    /// Where the package is located.
+    pub source : DependencySource,
+  }
+
+  impl GraphNode
+  {
+    /// Lines of the label of the node : the name, then the version and the directory if they are known.
+    fn label( &self ) -> Vec< String >
+    {
+      [ Some( self.name.clone() ), self.version.clone(), self.crate_dir.as_ref().map( | d | d.as_ref().display().to_string() ) ]
+      .into_iter()
+      .flatten()
+      .collect()
+    }
+  }
+
+  /// Edge of a dependency graph, from a dependent package to its dependency.
+  #[ derive( Debug, Clone, PartialEq, Eq, serde::Serialize ) ]
+  pub struct GraphEdge
+  {
+    /// Name of the dependent package.
+    pub from : String,
+    /// Name of the dependency.
+    pub to : String,
+    /// Kind of the dependency : `normal`, `dev` or `build`.
+    pub kind : DependencyCategory,
+  }
+
+  /// Graph of dependencies rendered as DOT or Mermaid, the `output` property serializes it as `{ nodes, edges }`.
+  ///
+  /// Edges are labeled by the kind of a dependency. Local packages are drawn as filled boxes,
+  /// remote ones as dashed outlines. Dependencies of remote packages are not included.
+  #[ derive( Debug, Default, Clone, serde::Serialize ) ]
+  pub struct DependencyGraph
+  {
+    /// Format of the text representation.
+    #[ serde( skip ) ]
+    pub format : ListFormat,
+    /// Packages.
+    pub nodes : Vec< GraphNode >,
+    /// Dependencies.
+    pub edges : Vec< GraphEdge >,
+  }
+
+  impl DependencyGraph
+  {
+    fn dot( &self ) -> String
+    {
+      let escape = | s : &str | s.replace( '\\', "\\\\" ).replace( '"', "\\\"" );
+      let mut dot = String::from( "digraph dependencies {\n  node [ shape = box ];\n" );
+      for node in &self.nodes
+      {
+        let style = match node.source
+        {
+          DependencySource::Local => "style = filled, fillcolor = lightblue",
+          DependencySource::Remote => "shape = ellipse, style = dashed",
+        };
+        let label = node.label().iter().map( | l | escape( l ) ).join( "\\n" );
+        dot.push_str( &format!( "  \"{}\" [ label = \"{label}\", {style} ];\n", escape( &node.name ) ) );
+      }
+      for edge in &self.edges
+      {
+        let style = match edge.kind
+        {
+          DependencyCategory::Primary => "",
+          DependencyCategory::Dev => ", style = dashed",
+          DependencyCategory::Build => ", style = dotted",
+        };
+        dot.push_str( &format!( "  \"{}\" -> \"{}\" [ label = \"{}\"{style} ];\n", escape( &edge.from ), escape( &edge.to ), edge.kind ) );
+      }
+      dot.push_str( "}\n" );
+
+      dot
+    }
+
+    fn mermaid( &self ) -> String
+    {
+      let id = | name : &str | self.nodes.iter().position( | n | n.name == name ).map( | i | format!( "n{i}" ) ).unwrap_or_default();
+      let mut mermaid = String::from( "graph TD\n" );
+      for ( i, node ) in self.nodes.iter().enumerate()
+      {
+        let label = node.label().join( "<br/>" ).replace( '"', "#quot;" );
+        match node.source
+        {
+          DependencySource::Local => mermaid.push_str( &format!( "  n{i}[\"{label}\"]:::local\n" ) ),
+          DependencySource::Remote => mermaid.push_str( &format!( "  n{i}([\"{label}\"]):::remote\n" ) ),
+        }
+      }
+      for edge in &self.edges
+      {
+        let arrow = match edge.kind
+        {
+          DependencyCategory::Primary => "-->",
+          DependencyCategory::Dev => "-.->",
+          DependencyCategory::Build => "==>",
+        };
+        mermaid.push_str( &format!( "  {} {arrow}|{}| {}\n", id( &edge.from ), edge.kind, id( &edge.to ) ) );
+      }
+      mermaid.push_str( "  classDef local fill:#d6eaf8,stroke:#2e86c1\n" );
+      mermaid.push_str( "  classDef remote fill:#ffffff,stroke:#999999,stroke-dasharray:5 5\n" );
+
+      mermaid
+    }
+  }
+
+  impl fmt::Display for DependencyGraph
+  {
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      match self.format
+      {
+        ListFormat::Mermaid => write!( f, "{}", self.mermaid() ),
+        _ => write!( f, "{}", self.dot() ),
+      }
+    }
+  }
+
   /// Represents the different report formats for the `list` action.
-  #[ derive( Debug, Default, Clone ) ]
+  ///
+  /// Serialized as `{ "tree" : [ node ] }`, `{ "list" : [ name ] }`, `{ "graph" : { nodes, edges } }` or `"empty"`, where `node` is
+  /// `{ name, version?, crate_dir?, duplicate, normal_dependencies, dev_dependencies, build_dependencies }`.
+  #[ derive( Debug, Default, Clone, serde::Serialize ) ]
+  #[ serde( rename_all = "snake_case" ) ]
   pub enum ListReport
   {
     /// Represents a tree-like report format.
     Tree( Vec< tool::TreePrinter > ),
     /// Represents a standard list report format in topological order.
     List( Vec< String > ),
+    /// Represents a graph of dependencies.
+    Graph( DependencyGraph ),
     /// Represents an empty report format.
     #[ default ]
     Empty,
@@ -296,6 +454,8 @@ mod private
           v.iter().enumerate().map( |( i, v )| format!( "[{i}] {v}" ) ).collect::< Vec< _ > >().join( "\n" )
         ),
 
+        Self::Graph( graph ) => write!( f, "{graph}" ),
+
         Self::Empty => write!( f, "Nothing" ),
       }
     }
@@ -487,7 +647,7 @@ mod private
         ListReport::Tree( ref mut v ) => ListReport::Tree
         ( { v.extend([ printer ]); v.clone() } ),
         ListReport::Empty => ListReport::Tree( vec![ printer ] ),
-        ListReport::List( _ ) => unreachable!(),
+        ListReport::List( _ ) | ListReport::Graph( _ ) => unreachable!(),
       };
       Ok( () )
     };
@@ -538,6 +698,23 @@ mod private
         let tree = merge_dev_dependencies( rep );
         report = ListReport::Tree( tree );
       }
+      ListFormat::Dot | ListFormat::Mermaid =>
+      {
+        let graph = if is_package
+        {
+          let package = workspace
+          .package_find_by_manifest( &manifest.manifest_file )
+          .ok_or_else( || format_err!( "Package not found in the workspace" ) )
+          .err_with_report( &report )?;
+          dependency_graph( &workspace, [ package ].into_iter(), &args )
+        }
+        else
+        {
+          dependency_graph( &workspace, workspace.packages(), &args )
+        }
+        .err_with_report( &report )?;
+        report = ListReport::Graph( graph );
+      }
       ListFormat::Topological =>
       {
 
@@ -685,6 +862,89 @@ mod private
     Ok( report )
   }
 
+  /// Collects packages reachable from the roots by dependencies which pass filters of the options.
+  fn dependency_graph< 'a >
+  (
+    workspace : &'a Workspace,
+    roots : impl Iterator< Item = WorkspacePackageRef< 'a > >,
+    args : &ListOptions,
+  )
+  -> error::untyped::Result< DependencyGraph >
+  {
+    let mut graph = DependencyGraph { format : args.format, ..Default::default() };
+    let mut queue = collection::VecDeque::new();
+    let local_node = | package : &WorkspacePackageRef< '_ > | -> error::untyped::Result< GraphNode >
+    {
+      Ok
+      (
+        GraphNode
+        {
+          name : package.name().to_string(),
+          version : args.info.contains( &PackageAdditionalInfo::Version ).then( || package.version().to_string() ),
+          crate_dir : if args.info.contains( &PackageAdditionalInfo::Path ) { Some( package.crate_dir()? ) } else { None },
+          source : DependencySource::Local,
+        }
+      )
+    };
+    for package in roots
+    {
+      if !graph.nodes.iter().any( | n | n.name == package.name() )
+      {
+        graph.nodes.push( local_node( &package )? );
+        queue.push_back( package );
+      }
+    }
+
+    while let Some( package ) = queue.pop_front()
+    {
+      for dependency in package.dependencies()
+      {
+        let kind = match dependency.kind()
+        {
+          DependencyKind::Normal => DependencyCategory::Primary,
+          DependencyKind::Development => DependencyCategory::Dev,
+          DependencyKind::Build => DependencyCategory::Build,
+          DependencyKind::Unknown => continue,
+        };
+        let source = if dependency.crate_dir().is_some() { DependencySource::Local } else { DependencySource::Remote };
+        if !args.dependency_categories.contains( &kind ) || !args.dependency_sources.contains( &source ) { continue }
+
+        let name = dependency.name();
+        if !graph.nodes.iter().any( | n | n.name == name )
+        {
+          let local = dependency
+          .crate_dir()
+          .and_then( | dir | workspace.package_find_by_manifest( dir.manifest_file() ) );
+          match local
+          {
+            Some( local ) =>
+            {
+              graph.nodes.push( local_node( &local )? );
+              queue.push_back( local );
+            }
+            None => graph.nodes.push
+            (
+              GraphNode
+              {
+                name : name.clone(),
+                version : args.info.contains( &PackageAdditionalInfo::Version ).then( || dependency.req().to_string() ),
+                crate_dir : if args.info.contains( &PackageAdditionalInfo::Path ) { dependency.crate_dir() } else { None },
+                source,
+              }
+            ),
+          }
+        }
+        let edge = GraphEdge { from : package.name().to_string(), to : name, kind };
+        if !graph.edges.contains( &edge )
+        {
+          graph.edges.push( edge );
+        }
+      }
+    }
+
+    Ok( graph )
+  }
+
   fn merge_build_dependencies( mut report: Vec< tool::TreePrinter > ) -> Vec< tool::TreePrinter >
   {
     let mut build_dependencies = vec![];
@@ -846,6 +1106,12 @@ crate::mod_interface!
   own use ListFilter;
   /// Contains output of the action.
   own use ListReport;
+  /// Graph of dependencies in the output of the action.
+  own use DependencyGraph;
+  /// Node of a graph of dependencies.
+  own use GraphNode;
+  /// Edge of a graph of dependencies.
+  own use GraphEdge;
   /// Contains output of a single node of the action.
   // own use ListNodeReport;
   /// List packages in workspace.
@@ -2,17 +2,7 @@ mod private
 {
   use crate::*;
   use std::fmt::{ Display, Formatter };
-  use std::fs::
-  {
-    OpenOptions
-  };
-  use std::io::
-  {
-    Read,
-    Seek,
-    SeekFrom,
-    Write
-  };
+  use std::fs;
   use std::path::PathBuf;
   use regex::Regex;
   use entity::{ PathError, WorkspaceInitError };
@@ -38,19 +28,24 @@ mod private
   }
 
   /// Report.
-  #[ derive( Debug, Default, Clone ) ]
+  #[ derive( Debug, Default, Clone, serde::Serialize ) ]
   pub struct MainHeaderRenewReport
   {
     found_file : Option< PathBuf >,
     touched_file : PathBuf,
     success : bool,
+    check : bool,
   }
 
   impl Display for MainHeaderRenewReport
   {
     fn fmt( &self, f : &mut Formatter< '_ > ) -> std::fmt::Result
     {
-      if self.success
+      if self.success && self.check
+      {
+        writeln!( f, "File is up to date : {}.", self.touched_file.display() )?;
+      }
+      else if self.success
       {
         if let Some( file_path ) = self.touched_file.to_str()
         {
@@ -96,6 +91,9 @@ mod private
     /// Represents an error related to directory paths.
     #[ error( "Directory error: {0}" ) ]
     Directory( #[ from ] PathError ),
+    /// Represents a difference between the generated header and the file in the check mode.
+    #[ error( "{0}" ) ]
+    Drift( #[ from ] drift::DriftError ),
   }
 
   /// The `HeaderParameters` structure represents a set of parameters, used for creating url for header.
@@ -193,11 +191,13 @@ mod private
   /// [![docs.rs](https://raster.shields.io/static/v1?label=docs&message=online&color=eee&logo=docsdotrs&logoColor=eee)](https://docs.rs/wtools)
   /// <!--{ generate.main_header.end }-->
   /// ```
-  pub fn readme_header_renew( crate_dir : CrateDir )
+  ///
+  /// If `check` is set nothing is written : the action fails with a unified diff if the file differs from the generated one.
+  pub fn readme_header_renew( crate_dir : CrateDir, check : bool )
   // -> Result< MainHeaderRenewReport, ( MainHeaderRenewReport, MainHeaderRenewError ) >
   -> ResultWithReport< MainHeaderRenewReport, MainHeaderRenewError >
   {
-    let mut report = MainHeaderRenewReport::default();
+    let mut report = MainHeaderRenewReport { check, ..Default::default() };
     regexes_initialize();
 
     let workspace = Workspace::try_from
@@ -220,14 +220,7 @@ mod private
 
     report.found_file = Some( read_me_path.clone().to_path_buf() );
 
-    let mut file = OpenOptions::new()
-    .read( true )
-    .write( true )
-    .open( &read_me_path )
-    .err_with_report( &report )?;
-
-    let mut content = String::new();
-    file.read_to_string( &mut content ).err_with_report( &report )?;
+    let content = fs::read_to_string( &read_me_path ).err_with_report( &report )?;
 
     let raw_params = TAGS_TEMPLATE
     .get()
@@ -253,9 +246,9 @@ mod private
       )
     ).into();
 
-    file.set_len( 0 ).err_with_report( &report )?;
-    file.seek( SeekFrom::Start( 0 ) ).err_with_report( &report )?;
-    file.write_all( content.as_bytes() ).err_with_report( &report )?;
+    let mut files = drift::GeneratedFiles::new( check );
+    files.write( &read_me_path, content ).err_with_report( &report )?;
+    files.finish().err_with_report( &report )?;
     report.touched_file = read_me_path.to_path_buf();
     report.success = true;
     Ok( report )
@@ -1,25 +1,37 @@
 crate::mod_interface!
 {
+  /// Find unused and duplicated dependencies.
+  layer dependencies_check;
   /// Deploy new.
   layer deploy_renew;
+  /// Check hygiene of manifests.
+  layer lint;
   /// List packages.
   layer list;
   /// Main Header.
   layer main_header;
   /// Publish packages.
   layer publish;
+  /// Check that the version about to be published allows changes of the public API.
+  layer publish_check;
   /// Return the differences between a local and remote package versions.
   layer publish_diff;
+  /// Roll back the interrupted publication.
+  layer publish_rollback;
   /// Generates health table in main Readme.md file of workspace.
   layer readme_health_table_renew;
   /// Module headers.
   layer readme_modules_headers_renew;
   /// Run all tests
   layer test;
+  /// Prune the test cache.
+  layer test_cache_prune;
   /// Workflow.
   layer cicd_renew;
   /// Workspace new.
   layer workspace_renew;
   /// List features.
   layer features;
+  /// Renew changelogs.
+  layer changelog_renew;
 }
@@ -9,18 +9,38 @@ mod private
     // error::untyped,
     error::ErrWith,
   };
+  use publish_journal::{ PublishJournal, PublishStage };
 
   /// Represents a report of publishing packages
-  #[ derive( Debug, Default, Clone ) ]
+  ///
+  /// Serialized as `{ workspace_root_dir?, plan?, packages : [ { path, report } ] }`.
+  #[ derive( Debug, Default, Clone, serde::Serialize ) ]
   pub struct PublishReport
   {
     /// Represents the absolute path to the root directory of the workspace.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
     pub workspace_root_dir : Option< AbsolutePath >,
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
     pub plan : Option< publish::PublishPlan >,
     /// Represents a collection of packages and their associated publishing reports.
+    #[ serde( serialize_with = "packages_serialize" ) ]
     pub packages : Vec<( AbsolutePath, publish::PublishReport )>
   }
 
+  fn packages_serialize< S >( packages : &[ ( AbsolutePath, publish::PublishReport ) ], serializer : S ) -> Result< S::Ok, S::Error >
+  where
+    S : serde::Serializer,
+  {
+    #[ derive( serde::Serialize ) ]
+    struct PackageView< 'a >
+    {
+      path : &'a AbsolutePath,
+      report : &'a publish::PublishReport,
+    }
+
+    serializer.collect_seq( packages.iter().map( |( path, report )| PackageView { path, report } ) )
+  }
+
   impl fmt::Display for PublishReport
   {
     fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
@@ -111,6 +131,8 @@ mod private
   /// * `patterns` - A vector of patterns specifying the folders to search for packages.
   /// * `dry` - A boolean value indicating whether to perform a dry run.
   /// * `temp` - A boolean value indicating whether to use a temporary directory.
+  /// * `concurrent` - Maximum count of independent packages published at the same time.
+  /// * `with_progress` - Shows progress of packages published concurrently.
   ///
   /// # Returns
   /// A Result containing a `PublishPlan` if successful, or an `Error` otherwise.
@@ -120,7 +142,9 @@ mod private
     patterns : Vec< String >,
     channel : channel::Channel,
     dry : bool,
-    temp : bool
+    temp : bool,
+    concurrent : u32,
+    with_progress : bool,
   )
   -> Result< publish::PublishPlan, error::untyped::Error >
   // qqq : use typed error
@@ -156,13 +180,31 @@ mod private
     let workspace_root_dir : AbsolutePath = workspace
     .workspace_root()
     .try_into()?;
+    let registry = registry::Registry::try_from( &workspace )?;
+
+    let journal_file = PublishJournal::file( &workspace );
+    let journal = PublishJournal::load( &journal_file )?;
 
     let packages = workspace.packages();
-    let packages_to_publish : Vec< String > = packages
+    let mut packages_to_publish : Vec< String > = packages
     .clone()
     .filter( | &package | paths.contains( &package.crate_dir().unwrap().into() ) )
     .map( | p | p.name().to_string() )
     .collect();
+    // packages of the interrupted publication are published even if they were not changed
+    let unfinished : Vec< String > = journal
+    .iter()
+    .flat_map( | j | j.unfinished() )
+    .filter( | e | packages.clone().any( | p | p.name() == e.name ) )
+    .map( | e | e.name.clone() )
+    .collect();
+    for name in &unfinished
+    {
+      if !packages_to_publish.contains( name )
+      {
+        packages_to_publish.push( name.clone() );
+      }
+    }
     let package_map : collection::HashMap< String, package::Package< '_ > > = packages
     .map( | p | ( p.name().to_string(), package::Package::from( p ) ) )
     .collect();
@@ -213,30 +255,56 @@ mod private
       &package_map,
       &tmp,
       &packages_to_publish,
-      dir.clone()
+      &unfinished,
+      dir.clone(),
+      &registry,
     )?;
     let subgraph = subgraph
     .map( | _, n | n, | _, e | e );
 
-    let queue : Vec< _ > = graph::toposort( subgraph )
-    .unwrap()
+    // packages of a layer do not depend on each other, so they can be published concurrently
+    let layers : Vec< Vec< _ > > = graph::topological_sort_with_grouping( subgraph )
     .into_iter()
-    .map( | n | package_map.get( &n ).unwrap() )
-    .cloned()
+    .map
+    (
+      | mut layer |
+      {
+        layer.sort();
+        layer.into_iter().map( | n | package_map.get( &n ).unwrap() ).cloned().collect()
+      }
+    )
     .collect();
 
     let roots : Vec< _ > = packages_to_publish
     .iter()
     .map( | p | package_map.get( p ).unwrap().crate_dir() ).collect();
 
-    let plan = publish::PublishPlan::former()
+    let mut plan = publish::PublishPlan::former()
     .channel( channel )
     .workspace_dir( CrateDir::try_from( workspace_root_dir ).unwrap() )
     .option_base_temp_dir( dir.clone() )
+    .registry( registry )
     .dry( dry )
     .roots( roots )
-    .packages( queue )
-    .form();
+    .journal( journal_file )
+    .concurrent( concurrent )
+    .with_progress( with_progress );
+    for layer in layers
+    {
+      plan = plan.layer( layer );
+    }
+    let mut plan = plan.form();
+
+    // committed packages of the interrupted publication are not bumped again, they are finished with versions they were committed with
+    for instruction in &mut plan.plans
+    {
+      let Some( entry ) = journal.as_ref().and_then( | j | j.entry( instruction.package_name.as_ref() ) ) else { continue };
+      if entry.stage < PublishStage::Committed { continue }
+      instruction.bump.old_version = entry.old_version.parse()?;
+      instruction.bump.new_version = entry.new_version.parse()?;
+      instruction.bump.level = None;
+      instruction.bump.reason = Some( format!( "{} by the interrupted publication", entry.stage ) );
+    }
 
     Ok( plan )
   }
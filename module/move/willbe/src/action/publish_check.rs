@@ -0,0 +1,152 @@
+/// Internal namespace.
+mod private
+{
+  use crate::*;
+
+  use std::fmt;
+  use path::PathBuf;
+  use crates_tools::CrateArchive;
+  use error::untyped::{ Result, format_err };
+  use public_api::{ PublicApiDiff, public_api_diff };
+  use version::BumpLevel;
+
+  /// Options for `publish_check` command
+  #[ derive( Debug, former::Former ) ]
+  pub struct PublishCheckOptions
+  {
+    path : PathBuf,
+    /// The version about to be published. If not specified, it is the version of the manifest when it is greater than the released one,
+    /// the default bump of the released version otherwise.
+    version : Option< Version >,
+    /// Registry to compare with. Taken from the workspace metadata if not specified.
+    registry : Option< registry::Registry >,
+  }
+
+  /// Compatibility of the public API of a local package with its last release.
+  ///
+  /// Serialized as `{ name, released_version, planned_version, required, allowed?, diff : { added, removed, changed } }`.
+  #[ derive( Debug, Clone, serde::Serialize ) ]
+  pub struct PublishCheckReport
+  {
+    /// Name of the package.
+    pub name : String,
+    /// Version of the last release, the local package is compared with it.
+    pub released_version : Version,
+    /// Version about to be published.
+    pub planned_version : Version,
+    /// Level of changes of the public API.
+    pub required : BumpLevel,
+    /// The highest level of changes the planned version allows. `None` if the planned version is not greater than the released one.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub allowed : Option< BumpLevel >,
+    /// Changes of the public API.
+    pub diff : PublicApiDiff,
+  }
+
+  impl PublishCheckReport
+  {
+    /// Returns `true` if the planned version allows the changes of the public API.
+    pub fn is_sufficient( &self ) -> bool
+    {
+      self.allowed.is_some_and( | allowed | allowed >= self.required )
+    }
+  }
+
+  impl fmt::Display for PublishCheckReport
+  {
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      writeln!( f, "Package `{}` : {} -> {}", self.name, self.released_version, self.planned_version )?;
+      if self.diff.removed.is_empty() && self.diff.changed.is_empty()
+      {
+        writeln!( f, "No breaking changes" )?;
+      }
+      else
+      {
+        writeln!( f, "Breaking changes :" )?;
+        for item in &self.diff.removed { writeln!( f, "  - {item}" )?; }
+        for item in &self.diff.changed { writeln!( f, "  ~ {item}" )?; }
+      }
+      if !self.diff.added.is_empty()
+      {
+        writeln!( f, "Additions :" )?;
+        for item in &self.diff.added { writeln!( f, "  + {item}" )?; }
+      }
+      let allowed = self.allowed.map_or_else( || "nothing".to_string(), | l | l.to_string() );
+      write!( f, "Required bump : {}, planned bump allows : {allowed}", self.required )
+    }
+  }
+
+  /// Compares the public API of a local package with its last release and finds the level of changes the version about to be published allows.
+  ///
+  /// The released version is the greatest version which has a local release tag `name-vX.Y.Z` or is published in the registry, not the version of the manifest.
+  /// Its archive is taken from the release tag if it exists, otherwise it is downloaded from the registry.
+  /// Use `PublishCheckReport::is_sufficient` to find out whether the planned version allows the changes.
+  #[ cfg_attr( feature = "tracing", tracing::instrument ) ]
+  pub fn publish_check( o : PublishCheckOptions ) -> Result< PublishCheckReport >
+  // qqq : don't use 1-prameter Result
+  {
+    let path = AbsolutePath::try_from( o.path )?;
+    let dir = CrateDir::try_from( path )?;
+    let registry = match o.registry
+    {
+      Some( registry ) => registry,
+      None => registry::Registry::try_from( &Workspace::try_from( dir.clone() )? )?,
+    };
+
+    let package = package::Package::try_from( dir.clone() )?;
+    let name = package.name()?.to_string();
+    let version = package.version()?;
+    let manifest_version : Version = version.as_str().try_into()?;
+    // a directory outside of a git repository has no tags
+    let mut released = packed_crate::tagged_versions( &name, &dir ).unwrap_or_default();
+    match registry.versions( &name )
+    {
+      Ok( versions ) => released.extend( versions ),
+      Err( e ) if released.is_empty() => return Err( format_err!( "Failed to get released versions of `{name}` : {e}" ) ),
+      Err( _ ) => {}
+    }
+    let released_version = released
+    .into_iter()
+    .max()
+    .map( Version::from )
+    .ok_or_else( || format_err!( "`{name}` has neither release tags nor versions in the registry `{registry}`" ) )?;
+    let planned_version = o.version.unwrap_or_else( ||
+    {
+      if manifest_version > released_version { manifest_version } else { released_version.clone().bump() }
+    });
+
+    _ = cargo::pack
+    (
+      cargo::PackOptions::former()
+      .path( dir.as_ref() )
+      .allow_dirty( true )
+      .checking_consistency( false )
+      .dry( false ).form()
+    )?;
+    let local = CrateArchive::read( packed_crate::local_path( &name, &version, dir.clone() )? )?;
+    let released = packed_crate::published( &registry, &name, &released_version.to_string(), dir )
+    .map_err( | e | format_err!( "Failed to get the released version `{released_version}` of `{name}` : {e}" ) )?;
+    let diff = public_api_diff( &local, &released );
+
+    Ok( PublishCheckReport
+    {
+      name,
+      allowed : released_version.level_allowed( &planned_version ),
+      released_version,
+      planned_version,
+      required : diff.level(),
+      diff,
+    })
+  }
+}
+
+//
+
+crate::mod_interface!
+{
+  orphan use PublishCheckOptions;
+  orphan use PublishCheckReport;
+  /// Checks that the version about to be published allows changes of the public API.
+  orphan use publish_check;
+}
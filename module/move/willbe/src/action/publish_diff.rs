@@ -12,7 +12,7 @@ mod private
   use action::list::ListReport;
   use error::untyped::Result;
   // qqq : group dependencies
-  use diff::{ DiffReport, crate_diff };
+  use diff::{ DiffOptions, DiffReport, ManifestDiff, crate_diff_with, manifest_diff };
   use error::untyped::format_err;
   use tool::ListNodeReport;
   use tool::TreePrinter;
@@ -23,16 +23,41 @@ mod private
   {
     path : PathBuf,
     keep_archive : Option< PathBuf >,
+    /// Registry to compare with. Taken from the workspace metadata if not specified.
+    registry : Option< registry::Registry >,
+    /// Options of comparison of files. Default options if not specified.
+    diff : Option< DiffOptions >,
   }
 
-  #[ derive( Debug ) ]
+  /// Differences between local and published versions of packages.
+  ///
+  /// Serialized as `{ diffs : { crate_dir : DiffReport }, manifests : { crate_dir : ManifestDiff }, root_path, tree : node }`.
+  #[ derive( Debug, serde::Serialize ) ]
   pub struct PublishDiffReport
   {
+    #[ serde( serialize_with = "diffs_serialize" ) ]
     pub diffs : HashMap< AbsolutePath, DiffReport >,
+    /// Differences between parsed manifests of packages.
+    #[ serde( serialize_with = "diffs_serialize" ) ]
+    pub manifests : HashMap< AbsolutePath, ManifestDiff >,
     pub root_path : AbsolutePath,
     pub tree : ListNodeReport,
   }
 
+  fn diffs_serialize< S, T >( diffs : &HashMap< AbsolutePath, T >, serializer : S ) -> std::result::Result< S::Ok, S::Error >
+  where
+    S : serde::Serializer,
+    T : serde::Serialize,
+  {
+    serializer.collect_map
+    (
+      diffs
+      .iter()
+      .map( |( path, diff )| ( path.as_ref().display().to_string(), diff ) )
+      .sorted_by( |( l, _ ), ( r, _ )| l.cmp( r ) )
+    )
+  }
+
   impl std::fmt::Display for PublishDiffReport
   {
     fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> std::fmt::Result
@@ -92,18 +117,31 @@ mod private
         writeln!( f, "No changes found in `{root_name} {root_version}`. Files:" )?;
       }
       write!( f, "{}", diff )?;
+      if let Some( manifest ) = self.manifests.get( &root ).filter( | m | m.has_changes() )
+      {
+        writeln!( f, "Manifest changes :" )?;
+        write!( f, "{}", manifest )?;
+      }
 
       Ok( () )
     }
   }
 
   /// Return the differences between a local and remote package versions.
+  ///
+  /// The remote version is taken from the local release tag `name-vX.Y.Z` if it exists, otherwise it is downloaded from the registry.
+  /// Modified text files are compared line by line, manifests are compared by versions and requirements of dependencies.
   #[ cfg_attr( feature = "tracing", tracing::instrument ) ]
   pub fn publish_diff( o : PublishDiffOptions ) -> Result< PublishDiffReport >
   // qqq : don't use 1-prameter Result
   {
     let path = AbsolutePath::try_from( o.path )?;
     let dir = CrateDir::try_from( path.clone() )?;
+    let registry = match o.registry
+    {
+      Some( registry ) => registry,
+      None => registry::Registry::try_from( &Workspace::try_from( dir.clone() )? )?,
+    };
 
     let list = action::list
     (
@@ -122,7 +160,9 @@ mod private
       return Err( format_err!( "Logical error. Unexpected list format" ) )
     };
     let mut tasks = vec![ tree[ 0 ].clone() ];
+    let diff_options = o.diff.unwrap_or_default();
     let mut diffs = HashMap::new();
+    let mut manifests = HashMap::new();
     let mut current_idx = 0;
     while current_idx < tasks.len()
     {
@@ -149,8 +189,8 @@ mod private
       .checking_consistency( false )
       .dry( false ).form()
     )?;
-    let l = CrateArchive::read( packed_crate::local_path( name, version, dir )? )?;
-    let r = CrateArchive::download_crates_io( name, version ).unwrap();
+    let l = CrateArchive::read( packed_crate::local_path( name, version, dir.clone() )? )?;
+    let r = packed_crate::published( &registry, name, version, dir )?;
 
 
       if let Some( out_path ) = &o.keep_archive
@@ -167,7 +207,8 @@ mod private
           std::fs::write( local_path, content )?;
         }
       }
-      diffs.insert( path, crate_diff( &l, &r ).exclude( diff::PUBLISH_IGNORE_LIST ) );
+      manifests.insert( path.clone(), manifest_diff( &l, &r ) );
+      diffs.insert( path, crate_diff_with( &l, &r, &diff_options ).exclude( diff::PUBLISH_IGNORE_LIST ) );
       let report = tasks[ current_idx ].info.normal_dependencies.clone();
       let printer : Vec< TreePrinter > = report
       .iter()
@@ -186,6 +227,7 @@ mod private
     {
       root_path : path.clone(),
       diffs,
+      manifests,
       tree : rep.remove( 0 ),
     };
 
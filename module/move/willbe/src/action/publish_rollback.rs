@@ -0,0 +1,119 @@
+/// Internal namespace.
+mod private
+{
+  use crate::*;
+
+  use std::fmt;
+  use path::PathBuf;
+  use former::Former;
+  use process_tools::process;
+  use error::
+  {
+    ErrWith,
+    untyped::{ Error, Context },
+  };
+  use publish_journal::{ JournalEntry, PublishJournal, PublishStage };
+
+  /// Options for `publish_rollback`.
+  #[ derive( Debug, Former ) ]
+  pub struct PublishRollbackOptions
+  {
+    /// Directory of the workspace the publication was made in.
+    crate_dir : CrateDir,
+    /// Do not reset anything, only report what would be reset.
+    #[ former( default = true ) ]
+    dry : bool,
+  }
+
+  /// Report of `publish_rollback`.
+  ///
+  /// Serialized as `{ journal?, dry, rolled_back : [ entry ], published : [ entry ], resets : [ { command, current_path, out, err, error? } ] }`,
+  /// where `entry` is `{ name, crate_dir, old_version, new_version, stage, commit? }`.
+  #[ derive( Debug, Default, Clone, serde::Serialize ) ]
+  pub struct PublishRollbackReport
+  {
+    /// Path to the journal of the interrupted publication. `None` if there is no such publication.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub journal : Option< PathBuf >,
+    /// Nothing was reset.
+    pub dry : bool,
+    /// Packages which were committed, but never reached the registry.
+    pub rolled_back : Vec< JournalEntry >,
+    /// Packages which reached the registry, but were not pushed. They are finished by the next `.publish`.
+    pub published : Vec< JournalEntry >,
+    /// Reports of resetting commits.
+    #[ serde( serialize_with = "output::process_report_vec_serialize" ) ]
+    pub resets : Vec< process::Report >,
+  }
+
+  impl fmt::Display for PublishRollbackReport
+  {
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      let Some( journal ) = &self.journal else
+      {
+        return write!( f, "No interrupted publication" );
+      };
+      writeln!( f, "Journal : {}", journal.display() )?;
+      for reset in &self.resets
+      {
+        write!( f, "{reset}" )?;
+      }
+      if self.rolled_back.is_empty()
+      {
+        writeln!( f, "Nothing to roll back" )?;
+      }
+      else
+      {
+        writeln!( f, "{} :", if self.dry { "To roll back" } else { "Rolled back" } )?;
+        for entry in &self.rolled_back
+        {
+          writeln!( f, "  {} ({} -> {})", entry.name, entry.old_version, entry.new_version )?;
+        }
+      }
+      if !self.published.is_empty()
+      {
+        writeln!( f, "Reached the registry, `.publish dry:0` finishes them :" )?;
+        for entry in &self.published
+        {
+          writeln!( f, "  {entry}" )?;
+        }
+      }
+
+      Ok( () )
+    }
+  }
+
+  /// Rolls back the interrupted publication : resets commits with bumped versions of packages which never reached the registry.
+  ///
+  /// Packages return to the `planned` stage of the journal. The journal is removed if none of its packages reached the registry.
+  // qqq : typed error
+  pub fn publish_rollback( o : PublishRollbackOptions ) -> ResultWithReport< PublishRollbackReport, Error >
+  {
+    let mut report = PublishRollbackReport { dry : o.dry, ..Default::default() };
+    let workspace = Workspace::try_from( o.crate_dir )
+    .context( "Failed to find workspace" )
+    .err_with_report( &report )?;
+    let file = PublishJournal::file( &workspace );
+    let Some( mut journal ) = PublishJournal::load( &file ).err_with_report( &report )? else { return Ok( report ) };
+
+    report.journal = Some( file );
+    report.rolled_back = journal.entries.iter().filter( | e | e.stage == PublishStage::Committed ).cloned().collect();
+    report.published = journal.unfinished().filter( | e | e.stage >= PublishStage::Published ).cloned().collect();
+    report.resets = journal.rollback( o.dry ).err_with_report( &report )?;
+    if !o.dry && journal.entries.iter().all( | e | e.stage == PublishStage::Planned )
+    {
+      journal.remove().err_with_report( &report )?;
+    }
+
+    Ok( report )
+  }
+}
+
+crate::mod_interface!
+{
+  orphan use PublishRollbackOptions;
+  orphan use PublishRollbackReport;
+  /// Roll back the interrupted publication.
+  orphan use publish_rollback;
+}
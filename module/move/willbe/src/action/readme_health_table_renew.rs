@@ -4,8 +4,8 @@ mod private
 
   use std::
   {
-    fs::{ OpenOptions, File },
-    io::{ Write, Read, Seek, SeekFrom },
+    fs::{ self, File },
+    io::Read,
   };
   use path::{ Path, PathBuf };
   use convert_case::Casing;
@@ -64,7 +64,9 @@ mod private
     #[ error( "Utf8Error error: {0}" ) ]
     Utf8Error( #[ from ] std::str::Utf8Error ),
     #[ error( "Toml edit error: {0}" ) ]
-    Toml( #[ from ] toml_edit::TomlError )
+    Toml( #[ from ] toml_edit::TomlError ),
+    #[ error( "{0}" ) ]
+    Drift( #[ from ] drift::DriftError ),
   }
 
   /// `Stability` is an enumeration that represents the stability level of a feature.
@@ -143,6 +145,8 @@ mod private
     include_docs : bool,
     // include sample column flag
     include : bool,
+    // include coverage column flag
+    include_coverage : bool,
   }
 
   impl From< HashMap< String, query::Value > > for TableOptions
@@ -169,6 +173,11 @@ mod private
       .map( | v | bool::from( v ) )
       .unwrap_or( true );
 
+      let include_coverage = value
+      .get( "with_coverage" )
+      .map( | v | bool::from( v ) )
+      .unwrap_or( false );
+
       let b_p = value.get( "1" );
       let base_path = if let Some( query::Value::String( path ) ) = value.get( "path" ).or( b_p )
       {
@@ -184,7 +193,8 @@ mod private
         include_branches,
         include_stability,
         include_docs,
-        include
+        include,
+        include_coverage,
       }
     }
   }
@@ -259,9 +269,11 @@ mod private
   /// will mean that at this place the table with modules located in the directory module/core will be generated.
   /// The tags do not disappear after generation.
   /// Anything between the opening and closing tag will be destroyed.
+  ///
+  /// If `check` is set nothing is written : the action fails with a unified diff if the file differs from the generated one.
   // aaa : for Petro : typed errors
   // aaa : done
-  pub fn readme_health_table_renew( path : &Path ) -> Result< (), HealthTableRenewError >
+  pub fn readme_health_table_renew( path : &Path, check : bool ) -> Result< (), HealthTableRenewError >
   {
     regexes_initialize();
     let workspace = Workspace::try_from( CrateDir::try_from( path )? )?;
@@ -273,14 +285,7 @@ mod private
 
     let read_me_path = workspace_root
     .join( repository::readme_path( &workspace_root )? );
-    let mut file = OpenOptions::new()
-    .read( true )
-    .write( true )
-    .open( &read_me_path )?;
-
-    let mut contents = Vec::new();
-
-    file.read_to_end( &mut contents )?;
+    let contents = fs::read( &read_me_path )?;
 
     let mut tags_closures = vec![];
     let mut tables = vec![];
@@ -318,19 +323,21 @@ mod private
         }
       }
     }
-    tables_write_into_file( tags_closures, tables, contents, file )?;
+    let content = tables_insert( tags_closures, tables, contents )?;
+    let mut files = drift::GeneratedFiles::new( check );
+    files.write( &read_me_path, content )?;
+    files.finish()?;
 
     Ok( () )
   }
 
-  /// Writes tables into a file at specified positions.
-  fn tables_write_into_file
+  /// Inserts tables into the content at specified positions.
+  fn tables_insert
   (
     tags_closures : Vec< ( usize, usize ) >,
     tables: Vec< String >,
     contents: Vec< u8 >,
-    mut file: File
-  ) -> Result< (), HealthTableRenewError >
+  ) -> Result< Vec< u8 >, HealthTableRenewError >
   {
     let mut buffer: Vec< u8 > = vec![];
     let mut start: usize = 0;
@@ -346,10 +353,7 @@ mod private
       start = *start_of_end_tag;
     }
     range_to_target_copy( &*contents,&mut buffer,start,contents.len() - 1 )?;
-    file.set_len( 0 )?;
-    file.seek( SeekFrom::Start( 0 ) )?;
-    file.write_all( &buffer )?;
-    Ok(())
+    Ok( buffer )
   }
 
   /// Generate table from `table_parameters`.
@@ -413,12 +417,22 @@ ensure that at least one remotest is present in git. ",
         )?;
         parameters.user_and_repo = url::git_info_extract( &parameters.core_url )?;
       }
+      let coverage = if table_parameters.include_coverage
+      {
+        // a module which was not tested with coverage has an empty cell
+        coverage::Coverage::read( coverage::lcov_path( workspace.target_directory().join( coverage::COVERAGE_DIR ), &package_name ) ).ok()
+      }
+      else
+      {
+        None
+      };
       table.push_str
       (
         &row_generate
         (
           &package_name,
           stability.as_ref(),
+          coverage.as_ref(),
           parameters,
           &table_parameters
         )
@@ -493,11 +507,12 @@ ensure that at least one remotest is present in git. ",
     Ok( names )
   }
 
-  /// Generate row that represents a module, with a link to it in the repository and optionals for stability, branches, documentation and links to the gitpod.
+  /// Generate row that represents a module, with a link to it in the repository and optionals for stability, branches, documentation, links to the gitpod and coverage.
   fn row_generate
   (
     module_name : &str,
     stability : Option< &Stability >,
+    coverage : Option< &coverage::Coverage >,
     parameters : &GlobalTableOptions,
     table_parameters : &TableOptions
   ) -> String
@@ -556,6 +571,11 @@ ensure that at least one remotest is present in git. ",
       };
       rou.push_str( &format!( " {} |", example ) );
     }
+    if table_parameters.include_coverage
+    {
+      let badge = coverage.map( coverage::coverage_badge ).unwrap_or_default();
+      rou.push_str( &format!( " {badge} |" ) );
+    }
     format!( "{rou}\n" )
   }
 
@@ -660,6 +680,12 @@ ensure that at least one remotest is present in git. ",
       separator.push_str( ":------:|" );
     }
 
+    if table_parameters.include_coverage
+    {
+      header.push_str( " Coverage |" );
+      separator.push_str( ":--------:|" );
+    }
+
     format!( "{}\n{}\n", header, separator )
   }
 
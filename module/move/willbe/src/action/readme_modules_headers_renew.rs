@@ -4,15 +4,8 @@ mod private
   use std::
   {
     borrow::Cow,
-    fs::OpenOptions,
+    fs,
     fmt,
-    io::
-    {
-      Read,
-      Seek,
-      Write,
-      SeekFrom,
-    }
   };
   use collection::BTreeSet;
   // use path::AbsolutePath;
@@ -54,11 +47,12 @@ mod private
   }
 
   /// Report.
-  #[ derive( Debug, Default, Clone ) ]
+  #[ derive( Debug, Default, Clone, serde::Serialize ) ]
   pub struct ModulesHeadersRenewReport
   {
     found_files : BTreeSet< PathBuf >,
     touched_files : BTreeSet< PathBuf >,
+    check : bool,
   }
 
   impl fmt::Display for ModulesHeadersRenewReport
@@ -76,7 +70,7 @@ mod private
         )?;
         return Ok(())
       }
-      writeln!( f, "Touched files :" )?;
+      writeln!( f, "{}", if self.check { "Up to date files :" } else { "Touched files :" } )?;
       let mut count = self.found_files.len();
       for path in &self.touched_files
       {
@@ -114,6 +108,9 @@ mod private
     /// Represents an error related to directory paths.
     #[ error( "Directory error: {0}" ) ]
     Directory( #[ from ] PathError ),
+    /// Represents differences between generated headers and files in the check mode.
+    #[ error( "{0}" ) ]
+    Drift( #[ from ] drift::DriftError ),
   }
 
   /// The `ModuleHeader` structure represents a set of parameters, used for creating url for header.
@@ -239,11 +236,14 @@ mod private
   /// [![experimental](https://raster.shields.io/static/v1?label=&message=experimental&color=orange)](https://github.com/emersion/stability-badges#experimental) | [![rust-status](https://github.com/Username/test/actions/workflows/ModuleChainOfPackagesAPush.yml/badge.svg)](https://github.com/Username/test/actions/workflows/ModuleChainOfPackagesAPush.yml)[![docs.rs](https://img.shields.io/docsrs/_chain_of_packages_a?color=e3e8f0&logo=docs.rs)](https://docs.rs/_chain_of_packages_a)[![Open in Gitpod](https://raster.shields.io/static/v1?label=try&message=online&color=eee&logo=gitpod&logoColor=eee)](https://gitpod.io/#RUN_PATH=.,SAMPLE_FILE=sample%2Frust%2F_chain_of_packages_a_trivial%2Fsrc%2Fmain.rs,RUN_POSTFIX=--example%20_chain_of_packages_a_trivial/https://github.com/Username/test)
   /// <!--{ generate.module_header.end }-->
   /// ```
-  pub fn readme_modules_headers_renew( crate_dir : CrateDir )
+  ///
+  /// If `check` is set nothing is written : the action fails with a unified diff of each file which differs from the generated one.
+  pub fn readme_modules_headers_renew( crate_dir : CrateDir, check : bool )
   -> ResultWithReport< ModulesHeadersRenewReport, ModulesHeadersRenewError >
   // -> Result< ModulesHeadersRenewReport, ( ModulesHeadersRenewReport, ModulesHeadersRenewError ) >
   {
-    let mut report = ModulesHeadersRenewReport::default();
+    let mut report = ModulesHeadersRenewReport { check, ..Default::default() };
+    let mut files = drift::GeneratedFiles::new( check );
     regexes_initialize();
     let workspace = Workspace::try_from( crate_dir )
     .err_with_report( &report )?;
@@ -288,14 +288,7 @@ mod private
       let header = ModuleHeader::from_cargo_toml( pakage.into(), &discord_url )
       .err_with_report( &report )?;
 
-      let mut file = OpenOptions::new()
-      .read( true )
-      .write( true )
-      .open( &read_me_path )
-      .err_with_report( &report )?;
-
-      let mut content = String::new();
-      file.read_to_string( &mut content ).err_with_report( &report )?;
+      let content = fs::read_to_string( &read_me_path ).err_with_report( &report )?;
 
       let raw_params = TAGS_TEMPLATE
       .get()
@@ -316,11 +309,14 @@ mod private
         workspace.workspace_root().to_str().unwrap()
       ).err_with_report( &report )?;
 
-      file.set_len( 0 ).err_with_report( &report )?;
-      file.seek( SeekFrom::Start( 0 ) ).err_with_report( &report )?;
-      file.write_all( content.as_bytes() ).err_with_report( &report )?;
-      report.touched_files.insert( path.as_ref().to_path_buf() );
+      let drifts = files.drifts().len();
+      files.write( &read_me_path, content.as_bytes() ).err_with_report( &report )?;
+      if files.drifts().len() == drifts
+      {
+        report.touched_files.insert( path.as_ref().to_path_buf() );
+      }
     }
+    files.finish().err_with_report( &report )?;
     Ok( report )
   }
 
@@ -2,14 +2,14 @@
 mod private
 {
   use crate::*;
-  use entity::test::{ TestPlan, TestOptions, TestsReport, tests_run };
+  use entity::test::{ TestPlan, TestOptions, TestsReport, TestDefaults, tests_run };
 
   // use test::*;
   // qqq : for Petro : no asterisks imports
   // qqq : for Petro : bad : not clear what is imported, there are multiple filles with name test
 
   use collection::HashSet;
-  use std::{ env, fs };
+  use std::{ env, fs, time::Duration };
 
   use former::Former;
   use error::
@@ -17,7 +17,8 @@ mod private
     untyped::
     {
       Error,
-      format_err
+      format_err,
+      Context,
     },
     // Result
   };
@@ -30,6 +31,15 @@ mod private
   /// - The `concurrent` field determines how match tests can be run at the same time.
   /// - The `exclude_features` field is a vector of strings representing the names of features to exclude when running tests.
   /// - The `include_features` field is a vector of strings representing the names of features to include when running tests.
+  /// - The `since` field is a git revision. If it is set, only packages affected by changes since the revision are tested.
+  /// - The `no_cache` field disables the cache of passed variants, so all variants are run and results are not recorded.
+  /// - The `features_strategy` field selects combinations of features : all combinations up to `power` features or pairwise covering ones.
+  /// - The `timeout` field limits time of a variant, the `retries` field is a count of additional runs of a failed variant.
+  ///   Both default to `workspace.metadata.test`, a zero timeout disables the limit.
+  /// - The `report_dir` field is a directory to write a JUnit XML report and logs of variants to. Nothing is written in dry mode.
+  /// - The `coverage` field runs variants instrumented and writes coverage of each package to `target/coverage/<package>.lcov`.
+  ///   Variants of the first channel are instrumented, they are always run.
+  /// - The `output_format` field is a format of the report, the plan is printed only as text.
   #[ derive( Debug, Former ) ]
   pub struct TestsCommandOptions
   {
@@ -52,8 +62,18 @@ mod private
     optimizations : HashSet< optimization::Optimization >,
     #[ former( default = 1000u32 ) ]
     variants_cap : u32,
+    features_strategy : features::FeaturesStrategy,
     #[ former( default = false ) ]
     with_progress : bool,
+    since : Option< String >,
+    #[ former( default = false ) ]
+    no_cache : bool,
+    report_dir : Option< path::PathBuf >,
+    timeout : Option< Duration >,
+    retries : Option< u32 >,
+    #[ former( default = false ) ]
+    coverage : bool,
+    output_format : output::OutputFormat,
   }
 
 
@@ -110,7 +130,15 @@ Try to install it with `rustup install {}` command(-s)",
       with_none_features,
       optimizations,
       variants_cap,
+      features_strategy,
       with_progress,
+      since,
+      no_cache,
+      report_dir,
+      timeout,
+      retries,
+      coverage,
+      output_format,
     } = o;
 
     // zzz : watch and review after been ready
@@ -131,6 +159,17 @@ Try to install it with `rustup install {}` command(-s)",
     // aaa : !When I wrote this solution, pr with this changes was not yet ready.!
     ;
 
+    let defaults = TestDefaults::try_from( &workspace ).err_with_report( &report )?;
+    let timeout = timeout.or( defaults.timeout ).filter( | t | !t.is_zero() );
+    let retries = retries.or( defaults.retries ).unwrap_or( 0 );
+
+    let affected = match &since
+    {
+      Some( since ) => Some( affected::affected( &workspace, since ).err_with_report( &report )? ),
+      None => None,
+    };
+    let affected_filter = affected.clone();
+
     // let packages = needed_packages( &workspace );
     let packages = workspace
     .packages()
@@ -145,10 +184,20 @@ Try to install it with `rustup install {}` command(-s)",
       .unwrap()
       .starts_with( path.as_ref() )
     )
+    .filter( move | p | affected_filter.as_ref().map_or( true, | a | a.contains( p.name() ) ) )
     // aaa : for Petro : too long line
     // aaa : done
     ;
 
+    let cache = if no_cache
+    {
+      None
+    }
+    else
+    {
+      Some( test_cache::TestCache::new( &workspace, packages.clone(), &channels ).err_with_report( &report )? )
+    };
+
     let plan = TestPlan::try_from
     (
       packages,
@@ -161,9 +210,17 @@ Try to install it with `rustup install {}` command(-s)",
       with_all_features,
       with_none_features,
       variants_cap,
+      features_strategy,
     ).err_with_report( &report )?;
 
-    println!( "{plan}" );
+    if output_format.is_text()
+    {
+      if let Some( affected ) = &affected
+      {
+        println!( "{affected}" );
+      }
+      println!( "{plan}" );
+    }
       // aaa : split on two functions for create plan and for execute
     // aaa : it's already separated, look line: 203 : let result = tests_run( &options );
 
@@ -200,7 +257,26 @@ Try to install it with `rustup install {}` command(-s)",
     .plan( plan )
     .option_temp( temp_path )
     .dry( dry )
-    .with_progress( with_progress );
+    .with_progress( with_progress )
+    .retries( retries );
+    let test_options_former = match timeout
+    {
+      Some( timeout ) => test_options_former.timeout( timeout ),
+      None => test_options_former,
+    };
+    let test_options_former = match cache
+    {
+      Some( cache ) => test_options_former.cache( cache ),
+      None => test_options_former,
+    };
+    let test_options_former = if coverage
+    {
+      test_options_former.coverage( workspace.target_directory().join( coverage::COVERAGE_DIR ) )
+    }
+    else
+    {
+      test_options_former
+    };
 
     let options = test_options_former.form();
     let result = tests_run( &options );
@@ -210,7 +286,25 @@ Try to install it with `rustup install {}` command(-s)",
       fs::remove_dir_all( options.temp_path.unwrap() ).err_with_report( &report )?;
     }
 
-    result.map_err( | ( report, e) | ( report, e.into() ) )
+    let ( mut report, error ) = match result
+    {
+      Ok( report ) => ( report, None ),
+      Err( ( report, e ) ) => ( report, Some( e ) ),
+    };
+    report.affected = affected;
+    if let ( false, Some( dir ) ) = ( dry, report_dir )
+    {
+      let artifacts = junit::write_artifacts( &report, &dir )
+      .with_context( || format!( "Failed to write test artifacts to `{}`", dir.display() ) )
+      .err_with_report( &report )?;
+      report.artifacts = Some( artifacts );
+    }
+
+    match error
+    {
+      None => Ok( report ),
+      Some( e ) => Err( ( report, e.into() ) ),
+    }
   }
 
 }
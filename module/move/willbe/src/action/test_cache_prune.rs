@@ -0,0 +1,37 @@
+/// Internal namespace.
+mod private
+{
+  use crate::*;
+
+  use std::time::Duration;
+  use former::Former;
+  use error::untyped::Context;
+
+  /// Options for `test_cache_prune`.
+  #[ derive( Debug, Former ) ]
+  pub struct TestCachePruneOptions
+  {
+    /// Directory inside of the workspace which cache is pruned.
+    crate_dir : CrateDir,
+    /// Entries which were not used for this count of days are removed. All entries are removed if it is zero.
+    #[ former( default = 0u32 ) ]
+    unused_days : u32,
+  }
+
+  /// Removes entries of the test cache of the workspace.
+  // qqq : typed error
+  pub fn test_cache_prune( o : TestCachePruneOptions ) -> error::untyped::Result< test_cache::TestCachePruneReport >
+  {
+    let workspace = Workspace::try_from( o.crate_dir ).context( "Failed to find workspace" )?;
+    let unused_for = Duration::from_secs( u64::from( o.unused_days ) * 24 * 60 * 60 );
+
+    test_cache::prune( &test_cache::TestCache::dir( &workspace ), unused_for )
+  }
+}
+
+crate::mod_interface!
+{
+  orphan use TestCachePruneOptions;
+  /// Prune the test cache.
+  orphan use test_cache_prune;
+}
@@ -0,0 +1,72 @@
+/// Internal namespace.
+mod private
+{
+  use crate::*;
+
+  use std::fs;
+  use std::path::PathBuf;
+  use wca::VerifiedCommand;
+  use former::Former;
+
+  #[ derive( Former ) ]
+  struct ChangelogRenewProperties
+  {
+    title : Option< String >,
+    #[ former( default = false ) ]
+    dry : bool,
+  }
+
+  ///
+  /// Renew changelogs of packages.
+  ///
+
+  // qqq : typed error
+  pub fn changelog_renew( o : VerifiedCommand ) -> error::untyped::Result< () >
+  {
+    let output_format = output::output_format( &o.props )?;
+    let path : PathBuf = o.args.get_owned( 0 ).unwrap_or_else( || "./".into() );
+    let crate_dir = CrateDir::try_from( fs::canonicalize( path )? )?;
+    let ChangelogRenewProperties { title, dry } = o.props.try_into()?;
+
+    let mut options = action::ChangelogRenewOptions::former()
+    .crate_dir( crate_dir )
+    .dry( dry );
+    if let Some( title ) = title { options = options.title( title ); }
+
+    match action::changelog_renew( options.form() )
+    {
+      Ok( report ) =>
+      {
+        println!( "{}", output::report_format( &report, output_format )? );
+        Ok( () )
+      }
+      Err(( report, e )) =>
+      {
+        eprintln!( "{}", output::report_format( &report, output_format )? );
+        Err( e.context( "changelog renew command" ) )
+      }
+    }
+  }
+
+  impl TryFrom< wca::Props > for ChangelogRenewProperties
+  {
+    type Error = error::untyped::Error;
+    fn try_from( value : wca::Props ) -> Result< Self, Self::Error >
+    {
+      let mut this = Self::former();
+
+      this = if let Some( v ) = value
+      .get_owned( "title" ) { this.title::< String >( v ) } else { this };
+      this = if let Some( v ) = value
+      .get_owned( "dry" ) { this.dry::< bool >( v ) } else { this };
+
+      Ok( this.form() )
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  /// Renew changelogs of packages.
+  orphan use changelog_renew;
+}
@@ -2,17 +2,24 @@ mod private
 {
   use crate::*;
 
+  use wca::VerifiedCommand;
   use error::{ untyped::Context };
 
   ///
   /// Generate table.
   ///
   // qqq : typed error
-  pub fn cicd_renew() -> error::untyped::Result< () >
+  pub fn cicd_renew( o : VerifiedCommand ) -> error::untyped::Result< () >
   {
+    let provider : Option< String > = o.props.get_owned( "provider" );
+    let provider = provider.map( | p | p.parse::< action::CiCdProvider >() ).transpose()?.unwrap_or_default();
+    let check : bool = o.props.get_owned( "check" ).unwrap_or_default();
+
     action::cicd_renew
     (
-      &std::env::current_dir()?
+      &std::env::current_dir()?,
+      provider,
+      check,
     )
     .context( "Fail to generate workflow" )
   }
@@ -23,4 +30,3 @@ crate::mod_interface!
   /// List packages.
   exposed use cicd_renew;
 }
-
@@ -0,0 +1,44 @@
+/// Internal namespace.
+mod private
+{
+  use crate::*;
+
+  use std::fs;
+  use std::path::PathBuf;
+  use wca::VerifiedCommand;
+
+  ///
+  /// Find unused and duplicated dependencies.
+  ///
+
+  // qqq : typed error
+  pub fn dependencies_check( o : VerifiedCommand ) -> error::untyped::Result< () >
+  {
+    let output_format = output::output_format( &o.props )?;
+    let path : PathBuf = o.args.get_owned( 0 ).unwrap_or_else( || "./".into() );
+    let crate_dir = CrateDir::try_from( fs::canonicalize( path )? )?;
+
+    let options = action::DependenciesCheckOptions::former()
+    .crate_dir( crate_dir )
+    .form();
+    match action::dependencies_check( options )
+    {
+      Ok( report ) =>
+      {
+        println!( "{}", output::report_format( &report, output_format )? );
+        Ok( () )
+      }
+      Err(( report, e )) =>
+      {
+        eprintln!( "{}", output::report_format( &report, output_format )? );
+        Err( e.context( "dependencies check command" ) )
+      }
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  /// Find unused and duplicated dependencies.
+  orphan use dependencies_check;
+}
@@ -16,6 +16,7 @@ mod private
 
   pub fn features( o : VerifiedCommand ) -> error::untyped::Result< () > // qqq : use typed error
   {
+    let output_format = output::output_format( &o.props )?;
     let path : PathBuf = o.args.get_owned( 0 ).unwrap_or_else( || "./".into() );
     let crate_dir = CrateDir::try_from( fs::canonicalize( path )? )?;
     let with_features_deps = o
@@ -29,8 +30,8 @@ mod private
     let report = action::features( o );
     match report
     {
-      Ok( success ) => println!( "{success}" ),
-      Err( failure ) => eprintln!( "{failure}" ),
+      Ok( success ) => println!( "{}", output::report_format( &success, output_format )? ),
+      Err( failure ) => eprintln!( "{}", output::report_format( &output::ErrorReport::from( &failure ), output_format )? ),
     }
     Ok( () )
   }
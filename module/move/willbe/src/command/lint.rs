@@ -0,0 +1,46 @@
+/// Internal namespace.
+mod private
+{
+  use crate::*;
+
+  use std::fs;
+  use std::path::PathBuf;
+  use wca::VerifiedCommand;
+
+  ///
+  /// Check hygiene of manifests of packages.
+  ///
+
+  // qqq : typed error
+  pub fn lint( o : VerifiedCommand ) -> error::untyped::Result< () >
+  {
+    let output_format = output::output_format( &o.props )?;
+    let path : PathBuf = o.args.get_owned( 0 ).unwrap_or_else( || "./".into() );
+    let crate_dir = CrateDir::try_from( fs::canonicalize( path )? )?;
+    let fix : bool = o.props.get_owned( "fix" ).unwrap_or_default();
+
+    let options = action::LintOptions::former()
+    .crate_dir( crate_dir )
+    .fix( fix )
+    .form();
+    match action::lint( options )
+    {
+      Ok( report ) =>
+      {
+        println!( "{}", output::report_format( &report, output_format )? );
+        Ok( () )
+      }
+      Err(( report, e )) =>
+      {
+        eprintln!( "{}", output::report_format( &report, output_format )? );
+        Err( e.context( "lint command" ) )
+      }
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  /// Check hygiene of manifests of packages.
+  orphan use lint;
+}
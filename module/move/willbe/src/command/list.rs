@@ -50,6 +50,7 @@ mod private
   // qqq : typed error
   pub fn list( o : VerifiedCommand ) -> error::untyped::Result< () >
   {
+    let output_format = output::output_format( &o.props )?;
     let path_to_workspace : PathBuf = o.args
     .get_owned( 0 )
     .unwrap_or( std::env::current_dir().context( "Workspace list command without subject" )? );
@@ -84,11 +85,11 @@ mod private
     {
       Ok( report ) =>
       {
-        println!( "{report}" );
+        println!( "{}", output::report_format( &report, output_format )? );
       }
       Err(( report, e )) =>
       {
-        eprintln!( "{report}" );
+        eprintln!( "{}", output::report_format( &report, output_format )? );
 
         return Err( e.context( "workspace list command" ) );
       }
@@ -3,24 +3,28 @@ mod private
   use crate::*;
   use action;
   use error::untyped::{ Error };
+  use wca::VerifiedCommand;
 
   /// Generates header to main Readme.md file.
   // qqq : typed error
-  pub fn readme_header_renew() -> error::untyped::Result< () >
+  pub fn readme_header_renew( o : VerifiedCommand ) -> error::untyped::Result< () >
   {
+    let output_format = output::output_format( &o.props )?;
+    let check : bool = o.props.get_owned( "check" ).unwrap_or_default();
     match action::readme_header_renew
     (
-      CrateDir::transitive_try_from::< AbsolutePath >( CurrentPath )?
+      CrateDir::transitive_try_from::< AbsolutePath >( CurrentPath )?,
+      check,
     )
     {
       Ok( report ) =>
       {
-        println!( "{report}" );
+        println!( "{}", output::report_format( &report, output_format )? );
         Ok( () )
       }
       Err( ( report, e ) ) =>
       {
-        eprintln!( "{report}" );
+        eprintln!( "{}", output::report_format( &report, output_format )? );
         Err( Error::from( e ).context( "Fail to generate main header." ) )
       }
     }
@@ -4,6 +4,12 @@ mod private
   use crate::*;
   use wca::{ Type, CommandsAggregator, CommandsAggregatorFormer };
 
+  /// Hint of the `check` property of commands which generate files.
+  const CHECK_HINT : &str = "Do not write files, fail with a unified diff if generated content differs from files on disk. Default is `false`.";
+
+  /// Hint of the `output` property of commands which print reports.
+  const OUTPUT_HINT : &str = "Format of the report : `text`, `json` or `toml`. Default is `text`.";
+
   ///
   /// Form CA commands grammar.
   ///
@@ -14,7 +20,7 @@ mod private
 
     .command( "publish" )
       .hint( "publish the specified package to `crates.io`" )
-      .long_hint( "used to publish the specified local package, which is located in the provided directory path, to the `crates.io` crate registry." )
+      .long_hint( "used to publish the specified local package, which is located in the provided directory path, to the `crates.io` crate registry, or to the registry with the sparse index set by `index` of `workspace.metadata.registry`. Steps are recorded in a journal in the target directory of the workspace, so a rerun after a failure resumes from the failed package. Commits of packages which never reached the registry are rolled back by `.publish.rollback`. With `concurrent` greater than `1`, packages which do not depend on each other are published at the same time, and packages depending on them wait until they appear in the index of the registry." )
       .subject()
         .hint( "Provide path(s) to the package(s) that you want to publish.\n\t  Each path should point to a directory that contains a `Cargo.toml` file.\n\t  Paths should be separated by a comma." )
         .kind( Type::List( Type::String.into(), ',' ) )
@@ -35,13 +41,28 @@ mod private
         .kind( Type::Bool )
         .optional( true )
         .end()
+      .property( "concurrent" )
+        .hint( "Indicates how many independent packages will be published at the same time. Default is `1` - packages are published one by one." )
+        .kind( Type::Number )
+        .optional( true )
+        .end()
+      .property( "with_progress" )
+        .hint( "If true, will display progressbar of packages published at the same time. Default is `true`. ! Work only with `progress_bar` feature !" )
+        .kind( Type::Bool )
+        .optional( true )
+        .end()
       // .property( "verbosity" ).hint( "Setup level of verbosity." ).kind( Type::String ).optional( true ).alias( "v" ).end()
+      .property( output::OUTPUT_PROPERTY )
+        .hint( OUTPUT_HINT )
+        .kind( Type::Enum( output::OUTPUT_FORMATS.map( String::from ).into() ) )
+        .optional( true )
+        .end()
       .routine( command::publish )
       .end()
 
     .command( "publish.diff" )
       .hint( "Display the differences between a local and remote package versions." )
-      .long_hint( "Following this command, you will immediately get a comparison between the local and remote packages. It looks at each file, identifying those added, removed, or modified. A full report will then be generated where you can quickly and easily see the differences." )
+      .long_hint( "Following this command, you will immediately get a comparison between the local and remote packages. It looks at each file, identifying those added, removed, or modified, and prints unified line diffs of modified text files. Versions and requirements of dependencies of the manifests are compared separately. A full report will then be generated where you can quickly and easily see the differences." )
       .subject()
         .hint( "Provide path to the package that you want to check.\n\t  The path should point to a directory that contains a `Cargo.toml` file." )
         .kind( Type::Path )
@@ -52,9 +73,71 @@ mod private
         .kind( Type::Path )
         .optional( true )
         .end()
+      .property( "lines" )
+        .hint( "Print unified line diffs of modified text files. Default is `true`." )
+        .kind( Type::Bool )
+        .optional( true )
+        .end()
+      .property( "context" )
+        .hint( "Count of unchanged lines around changes in line diffs. Default is `3`." )
+        .kind( Type::Number )
+        .optional( true )
+        .end()
+      .property( "max_size" )
+        .hint( "Files larger than this size in bytes are reported as modified without a line diff. Default is `1048576`." )
+        .kind( Type::Number )
+        .optional( true )
+        .end()
+      .property( output::OUTPUT_PROPERTY )
+        .hint( OUTPUT_HINT )
+        .kind( Type::Enum( output::OUTPUT_FORMATS.map( String::from ).into() ) )
+        .optional( true )
+        .end()
       .routine( command::publish_diff )
       .end()
 
+    .command( "publish.check" )
+      .hint( "Check that the version about to be published allows changes of the public API." )
+      .long_hint( "Extracts the public API of the local package and of its last release : paths of public items, signatures of functions, trait implementations and features with what they enable. Breaking changes ( removed or changed items ) and additions are listed, and the command fails if the planned version does not allow them by the rules of Cargo semantic versioning. The last release is the greatest version with a local release tag or in the registry, it is taken from the release tag or downloaded from the registry." )
+      .subject()
+        .hint( "Provide path to the package that you want to check.\n\t  The path should point to a directory that contains a `Cargo.toml` file." )
+        .kind( Type::Path )
+        .optional( true )
+        .end()
+      .property( "version" )
+        .hint( "The version about to be published. Default is the version of the manifest if it is greater than the released one, the default bump of the released version otherwise." )
+        .kind( Type::String )
+        .optional( true )
+        .end()
+      .property( output::OUTPUT_PROPERTY )
+        .hint( OUTPUT_HINT )
+        .kind( Type::Enum( output::OUTPUT_FORMATS.map( String::from ).into() ) )
+        .optional( true )
+        .end()
+      .routine( command::publish_check )
+      .end()
+
+    .command( "publish.rollback" )
+      .hint( "Roll back the interrupted publication." )
+      .long_hint( "`.publish` records every step in a journal in the target directory of the workspace, so a rerun after a failure resumes packages from the steps they reached. This command resets commits with bumped versions of packages which were committed, but never reached the registry. The commits must be on top of the branch in any order. A commit of a published package or any other commit above them stops the rollback, such commits have to be reset manually. Packages already published are finished by the next `.publish dry:0`." )
+      .subject()
+        .hint( "Provide path to the workspace.\n\t  The path should point to a directory that contains a `Cargo.toml` file." )
+        .kind( Type::Path )
+        .optional( true )
+        .end()
+      .property( "dry" )
+        .hint( "Enables 'dry run'. Does not reset commits, only shows them. Default is `true`." )
+        .kind( Type::Bool )
+        .optional( true )
+        .end()
+      .property( output::OUTPUT_PROPERTY )
+        .hint( OUTPUT_HINT )
+        .kind( Type::Enum( output::OUTPUT_FORMATS.map( String::from ).into() ) )
+        .optional( true )
+        .end()
+      .routine( command::publish_rollback )
+      .end()
+
     .command( "list" )
       .hint( "list packages from a directory" )
       .long_hint( "generates a list of packages based on the provided directory path. The directory must contain a `Cargo.toml` file." )
@@ -64,7 +147,7 @@ mod private
         .optional( true )
         .end()
       .property( "format" )
-        .hint( "Adjusts the output format - 'topsort' for a topologically sorted list or 'tree' for a structure of independent crates trees. The default is `tree`." )
+        .hint( "Adjusts the output format - 'topsort' for a topologically sorted list, 'tree' for a structure of independent crates trees, 'dot' or 'mermaid' for a graph of dependencies with edges labeled by kind of dependency. The default is `tree`. Use `output:json` to get the graph as JSON." )
         .kind( Type::String )
         .optional( true )
         .end()
@@ -103,6 +186,11 @@ mod private
         .kind( Type::Bool )
         .optional( true )
         .end()
+      .property( output::OUTPUT_PROPERTY )
+        .hint( OUTPUT_HINT )
+        .kind( Type::Enum( output::OUTPUT_FORMATS.map( String::from ).into() ) )
+        .optional( true )
+        .end()
       .routine( command::list )
       .end()
 
@@ -123,7 +211,13 @@ path: The relative path to the directory from workspace root, which crates will
 with_branches: If set to 1, it will display the status of workflow execution on branches specified in branches under workspace.metadata in the Cargo.toml of your workspace. For example, branches = ["master", "alpha"]. Default is 1.
 with_stability: If set to 1, a column indicating the stability of the module will be added. Information is taken from package.metadata of each module (package.metadata.stability = "stable"). By default, the module is considered experimental. Default is 1.
 with_docs: If set to 1, adds a column with a link to the module's documentation. Default is 1.
-with_gitpod: If set to 1, a column with a link to Gitpod will be added. Clicking on it will open and run an example named <module_name>_trivial. Default is 1."# )
+with_gitpod: If set to 1, a column with a link to Gitpod will be added. Clicking on it will open and run an example named <module_name>_trivial. Default is 1.
+with_coverage: If set to 1, a column with a badge of line coverage will be added. Coverage is taken from `target/coverage/<module_name>.lcov` written by `.test coverage:1`, the cell is empty if there is no such file. Default is 0."# )
+      .property( "check" )
+        .hint( CHECK_HINT )
+        .kind( Type::Bool )
+        .optional( true )
+        .end()
       .routine( command::readme_health_table_renew )
       .end()
 
@@ -193,17 +287,84 @@ with_gitpod: If set to 1, a column with a link to Gitpod will be added. Clicking
         .kind( Type::Number )
         .optional( true )
         .end()
+      .property( "features_strategy" )
+        .hint( "How combinations of features are selected : `powerset` - all combinations up to `power` features, `pairwise` - fewer combinations which cover every pair of features enabled and disabled. Combinations which enable the same features are tested once. Default is `powerset`." )
+        .kind( Type::String )
+        .optional( true )
+        .end()
       .property( "with_progress" )
         .hint( "If true, will display progressbar during the tests. Default is `true`. ! Work only with `progress_bar` feature !")
         .kind( Type::Bool )
         .optional( true )
         .end()
+      .property( "since" )
+        .hint( "A git revision. If specified, only packages changed since the revision and packages which depend on them are tested. Default is empty." )
+        .kind( Type::String )
+        .optional( true )
+        .end()
+      .property( "no_cache" )
+        .hint( "If true, variants which passed before with the same sources are run again and results are not recorded. Default is `false`." )
+        .kind( Type::Bool )
+        .optional( true )
+        .end()
+      .property( "timeout" )
+        .hint( "Time limit of a variant in seconds. A variant which runs longer is killed and failed. Default is `timeout` of `workspace.metadata.test` or no limit. `0` disables the limit." )
+        .kind( Type::Number )
+        .optional( true )
+        .end()
+      .property( "retries" )
+        .hint( "Count of additional runs of a failed variant. A variant which passes on a retry is reported as flaky with output of the failed runs and does not fail the run. Default is `retries` of `workspace.metadata.test` or `0`." )
+        .kind( Type::Number )
+        .optional( true )
+        .end()
+      .property( "report_dir" )
+        .hint( "A directory to write `junit.xml` with one testsuite per package and one testcase per variant, and the full output of each variant to `logs/<package>/<variant>.log`. Nothing is written in dry mode." )
+        .kind( Type::Path )
+        .optional( true )
+        .end()
+      .property( "coverage" )
+        .hint( "If true, variants of the first channel are built with `-C instrument-coverage` and always run. Their profiles are merged per package into `target/coverage/<package>.lcov`, percentages are printed in the report. Requires the `llvm-tools-preview` component. Default is `false`." )
+        .kind( Type::Bool )
+        .optional( true )
+        .end()
+      .property( output::OUTPUT_PROPERTY )
+        .hint( OUTPUT_HINT )
+        .kind( Type::Enum( output::OUTPUT_FORMATS.map( String::from ).into() ) )
+        .optional( true )
+        .end()
       .routine( command::test )
       .end()
 
+    .command( "test.cache.prune" )
+      .hint( "remove entries of the test cache" )
+      .long_hint( "`.test` stores passed variants in `target/willbe/test_cache` of the workspace and skips them while sources of the package and of its local dependencies and the version of the toolchain do not change. This command removes entries of the cache of the workspace inferred from the path." )
+      .subject().hint( "A path to the workspace. If no path is provided, the current directory is used." ).kind( Type::Path ).optional( true ).end()
+      .property( "unused_days" )
+        .hint( "Only entries which were not used for this count of days are removed. Default is `0` - which means all entries." )
+        .kind( Type::Number )
+        .optional( true )
+        .end()
+      .property( output::OUTPUT_PROPERTY )
+        .hint( OUTPUT_HINT )
+        .kind( Type::Enum( output::OUTPUT_FORMATS.map( String::from ).into() ) )
+        .optional( true )
+        .end()
+      .routine( command::test_cache_prune )
+      .end()
+
     .command( "cicd.renew" )
       .hint( "generate a CI/CD for the workspace" )
       .long_hint( "this command generates a development workflow for the entire workspace inferred from the current directory. The workflow outlines the build steps, dependencies, test processes, and more for all modules within the workspace." )
+      .property( "provider" )
+        .hint( "CI/CD system : `github` writes workflows to `.github/workflows`, `gitlab` writes `.gitlab-ci.yml`, `makefile` writes `ci.mk`. Default is `github`." )
+        .kind( Type::String )
+        .optional( true )
+        .end()
+      .property( "check" )
+        .hint( CHECK_HINT )
+        .kind( Type::Bool )
+        .optional( true )
+        .end()
       .routine( command::cicd_renew )
       .end()
 
@@ -252,18 +413,48 @@ with_gitpod: If set to 1, a column with a link to Gitpod will be added. Clicking
     .command( "readme.header.renew" )
       .hint( "Generate header in workspace`s Readme.md file")
       .long_hint( "Generate header which contains a badge with the general status of workspace, a link to discord, an example in gitpod and documentation in workspace`s Readme.md file.\n For use this command you need to specify:\n\n[workspace.metadata]\nmaster_branch = \"alpha\"\nworkspace_name = \"wtools\"\nrepo_url = \"https://github.com/Wandalen/wTools\"\ndiscord_url = \"https://discord.gg/123123\"\n\nin workspace's Cargo.toml.")
+      .property( "check" )
+        .hint( CHECK_HINT )
+        .kind( Type::Bool )
+        .optional( true )
+        .end()
+      .property( output::OUTPUT_PROPERTY )
+        .hint( OUTPUT_HINT )
+        .kind( Type::Enum( output::OUTPUT_FORMATS.map( String::from ).into() ) )
+        .optional( true )
+        .end()
       .routine( command::readme_header_renew )
       .end()
 
     .command( "readme.modules.headers.renew" )
       .hint( "Generates header for each workspace member." )
       .long_hint( "Generates header for each workspace member which contains a badge with the status of crate, a link to discord, an example in gitpod and documentation in crate Readme.md file.\nFor use this command you need to specify:\n\n[package]\nname = \"test_module\"\nrepository = \"https://github.com/Username/ProjectName/tree/master/module/test_module\"\n...\n[package.metadata]\nstability = \"stable\" (Optional)\ndiscord_url = \"https://discord.gg/1234567890\" (Optional)\n\nin module's Cargo.toml." )
+      .property( "check" )
+        .hint( CHECK_HINT )
+        .kind( Type::Bool )
+        .optional( true )
+        .end()
+      .property( output::OUTPUT_PROPERTY )
+        .hint( OUTPUT_HINT )
+        .kind( Type::Enum( output::OUTPUT_FORMATS.map( String::from ).into() ) )
+        .optional( true )
+        .end()
       .routine( command::readme_modules_headers_renew )
       .end()
 
     .command( "readme.headers.renew" )
       .hint( "Aggregation of two command : `readme.header.renew` and `readme.modules.headers.renew`.\n Generated headers in workspace members and in main Readme.md file.")
       .long_hint( "Generate header which contains a badge with the general status of workspace, a link to discord, an example in gitpod and documentation in workspace`s Readme.md file.\n For use this command you need to specify:\n\n[workspace.metadata]\nmaster_branch = \"alpha\"\nworkspace_name = \"wtools\"\nrepo_url = \"https://github.com/Wandalen/wTools\"\ndiscord_url = \"https://discord.gg/123123\"\n\nin workspace's Cargo.toml.\n\nGenerates header for each workspace member which contains a badge with the status of crate, a link to discord, an example in gitpod and documentation in crate Readme.md file.\nFor use this command you need to specify:\n\n[package]\nname = \"test_module\"\nrepository = \"https://github.com/Username/ProjectName/tree/master/module/test_module\"\n...\n[package.metadata]\nstability = \"stable\" (Optional)\ndiscord_url = \"https://discord.gg/1234567890\" (Optional)\n\nin module's Cargo.toml.")
+      .property( "check" )
+        .hint( CHECK_HINT )
+        .kind( Type::Bool )
+        .optional( true )
+        .end()
+      .property( output::OUTPUT_PROPERTY )
+        .hint( OUTPUT_HINT )
+        .kind( Type::Enum( output::OUTPUT_FORMATS.map( String::from ).into() ) )
+        .optional( true )
+        .end()
       .routine( command::readme_headers_renew )
       .end()
 
@@ -280,8 +471,76 @@ with_gitpod: If set to 1, a column with a link to Gitpod will be added. Clicking
         .kind( Type::Bool )
         .optional( true )
         .end()
+      .property( output::OUTPUT_PROPERTY )
+        .hint( OUTPUT_HINT )
+        .kind( Type::Enum( output::OUTPUT_FORMATS.map( String::from ).into() ) )
+        .optional( true )
+        .end()
       .routine( command::features )
       .end()
+
+    .command( "changelog.renew" )
+      .hint( "Write changes made since the last release into `Changelog.md` of packages." )
+      .long_hint( "Collects commits touching each package since its last release tag ( `name-vX.Y.Z` ) and groups them by the conventional commit prefix : breaking changes ( `type!:` or `BREAKING CHANGE:` ), features ( `feat:` ) and fixes ( `fix:` ). The section is written into `Changelog.md` of the package. The same section is written automatically by `.publish` for the new version." )
+      .subject()
+        .hint( "Provide path to the package or to the workspace.\n\t  The path should point to a directory that contains a `Cargo.toml` file." )
+        .kind( Type::Path )
+        .optional( true )
+        .end()
+      .property( "title" )
+        .hint( "Title of the section. Default `Unreleased`." )
+        .kind( Type::String )
+        .optional( true )
+        .end()
+      .property( "dry" )
+        .hint( "Enables 'dry run'. Does not write files, only prints sections. Default is `false`." )
+        .kind( Type::Bool )
+        .optional( true )
+        .end()
+      .property( output::OUTPUT_PROPERTY )
+        .hint( OUTPUT_HINT )
+        .kind( Type::Enum( output::OUTPUT_FORMATS.map( String::from ).into() ) )
+        .optional( true )
+        .end()
+      .routine( command::changelog_renew )
+      .end()
+
+    .command( "lint" )
+      .hint( "Check hygiene of manifests of packages." )
+      .long_hint( "Detects dependencies declared in `[workspace.dependencies]` but not inherited by a package, missing `repository`, `description` or `license`, local path dependencies without `version`, features referencing dependencies or features which do not exist and missing `Readme.md`. Packages with `publish = false` are not checked for fields and versions. With `fix:1` problems which can be fixed are fixed in place, formatting and comments of manifests are preserved." )
+      .subject()
+        .hint( "Provide path to the package or to the workspace.\n\t  The path should point to a directory that contains a `Cargo.toml` file." )
+        .kind( Type::Path )
+        .optional( true )
+        .end()
+      .property( "fix" )
+        .hint( "Fix problems which can be fixed automatically. Default is `false`." )
+        .kind( Type::Bool )
+        .optional( true )
+        .end()
+      .property( output::OUTPUT_PROPERTY )
+        .hint( OUTPUT_HINT )
+        .kind( Type::Enum( output::OUTPUT_FORMATS.map( String::from ).into() ) )
+        .optional( true )
+        .end()
+      .routine( command::lint )
+      .end()
+
+    .command( "dependencies.check" )
+      .hint( "Find unused and duplicated dependencies." )
+      .long_hint( "Reports dependencies declared in manifests but never referenced from source files of the package ( `name::`, `use name` or `extern crate name` ), and dependencies resolved to several semver incompatible versions in `Cargo.lock`. Each finding points to the line of the manifest which declares the dependency. Dependencies used only to enable features can be listed in `[package.metadata] unused_dependencies_ignore`." )
+      .subject()
+        .hint( "Provide path to the package or to the workspace.\n\t  The path should point to a directory that contains a `Cargo.toml` file." )
+        .kind( Type::Path )
+        .optional( true )
+        .end()
+      .property( output::OUTPUT_PROPERTY )
+        .hint( OUTPUT_HINT )
+        .kind( Type::Enum( output::OUTPUT_FORMATS.map( String::from ).into() ) )
+        .optional( true )
+        .end()
+      .routine( command::dependencies_check )
+      .end()
   }
 }
 
@@ -294,8 +553,12 @@ crate::mod_interface!
   layer list;
   /// Publish packages.
   layer publish;
+  /// Checks that the version about to be published allows changes of the public API.
+  layer publish_check;
   /// Used to compare local and published versions of a specific package.
   layer publish_diff;
+  /// Roll back the interrupted publication.
+  layer publish_rollback;
   /// Combination of two commands `main_header` and `readme_modules_headers_renew`.
   layer readme_headers_renew;
   /// Generates health table in main Readme.md file of workspace.
@@ -316,4 +579,12 @@ crate::mod_interface!
   layer readme_modules_headers_renew;
   /// List features
   layer features;
+  /// Renew changelogs
+  layer changelog_renew;
+  /// Remove entries of the test cache
+  layer test_cache_prune;
+  /// Check hygiene of manifests
+  layer lint;
+  /// Find unused and duplicated dependencies
+  layer dependencies_check;
 }
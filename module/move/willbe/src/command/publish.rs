@@ -19,6 +19,10 @@ mod private
     dry : bool,
     #[ former( default = true ) ]
     temp : bool,
+    #[ former( default = 1u32 ) ]
+    concurrent : u32,
+    #[ former( default = true ) ]
+    with_progress : bool,
   }
 
   ///
@@ -27,6 +31,7 @@ mod private
 
   pub fn publish( o : VerifiedCommand ) -> error::untyped::Result< () > // qqq : use typed error
   {
+    let output_format = output::output_format( &o.props )?;
     let args_line = format!
     (
       "{}",
@@ -53,9 +58,11 @@ mod private
     {
       channel,
       dry,
-      temp
+      temp,
+      concurrent,
+      with_progress,
     } = o.props.try_into()?;
-    let plan = action::publish_plan( patterns, channel, dry, temp )
+    let plan = action::publish_plan( patterns, channel, dry, temp, concurrent, with_progress )
     .context( "Failed to plan the publication process" )?;
 
     let mut formatted_plan = String::new();
@@ -67,15 +74,18 @@ mod private
       writeln!( &mut formatted_plan, "The following packages are pending for publication :" )?;
       plan.write_as_list( &mut formatted_plan )?;
     }
-    println!( "{formatted_plan}" );
+    if output_format.is_text()
+    {
+      println!( "{formatted_plan}" );
+    }
 
     match action::publish( plan )
     {
       Ok( report ) =>
       {
-        println!( "{report}" );
+        println!( "{}", output::report_format( &report, output_format )? );
 
-        if dry && !report.packages.is_empty()
+        if dry && !report.packages.is_empty() && output_format.is_text()
         {
           let args = if args_line.is_empty() { String::new() } else { format!(" {}", args_line) };
           let prop = if prop_line.is_empty() { String::new() } else { format!(" {}", prop_line) };
@@ -89,7 +99,7 @@ mod private
       }
       Err( ( report, e ) ) =>
       {
-        eprintln!( "{report}" );
+        eprintln!( "{}", output::report_format( &report, output_format )? );
         Err( e.context( "publish command" ) )
       }
     }
@@ -114,6 +124,10 @@ mod private
       .get_owned( "dry" ) { this.dry::< bool >( v ) } else { this };
       this = if let Some( v ) = value
       .get_owned( "temp" ) { this.temp::< bool >( v ) } else { this };
+      this = if let Some( v ) = value
+      .get_owned( "concurrent" ) { this.concurrent::< u32 >( v ) } else { this };
+      this = if let Some( v ) = value
+      .get_owned( "with_progress" ) { this.with_progress::< bool >( v ) } else { this };
 
       Ok( this.form() )
     }
@@ -0,0 +1,51 @@
+/// Internal namespace.
+mod private
+{
+  use crate::*;
+
+  use std::path::PathBuf;
+  use wca::VerifiedCommand;
+  use error::untyped::format_err;
+
+  ///
+  /// Check that the version about to be published allows changes of the public API.
+  ///
+
+  // qqq : typed error
+  pub fn publish_check( o : VerifiedCommand ) -> error::untyped::Result< () >
+  {
+    let output_format = output::output_format( &o.props )?;
+    let path : PathBuf = o.args.get_owned( 0 ).unwrap_or( std::env::current_dir()? );
+    let version : Option< String > = o.props.get_owned( "version" );
+
+    let mut options = action::PublishCheckOptions::former()
+    .path( path );
+    if let Some( version ) = version
+    {
+      options = options.version( version.parse::< Version >()? );
+    }
+    let report = action::publish_check( options.form() )?;
+    println!( "{}", output::report_format( &report, output_format )? );
+    if !report.is_sufficient()
+    {
+      let nearest = report.released_version.clone().bump_with( report.required );
+      return Err( format_err!
+      (
+        "The version `{}` of `{}` is insufficient : changes of the public API require a {} bump, the nearest sufficient version is `{nearest}`",
+        report.planned_version,
+        report.name,
+        report.required,
+      ));
+    }
+
+    Ok( () )
+  }
+}
+
+//
+
+crate::mod_interface!
+{
+  /// Checks that the version about to be published allows changes of the public API.
+  orphan use publish_check;
+}
@@ -14,6 +14,12 @@ mod private
   struct PublishDiffProperties
   {
     keep_archive : Option< PathBuf >,
+    #[ former( default = true ) ]
+    lines : bool,
+    #[ former( default = diff::DIFF_CONTEXT as u64 ) ]
+    context : u64,
+    #[ former( default = diff::DIFF_MAX_SIZE as u64 ) ]
+    max_size : u64,
   }
 
   /// Command to display the differences between a local and remote package versions.
@@ -32,16 +38,18 @@ mod private
 
   pub fn publish_diff( o : VerifiedCommand ) -> error::untyped::Result< () > // qqq : use typed error
   {
+    let output_format = output::output_format( &o.props )?;
     let path : PathBuf = o.args.get_owned( 0 ).unwrap_or( std::env::current_dir()? );
-    let PublishDiffProperties { keep_archive } = o.props.try_into()?;
+    let PublishDiffProperties { keep_archive, lines, context, max_size } = o.props.try_into()?;
 
     let mut o = action::PublishDiffOptions::former()
-    .path( path );
+    .path( path )
+    .diff( diff::DiffOptions::former().lines( lines ).context( context as usize ).max_size( max_size as usize ).form() );
     if let Some( k ) = keep_archive.clone() { o = o.keep_archive( k ); }
     let o = o.form();
 
-    println!( "{}", action::publish_diff( o )? );
-    if let Some( keep ) = keep_archive
+    println!( "{}", output::report_format( &action::publish_diff( o )?, output_format )? );
+    if let Some( keep ) = keep_archive.filter( | _ | output_format.is_text() )
     {
       let keep = AbsolutePath::try_from( fs::canonicalize( keep )? ).unwrap();
       println!( "Remote version of the package was saved at `{}`", keep.as_ref().display() );
@@ -62,6 +70,12 @@ mod private
       { this.keep_archive::< PathBuf >( v ) }
       else
       { this };
+      this = if let Some( v ) = value
+      .get_owned( "lines" ) { this.lines::< bool >( v ) } else { this };
+      this = if let Some( v ) = value
+      .get_owned( "context" ) { this.context::< u64 >( v ) } else { this };
+      this = if let Some( v ) = value
+      .get_owned( "max_size" ) { this.max_size::< u64 >( v ) } else { this };
 
       Ok( this.form() )
     }
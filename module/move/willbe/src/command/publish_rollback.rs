@@ -0,0 +1,46 @@
+/// Internal namespace.
+mod private
+{
+  use crate::*;
+
+  use std::fs;
+  use std::path::PathBuf;
+  use wca::VerifiedCommand;
+
+  ///
+  /// Roll back the interrupted publication.
+  ///
+
+  // qqq : typed error
+  pub fn publish_rollback( o : VerifiedCommand ) -> error::untyped::Result< () >
+  {
+    let output_format = output::output_format( &o.props )?;
+    let path : PathBuf = o.args.get_owned( 0 ).unwrap_or_else( || "./".into() );
+    let crate_dir = CrateDir::try_from( fs::canonicalize( path )? )?;
+    let dry : bool = o.props.get_owned( "dry" ).unwrap_or( true );
+
+    let options = action::PublishRollbackOptions::former()
+    .crate_dir( crate_dir )
+    .dry( dry )
+    .form();
+    match action::publish_rollback( options )
+    {
+      Ok( report ) =>
+      {
+        println!( "{}", output::report_format( &report, output_format )? );
+        Ok( () )
+      }
+      Err(( report, e )) =>
+      {
+        eprintln!( "{}", output::report_format( &report, output_format )? );
+        Err( e.context( "publish rollback command" ) )
+      }
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  /// Roll back the interrupted publication.
+  orphan use publish_rollback;
+}
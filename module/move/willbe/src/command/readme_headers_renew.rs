@@ -4,13 +4,16 @@ mod private
   use action;
   use error::{ err };
   use std::fmt::{ Display, Formatter };
+  use wca::VerifiedCommand;
 
-  #[ derive( Debug, Default ) ]
+  #[ derive( Debug, Default, serde::Serialize ) ]
   struct ReadmeHeadersRenewReport
   {
     main_header_renew_report : action::MainHeaderRenewReport,
+    #[ serde( skip_serializing_if = "Option::is_none", serialize_with = "output::error_option_serialize" ) ]
     main_header_renew_error : Option< action::MainHeaderRenewError >, // aaa : for Petro : typed error // aaa : done
     modules_headers_renew_report : action::ModulesHeadersRenewReport,
+    #[ serde( skip_serializing_if = "Option::is_none", serialize_with = "output::error_option_serialize" ) ]
     modules_headers_renew_error : Option< action::ModulesHeadersRenewError >, // aaa : for Petro : typed error // aaa : done
   }
 
@@ -26,7 +29,7 @@ mod private
           (
             f,
             "Main header renew report : \
-{}\nError : \n{:?}\nModules headers renew report : \n{}\nError : \n{:?}",
+{}\nError : \n{}\nModules headers renew report : \n{}\nError : \n{}",
             self.main_header_renew_report, main, self.modules_headers_renew_report, modules
           )?;
         }
@@ -36,7 +39,7 @@ mod private
           (
             f,
             "Main header renew report : \
-{}\nError : \n{:?}\nModules headers renew report : \n{}",
+{}\nError : \n{}\nModules headers renew report : \n{}",
             self.main_header_renew_report, main, self.modules_headers_renew_report
           )?;
         }
@@ -46,7 +49,7 @@ mod private
           (
             f,
             "Main header renew report : \
-{}\nModules headers renew report : \n{}\nError : \n{:?}\n",
+{}\nModules headers renew report : \n{}\nError : \n{}\n",
             self.main_header_renew_report, self.modules_headers_renew_report, modules
           )?;
         }
@@ -66,14 +69,16 @@ mod private
 
 
   /// Aggregates two commands: `generate_modules_headers` & `generate_main_header`
-  pub fn readme_headers_renew() -> error::untyped::Result< () > // qqq : use typed error
+  pub fn readme_headers_renew( o : VerifiedCommand ) -> error::untyped::Result< () > // qqq : use typed error
   {
+    let output_format = output::output_format( &o.props )?;
+    let check : bool = o.props.get_owned( "check" ).unwrap_or_default();
     let mut report = ReadmeHeadersRenewReport::default();
     // let absolute_path = AbsolutePath::try_from( std::env::current_dir()? )?;
     let crate_dir = CrateDir::transitive_try_from::< AbsolutePath >( CurrentPath )?;
     let mut fail = false;
 
-    match action::readme_header_renew( crate_dir.clone() )
+    match action::readme_header_renew( crate_dir.clone(), check )
     {
       Ok( r ) =>
       {
@@ -86,7 +91,7 @@ mod private
         report.main_header_renew_error = Some( error );
       }
     };
-    match action::readme_modules_headers_renew( crate_dir )
+    match action::readme_modules_headers_renew( crate_dir, check )
     {
       Ok( r ) =>
       {
@@ -102,12 +107,12 @@ mod private
 
     if fail
     {
-      eprintln!( "{report}" );
+      eprintln!( "{}", output::report_format( &report, output_format )? );
       Err( err!( "Something went wrong" ) )
     }
     else
     {
-      println!( "{report}" );
+      println!( "{}", output::report_format( &report, output_format )? );
       Ok( () )
     }
   }
@@ -2,17 +2,21 @@ mod private
 {
   use crate::*;
 
+  use wca::VerifiedCommand;
   use error::{ untyped::Context };
 
   ///
   /// Generate table.
   ///
   // qqq : typed error
-  pub fn readme_health_table_renew() -> error::untyped::Result< () >
+  pub fn readme_health_table_renew( o : VerifiedCommand ) -> error::untyped::Result< () >
   {
+    let check : bool = o.props.get_owned( "check" ).unwrap_or_default();
+
     action::readme_health_table_renew
     (
-      &std::env::current_dir()?
+      &std::env::current_dir()?,
+      check,
     )
     .context( "Fail to create table" )
   }
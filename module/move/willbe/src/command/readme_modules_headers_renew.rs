@@ -3,21 +3,24 @@ mod private
   use crate::*;
   // use path::AbsolutePath;
   // use error::{ untyped::Error };
+  use wca::VerifiedCommand;
 
   /// Generate headers for workspace members
   // qqq : typed error
-  pub fn readme_modules_headers_renew() -> error::untyped::Result< () >
+  pub fn readme_modules_headers_renew( o : VerifiedCommand ) -> error::untyped::Result< () >
   {
-    match action::readme_modules_headers_renew( CrateDir::transitive_try_from::< AbsolutePath >( CurrentPath )? )
+    let output_format = output::output_format( &o.props )?;
+    let check : bool = o.props.get_owned( "check" ).unwrap_or_default();
+    match action::readme_modules_headers_renew( CrateDir::transitive_try_from::< AbsolutePath >( CurrentPath )?, check )
     {
       Ok( report ) =>
       {
-        println!( "{report}" );
+        println!( "{}", output::report_format( &report, output_format )? );
         Ok( () )
       }
       Err( ( report, e ) ) =>
       {
-        eprintln!( "{report}" );
+        eprintln!( "{}", output::report_format( &report, output_format )? );
         Err( error::untyped::Error::from( e ).context( "Fail to generate modules headers." ) )
         // qqq : use typed error
       }
@@ -4,7 +4,7 @@ mod private
   use crate::*;
 
   use collection::HashSet;
-  use std::fs;
+  use std::{ fs, time::Duration };
   use colored::Colorize;
   use wca::VerifiedCommand;
   // use error::Result;
@@ -15,6 +15,8 @@ mod private
   use channel::Channel;
   use error::untyped::bail;
   use optimization::Optimization;
+  use features::FeaturesStrategy;
+  use std::str::FromStr;
 
   #[ derive( Former, Debug ) ]
   struct TestsProperties
@@ -45,12 +47,22 @@ mod private
     with_release : bool,
     #[ former( default = true ) ]
     with_progress : bool,
+    since : Option< String >,
+    #[ former( default = false ) ]
+    no_cache : bool,
+    features_strategy : FeaturesStrategy,
+    report_dir : Option< PathBuf >,
+    timeout : Option< u32 >,
+    retries : Option< u32 >,
+    #[ former( default = false ) ]
+    coverage : bool,
   }
 
   /// run tests in specified crate
   // qqq : don't use 1-prameter Result
   pub fn test( o : VerifiedCommand ) -> error::untyped::Result< () > // qqq : use typed error
   {
+    let output_format = output::output_format( &o.props )?;
     let args_line = format!
     (
       "{}",
@@ -87,7 +99,14 @@ mod private
       with_none_features,
       with_debug,
       with_release,
-      with_progress
+      with_progress,
+      since,
+      no_cache,
+      features_strategy,
+      report_dir,
+      timeout,
+      retries,
+      coverage,
     } = o.props.try_into()?;
 
     let mut channels = HashSet::new();
@@ -118,14 +137,26 @@ Set at least one of them to true." );
     .with_none_features( with_none_features )
     .optimizations( optimizations )
     .with_progress( with_progress )
-    .form();
+    .no_cache( no_cache )
+    .coverage( coverage )
+    .output_format( output_format )
+    .features_strategy( features_strategy );
+    let args = if let Some( since ) = since { args.since( since ) } else { args };
+    let args = if let Some( report_dir ) = report_dir { args.report_dir( report_dir ) } else { args };
+    let args = if let Some( timeout ) = timeout { args.timeout( Duration::from_secs( timeout.into() ) ) } else { args };
+    let args = if let Some( retries ) = retries { args.retries( retries ) } else { args };
+    let args = args.form();
 
     match action::test( args, dry )
     {
 
       Ok( report ) =>
       {
-        if dry
+        if !output_format.is_text()
+        {
+          println!( "{}", output::report_format( &report, output_format )? );
+        }
+        else if dry
         {
           let args = if args_line.is_empty() { String::new() } else { format!(" {}", args_line) };
           let prop = if prop_line.is_empty() { String::new() } else { format!(" {}", prop_line) };
@@ -141,7 +172,7 @@ Set at least one of them to true." );
       }
       Err( ( report, e ) ) =>
       {
-        eprintln!( "{report}" );
+        eprintln!( "{}", output::report_format( &report, output_format )? );
         Err( e.context( "package test command" ) )
       }
     }
@@ -182,6 +213,21 @@ Set at least one of them to true." );
       .get_owned( "always" ) { this.enabled_features::< Vec< String > >( v ) } else { this };
       this = if let Some( v ) = value
       .get_owned( "with_progress" ) { this.with_progress::< bool >( v ) } else { this };
+      this = if let Some( v ) = value
+      .get_owned( "since" ) { this.since::< String >( v ) } else { this };
+      this = if let Some( v ) = value
+      .get_owned( "no_cache" ) { this.no_cache::< bool >( v ) } else { this };
+      this = if let Some( v ) = value
+      .get_owned( "features_strategy" )
+      .map( | v : String | FeaturesStrategy::from_str( &v ) ) { this.features_strategy( v? ) } else { this };
+      this = if let Some( v ) = value
+      .get_owned( "report_dir" ) { this.report_dir::< PathBuf >( v ) } else { this };
+      this = if let Some( v ) = value
+      .get_owned( "timeout" ) { this.timeout::< u32 >( v ) } else { this };
+      this = if let Some( v ) = value
+      .get_owned( "retries" ) { this.retries::< u32 >( v ) } else { this };
+      this = if let Some( v ) = value
+      .get_owned( "coverage" ) { this.coverage::< bool >( v ) } else { this };
 
       Ok( this.form() )
     }
@@ -0,0 +1,37 @@
+/// Internal namespace.
+mod private
+{
+  use crate::*;
+
+  use std::fs;
+  use std::path::PathBuf;
+  use wca::VerifiedCommand;
+
+  ///
+  /// Remove entries of the test cache.
+  ///
+
+  // qqq : typed error
+  pub fn test_cache_prune( o : VerifiedCommand ) -> error::untyped::Result< () >
+  {
+    let output_format = output::output_format( &o.props )?;
+    let path : PathBuf = o.args.get_owned( 0 ).unwrap_or_else( || "./".into() );
+    let crate_dir = CrateDir::try_from( fs::canonicalize( path )? )?;
+    let unused_days : u32 = o.props.get_owned( "unused_days" ).unwrap_or_default();
+
+    let options = action::TestCachePruneOptions::former()
+    .crate_dir( crate_dir )
+    .unused_days( unused_days )
+    .form();
+    let report = action::test_cache_prune( options )?;
+    println!( "{}", output::report_format( &report, output_format )? );
+
+    Ok( () )
+  }
+}
+
+crate::mod_interface!
+{
+  /// Remove entries of the test cache.
+  orphan use test_cache_prune;
+}
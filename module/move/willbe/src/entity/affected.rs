@@ -0,0 +1,162 @@
+mod private
+{
+  use crate::*;
+
+  use std::fmt;
+  use path::PathBuf;
+  use collection::{ BTreeMap, VecDeque };
+  use iter::Itertools;
+  use petgraph::Direction::Incoming;
+  use package::PackageName;
+
+  /// Why a package is affected by changes.
+  #[ derive( Debug, Clone, PartialEq, Eq, serde::Serialize ) ]
+  #[ serde( tag = "reason", rename_all = "snake_case" ) ]
+  pub enum AffectedReason
+  {
+    /// Files of the package were changed. Paths are relative to the workspace root.
+    Changed
+    {
+      /// Changed files.
+      files : Vec< PathBuf >,
+    },
+    /// The package depends on an affected package.
+    Dependent
+    {
+      /// The affected dependency.
+      dependency : PackageName,
+    },
+  }
+
+  impl fmt::Display for AffectedReason
+  {
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      match self
+      {
+        Self::Changed { files } =>
+        {
+          let first = files.iter().take( 3 ).map( | p | format!( "`{}`", p.display() ) ).join( ", " );
+          let more = if files.len() > 3 { format!( " and {} more", files.len() - 3 ) } else { String::new() };
+          write!( f, "changed {first}{more}" )
+        }
+        Self::Dependent { dependency } => write!( f, "depends on `{dependency}`" ),
+      }
+    }
+  }
+
+  /// Packages affected by changes, with the reason each package was included.
+  #[ derive( Debug, Default, Clone, PartialEq, Eq, serde::Serialize ) ]
+  #[ serde( transparent ) ]
+  pub struct AffectedPackages( pub BTreeMap< PackageName, AffectedReason > );
+
+  impl AffectedPackages
+  {
+    /// Returns `true` if the package is affected.
+    pub fn contains( &self, name : &str ) -> bool
+    {
+      self.0.contains_key( &PackageName::from( name.to_string() ) )
+    }
+
+    /// Returns `true` if no package is affected.
+    pub fn is_empty( &self ) -> bool
+    {
+      self.0.is_empty()
+    }
+  }
+
+  impl fmt::Display for AffectedPackages
+  {
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      if self.0.is_empty()
+      {
+        return writeln!( f, "Affected packages : none" );
+      }
+      writeln!( f, "Affected packages :" )?;
+      for ( name, reason ) in &self.0
+      {
+        writeln!( f, "  {name} - {reason}" )?;
+      }
+
+      Ok( () )
+    }
+  }
+
+  /// Collects packages of the workspace affected by changes since the revision.
+  ///
+  /// A package is affected if any of its files was changed ( uncommitted changes and new files which are not ignored are included ),
+  /// or if it depends on an affected package of the workspace. Development dependencies count, as their changes can break tests.
+  /// Changed files outside of packages are ignored.
+  ///
+  /// # Arguments
+  ///
+  /// * `workspace` - The workspace.
+  /// * `since` - A git revision to compare the working tree with, for example `origin/master` or `HEAD~3`.
+  // qqq : typed error
+  pub fn affected( workspace : &Workspace, since : &str ) -> error::untyped::Result< AffectedPackages >
+  {
+    let root = workspace.workspace_root();
+    let changed = tool::git::diff_names( &root, since )?;
+    let untracked = tool::git::untracked_names( &root )?;
+
+    let packages : Vec< ( PackageName, PathBuf ) > = workspace
+    .packages()
+    .filter_map( | p | Some( ( p.name().to_string().into(), p.crate_dir().ok()?.as_ref().to_path_buf() ) ) )
+    .collect();
+
+    let mut result = BTreeMap::new();
+    for file in changed.out.lines().chain( untracked.out.lines() ).map( str::trim ).filter( | l | !l.is_empty() )
+    {
+      let path = root.as_ref().join( file );
+      // the innermost package owns the file
+      let owner = packages
+      .iter()
+      .filter( |( _, dir )| path.starts_with( dir ) )
+      .max_by_key( |( _, dir )| dir.components().count() );
+      if let Some(( name, _ )) = owner
+      {
+        if let AffectedReason::Changed { files } = result.entry( name.clone() ).or_insert_with( || AffectedReason::Changed { files : vec![] } )
+        {
+          files.push( PathBuf::from( file ) );
+        }
+      }
+    }
+
+    let dependencies = packages::filter
+    (
+      workspace.packages(),
+      packages::FilterMapOptions
+      {
+        package_filter : None,
+        dependency_filter : Some( Box::new( | _, d | d.crate_dir().is_some() ) ),
+      },
+    );
+    let graph = graph::construct( &dependencies );
+    let mut queue : VecDeque< _ > = result.keys().cloned().collect();
+    while let Some( name ) = queue.pop_front()
+    {
+      let Some( node ) = graph.node_indices().find( | i | *graph[ *i ] == name ) else { continue };
+      for dependent in graph.neighbors_directed( node, Incoming )
+      {
+        let dependent = graph[ dependent ].clone();
+        if !result.contains_key( &dependent )
+        {
+          result.insert( dependent.clone(), AffectedReason::Dependent { dependency : name.clone() } );
+          queue.push_back( dependent );
+        }
+      }
+    }
+
+    Ok( AffectedPackages( result ) )
+  }
+}
+
+//
+
+crate::mod_interface!
+{
+  own use AffectedReason;
+  own use AffectedPackages;
+  own use affected;
+}
@@ -0,0 +1,345 @@
+mod private
+{
+  use crate::*;
+
+  use std::
+  {
+    fmt,
+    fs,
+    sync::OnceLock,
+  };
+  use regex::Regex;
+  use path::PathBuf;
+  use error::untyped::{ Context, format_err };
+
+  /// Name of the changelog file of a package.
+  pub const CHANGELOG_FILE : &str = "Changelog.md";
+
+  /// Title of a section with changes which are not released yet.
+  pub const UNRELEASED : &str = "Unreleased";
+
+  const CHANGELOG_TITLE : &str = "# Changelog";
+
+  /// A commit of the repository.
+  #[ derive( Debug, Clone, PartialEq, Eq ) ]
+  pub struct Commit
+  {
+    /// Full hash of the commit.
+    pub hash : String,
+    /// First line of the commit message.
+    pub subject : String,
+    /// The rest of the commit message.
+    pub body : String,
+  }
+
+  impl Commit
+  {
+    /// Parses output of `tool::git::log`.
+    pub fn list_parse( out : &str ) -> Vec< Self >
+    {
+      out
+      .split( '\x1e' )
+      .map( str::trim )
+      .filter( | c | !c.is_empty() )
+      .filter_map( | c |
+      {
+        let mut parts = c.splitn( 3, '\x1f' );
+        let hash = parts.next()?.trim().to_string();
+        let subject = parts.next()?.trim().to_string();
+        let body = parts.next().unwrap_or_default().trim().to_string();
+
+        Some( Self { hash, subject, body } )
+      })
+      .collect()
+    }
+
+    /// Abbreviated hash of the commit.
+    pub fn short_hash( &self ) -> &str
+    {
+      &self.hash[ .. self.hash.len().min( 7 ) ]
+    }
+  }
+
+  /// Group of a change, detected by the conventional commit prefix of a message.
+  #[ derive( Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize ) ]
+  #[ serde( rename_all = "lowercase" ) ]
+  pub enum ChangeKind
+  {
+    /// `type!: ...` or a `BREAKING CHANGE:` footer.
+    Breaking,
+    /// `feat: ...`
+    Feature,
+    /// `fix: ...`
+    Fix,
+    /// Everything else.
+    Other,
+  }
+
+  fn conventional_regex() -> &'static Regex
+  {
+    static REGEX : OnceLock< Regex > = OnceLock::new();
+    REGEX.get_or_init( || Regex::new( r"^(?<kind>[A-Za-z]+)(\((?<scope>[^)]*)\))?(?<breaking>!)?\s*:\s*(?<description>.+)$" ).unwrap() )
+  }
+
+  /// A single line of a changelog.
+  #[ derive( Debug, Clone, PartialEq, Eq, serde::Serialize ) ]
+  pub struct Change
+  {
+    /// Group of the change.
+    pub kind : ChangeKind,
+    /// Scope of the change, if specified.
+    pub scope : Option< String >,
+    /// Description of the change.
+    pub description : String,
+    /// Abbreviated hash of the commit.
+    pub commit : String,
+  }
+
+  impl From< &Commit > for Change
+  {
+    fn from( commit : &Commit ) -> Self
+    {
+      let footer_breaking = commit.body.lines().any( | l | l.starts_with( "BREAKING CHANGE:" ) || l.starts_with( "BREAKING-CHANGE:" ) );
+      match conventional_regex().captures( &commit.subject )
+      {
+        Some( captures ) =>
+        {
+          let kind = match &captures[ "kind" ]
+          {
+            _ if captures.name( "breaking" ).is_some() || footer_breaking => ChangeKind::Breaking,
+            "feat" => ChangeKind::Feature,
+            "fix" => ChangeKind::Fix,
+            _ => ChangeKind::Other,
+          };
+          Self
+          {
+            kind,
+            scope : captures.name( "scope" ).map( | s | s.as_str().to_string() ).filter( | s | !s.is_empty() ),
+            description : captures[ "description" ].trim().to_string(),
+            commit : commit.short_hash().to_string(),
+          }
+        }
+        None => Self
+        {
+          kind : if footer_breaking { ChangeKind::Breaking } else { ChangeKind::Other },
+          scope : None,
+          description : commit.subject.clone(),
+          commit : commit.short_hash().to_string(),
+        },
+      }
+    }
+  }
+
+  impl fmt::Display for Change
+  {
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      match &self.scope
+      {
+        Some( scope ) => write!( f, "- **{scope}**: {} ({})", self.description, self.commit ),
+        None => write!( f, "- {} ({})", self.description, self.commit ),
+      }
+    }
+  }
+
+  /// Section of a changelog which describes a single version.
+  #[ derive( Debug, Clone, Default, serde::Serialize ) ]
+  pub struct ChangelogSection
+  {
+    /// Title of the section, a version or `Unreleased`.
+    pub title : String,
+    /// Changes of the version.
+    pub changes : Vec< Change >,
+  }
+
+  impl fmt::Display for ChangelogSection
+  {
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      writeln!( f, "## {}", self.title )?;
+      if self.changes.is_empty()
+      {
+        writeln!( f, "\nNo notable changes." )?;
+        return Ok( () );
+      }
+      for ( kind, header ) in
+      [
+        ( ChangeKind::Breaking, "Breaking changes" ),
+        ( ChangeKind::Feature, "Features" ),
+        ( ChangeKind::Fix, "Fixes" ),
+        ( ChangeKind::Other, "Other changes" ),
+      ]
+      {
+        let changes : Vec< _ > = self.changes.iter().filter( | c | c.kind == kind ).collect();
+        if changes.is_empty() { continue }
+        writeln!( f, "\n### {header}\n" )?;
+        for change in changes
+        {
+          writeln!( f, "{change}" )?;
+        }
+      }
+
+      Ok( () )
+    }
+  }
+
+  /// Name of the release tag of a package version.
+  pub fn release_tag( name : &str, version : &str ) -> String
+  {
+    format!( "{name}-v{version}" )
+  }
+
+  /// Collects changes of a package since its last release.
+  ///
+  /// The last release is the `name-vX.Y.Z` tag of the current version of the package. If there is no such tag,
+  /// the release commit with the same message is used. If neither is found, all commits touching the package are collected.
+  ///
+  /// # Arguments
+  ///
+  /// * `crate_dir` - The directory of the package.
+  /// * `name` - The name of the package.
+  /// * `version` - The last released version of the package.
+  /// * `title` - The title of the section, usually the new version.
+  // qqq : typed error
+  pub fn section_collect( crate_dir : &CrateDir, name : &str, version : &str, title : &str ) -> error::untyped::Result< ChangelogSection >
+  {
+    let tag = release_tag( name, version );
+    let range = tool::git::rev_parse( crate_dir, &tag ).ok().map( | _ | format!( "{tag}..HEAD" ) );
+    let report = tool::git::log( crate_dir, range.as_deref(), &[ "." ] )?;
+
+    let changes = Commit::list_parse( &report.out )
+    .iter()
+    .take_while( | c | range.is_some() || c.subject != tag )
+    .filter( | c | !release_is( &c.subject ) )
+    .map( Change::from )
+    .collect();
+
+    Ok( ChangelogSection { title : title.into(), changes } )
+  }
+
+  /// Release commits are not a part of a changelog.
+  fn release_is( subject : &str ) -> bool
+  {
+    static REGEX : OnceLock< Regex > = OnceLock::new();
+    REGEX.get_or_init( || Regex::new( r"^[\w-]+-v\d+\.\d+\.\d+\S*$" ).unwrap() ).is_match( subject )
+  }
+
+  /// Report about writing of a changelog.
+  #[ derive( Debug, Clone, Default, serde::Serialize ) ]
+  pub struct ChangelogReport
+  {
+    /// Path to the changelog file.
+    pub file : PathBuf,
+    /// Written section.
+    pub section : ChangelogSection,
+    /// Content of the file before the change. `None` if the file did not exist.
+    #[ serde( skip ) ]
+    pub previous : Option< String >,
+    /// Whether the file was actually written.
+    pub written : bool,
+  }
+
+  impl fmt::Display for ChangelogReport
+  {
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      let action = if self.written { "Updated" } else { "Will update" };
+      writeln!( f, "{action} `{}` :", self.file.display() )?;
+      write!( f, "  {}", self.section.to_string().trim_end().replace( '\n', "\n  " ) )
+    }
+  }
+
+  /// Inserts a section into a changelog.
+  ///
+  /// A section with the same title and the `Unreleased` section are replaced, other sections are kept below the new one.
+  pub fn section_insert( content : Option< &str >, section : &ChangelogSection ) -> String
+  {
+    let content = content.unwrap_or( CHANGELOG_TITLE );
+    let mut head = vec![];
+    let mut sections : Vec< ( String, Vec< &str > ) > = vec![];
+    for line in content.lines()
+    {
+      if let Some( title ) = line.strip_prefix( "## " )
+      {
+        sections.push( ( title.trim().to_string(), vec![ line ] ) );
+      }
+      else if let Some(( _, lines )) = sections.last_mut()
+      {
+        lines.push( line );
+      }
+      else
+      {
+        head.push( line );
+      }
+    }
+
+    let mut result = head.join( "\n" ).trim_end().to_string();
+    result.push_str( "\n\n" );
+    result.push_str( section.to_string().trim_end() );
+    result.push( '\n' );
+    for ( title, lines ) in sections
+    {
+      if title == section.title || title == UNRELEASED { continue }
+      result.push( '\n' );
+      result.push_str( lines.join( "\n" ).trim_end() );
+      result.push( '\n' );
+    }
+
+    result
+  }
+
+  /// Writes a section into the changelog of a package.
+  ///
+  /// In dry mode the file is not touched, but the report describes the change.
+  // qqq : typed error
+  pub fn section_write( crate_dir : &CrateDir, section : ChangelogSection, dry : bool ) -> error::untyped::Result< ChangelogReport >
+  {
+    let file = crate_dir.as_ref().join( CHANGELOG_FILE );
+    let previous = if file.exists()
+    {
+      Some( fs::read_to_string( &file ).with_context( || format!( "Failed to read {}", file.display() ) )? )
+    }
+    else
+    {
+      None
+    };
+    let content = section_insert( previous.as_deref(), &section );
+    if !dry
+    {
+      fs::write( &file, content ).with_context( || format!( "Failed to write {}", file.display() ) )?;
+    }
+
+    Ok( ChangelogReport { file, section, previous, written : !dry } )
+  }
+
+  /// Restores the changelog to the state before `section_write`.
+  // qqq : typed error
+  pub fn revert( report : &ChangelogReport ) -> error::untyped::Result< () >
+  {
+    if !report.written { return Ok( () ) }
+    match &report.previous
+    {
+      Some( previous ) => fs::write( &report.file, previous ),
+      None => fs::remove_file( &report.file ),
+    }
+    .map_err( | e | format_err!( "Failed to revert {} : {e}", report.file.display() ) )
+  }
+}
+
+//
+
+crate::mod_interface!
+{
+  own use CHANGELOG_FILE;
+  own use UNRELEASED;
+  own use Commit;
+  own use ChangeKind;
+  own use Change;
+  own use ChangelogSection;
+  own use ChangelogReport;
+  own use release_tag;
+  own use section_collect;
+  own use section_insert;
+  own use section_write;
+  own use revert;
+}
@@ -12,7 +12,8 @@ mod private
   use process_tools::process::*;
 
   /// The `Channel` enum represents different release channels for rust.
-  #[ derive( Debug, Default, Copy, Clone, Hash, Eq, PartialEq, Ord, PartialOrd ) ]
+  #[ derive( Debug, Default, Copy, Clone, Hash, Eq, PartialEq, Ord, PartialOrd, serde::Serialize ) ]
+  #[ serde( rename_all = "lowercase" ) ]
   pub enum Channel
   {
     /// Represents the stable release channel.
@@ -78,6 +79,24 @@ mod private
 
     Ok( list )
   }
+
+  /// Returns the verbose version of the compiler of the channel, output of `rustc +<channel> -vV`.
+  ///
+  /// The version includes the commit hash, so it changes with every update of the toolchain.
+  // qqq : typed error
+  pub fn toolchain_version< P >( channel : Channel, path : P ) -> error::untyped::Result< String >
+  where
+    P : AsRef< Path >,
+  {
+    let ( program, options ) = ( "rustc", [ format!( "+{channel}" ), "-vV".into() ] );
+    let report = Run::former()
+    .bin_path( program )
+    .args( options.into_iter().map( OsString::from ).collect::< Vec< _ > >() )
+    .current_path( path.as_ref().to_path_buf() )
+    .run().map_err::< Error, _ >( | report | err!( report.to_string() ) )?;
+
+    Ok( report.out )
+  }
 }
 
 //
@@ -86,4 +105,5 @@ crate::mod_interface!
 {
   own use Channel;
   own use available_channels;
+  own use toolchain_version;
 }
@@ -0,0 +1,241 @@
+mod private
+{
+  use crate::*;
+
+  use std::{ fmt, fs, ffi::OsString };
+  use path::{ Path, PathBuf };
+  use process_tools::process::*;
+  use error::untyped::{ Context, Result, format_err };
+
+  /// Directory of coverage data in the target directory of the workspace.
+  pub const COVERAGE_DIR : &str = "coverage";
+
+  /// Flags of an instrumented build.
+  pub const INSTRUMENT_FLAGS : &str = "-C instrument-coverage";
+
+  /// Line coverage of a package.
+  ///
+  /// Serialized as `{ lcov, lines_found, lines_hit }`.
+  #[ derive( Debug, Clone, PartialEq, Eq, serde::Serialize ) ]
+  pub struct Coverage
+  {
+    /// File with the coverage in the lcov format.
+    pub lcov : PathBuf,
+    /// Count of instrumented lines of sources of the package.
+    pub lines_found : u64,
+    /// Count of lines executed at least once.
+    pub lines_hit : u64,
+  }
+
+  impl Coverage
+  {
+    /// Reads the coverage from the lcov file.
+    pub fn read< P : AsRef< Path > >( lcov : P ) -> Result< Self >
+    {
+      let lcov = lcov.as_ref();
+      let content = fs::read_to_string( lcov ).with_context( || format!( "Failed to read {}", lcov.display() ) )?;
+      let ( lines_found, lines_hit ) = lcov_lines( &content );
+
+      Ok( Self { lcov : lcov.to_path_buf(), lines_found, lines_hit } )
+    }
+
+    /// Percentage of executed lines, a package without instrumented lines is fully covered.
+    pub fn percent( &self ) -> f64
+    {
+      if self.lines_found == 0 { 100.0 } else { self.lines_hit as f64 * 100.0 / self.lines_found as f64 }
+    }
+  }
+
+  impl fmt::Display for Coverage
+  {
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      write!( f, "{:.1} % ( {} / {} lines )", self.percent(), self.lines_hit, self.lines_found )
+    }
+  }
+
+  /// Path to the lcov file of the package in the coverage directory.
+  pub fn lcov_path< P : AsRef< Path > >( coverage_dir : P, package_name : &str ) -> PathBuf
+  {
+    coverage_dir.as_ref().join( format!( "{package_name}.lcov" ) )
+  }
+
+  /// Value of `LLVM_PROFILE_FILE` which writes raw profiles of all test binaries of a run into the directory.
+  pub fn profile_file< P : AsRef< Path > >( profraw_dir : P ) -> PathBuf
+  {
+    // `%p` is the id of the process and `%m` is the signature of the binary, so binaries and their runs do not overwrite profiles of each other
+    profraw_dir.as_ref().join( "%p-%m.profraw" )
+  }
+
+  /// Sums counts of instrumented ( `LF` ) and executed ( `LH` ) lines of all records.
+  pub fn lcov_lines( content : &str ) -> ( u64, u64 )
+  {
+    content.lines().fold( ( 0, 0 ), | ( found, hit ), line |
+    {
+      let count = | prefix | line.strip_prefix( prefix ).and_then( | v : &str | v.trim().parse::< u64 >().ok() ).unwrap_or( 0 );
+      ( found + count( "LF:" ), hit + count( "LH:" ) )
+    })
+  }
+
+  /// Keeps records of source files located in the directory, other records are coverage of dependencies and of the standard library.
+  pub fn lcov_filter< P : AsRef< Path > >( content : &str, dir : P ) -> String
+  {
+    let mut filtered = String::new();
+    let mut record = String::new();
+    let mut keep = false;
+    for line in content.lines()
+    {
+      if let Some( file ) = line.strip_prefix( "SF:" )
+      {
+        keep = Path::new( file ).starts_with( dir.as_ref() );
+      }
+      if keep
+      {
+        record.push_str( line );
+        record.push( '\n' );
+      }
+      if line == "end_of_record"
+      {
+        filtered.push_str( &record );
+        record.clear();
+        keep = false;
+      }
+    }
+
+    filtered
+  }
+
+  /// Options of collection of the coverage of a package.
+  #[ derive( Debug, former::Former ) ]
+  pub struct CoverageOptions
+  {
+    /// Channel the instrumented binaries were built with, the LLVM tools of its toolchain read their profiles.
+    channel : channel::Channel,
+    /// Directory of the package, only its sources are kept.
+    crate_dir : CrateDir,
+    /// Directory with raw profiles of runs, they are searched recursively.
+    profraw_dir : PathBuf,
+    /// Instrumented test binaries the profiles were written by.
+    objects : Vec< PathBuf >,
+    /// File to write the coverage to.
+    lcov : PathBuf,
+  }
+
+  /// Merges raw profiles of a package and exports the coverage of its sources to the lcov file.
+  ///
+  /// Requires the `llvm-tools-preview` component of the toolchain of the channel.
+  // qqq : typed error
+  pub fn collect( o : CoverageOptions ) -> Result< Coverage >
+  {
+    let profiles = profraw_files( &o.profraw_dir )?;
+    if profiles.is_empty()
+    {
+      return Err( format_err!( "No raw profiles were written to `{}`", o.profraw_dir.display() ) );
+    }
+    let mut objects = o.objects;
+    objects.sort();
+    objects.dedup();
+    let Some( ( object, others ) ) = objects.split_first() else
+    {
+      return Err( format_err!( "No instrumented test binaries were built" ) );
+    };
+
+    let profdata = o.profraw_dir.join( "merged.profdata" );
+    let merge = [ OsString::from( "merge" ), "-sparse".into(), "-o".into(), profdata.clone().into() ]
+    .into_iter()
+    .chain( profiles.into_iter().map( OsString::from ) );
+    let export = [ OsString::from( "export" ), "-format=lcov".into(), format!( "-instr-profile={}", profdata.display() ).into(), object.into() ]
+    .into_iter()
+    .chain( others.iter().flat_map( | o | [ OsString::from( "-object" ), o.into() ] ) );
+
+    let dir = o.crate_dir.clone().absolute_path().inner();
+    llvm_tool_run( o.channel, "llvm-profdata", merge.collect(), &dir )?;
+    let exported = llvm_tool_run( o.channel, "llvm-cov", export.collect(), &dir )?;
+
+    let content = lcov_filter( &exported.out, dir.join( "src" ) );
+    if let Some( parent ) = o.lcov.parent()
+    {
+      fs::create_dir_all( parent )?;
+    }
+    fs::write( &o.lcov, &content ).with_context( || format!( "Failed to write {}", o.lcov.display() ) )?;
+    let ( lines_found, lines_hit ) = lcov_lines( &content );
+
+    Ok( Coverage { lcov : o.lcov, lines_found, lines_hit } )
+  }
+
+  /// Raw profiles in the directory and in its subdirectories.
+  fn profraw_files( dir : &Path ) -> Result< Vec< PathBuf > >
+  {
+    let mut files = vec![];
+    for entry in walkdir::WalkDir::new( dir )
+    {
+      let entry = entry?;
+      if entry.path().extension().is_some_and( | e | e == "profraw" )
+      {
+        files.push( entry.into_path() );
+      }
+    }
+    files.sort();
+
+    Ok( files )
+  }
+
+  /// Runs the LLVM tool shipped with the toolchain of the channel.
+  fn llvm_tool_run( channel : channel::Channel, name : &str, args : Vec< OsString >, path : &Path ) -> Result< Report >
+  {
+    let rustc = | args : &[ &str ] | -> Result< String >
+    {
+      let report = Run::former()
+      .bin_path( "rustup" )
+      .args( [ "run", &channel.to_string(), "rustc" ].iter().chain( args ).map( OsString::from ).collect::< Vec< _ > >() )
+      .current_path( path.to_path_buf() )
+      .run()
+      .map_err( | report | format_err!( report.to_string() ) )?;
+      Ok( report.out )
+    };
+    let sysroot = rustc( &[ "--print", "sysroot" ] )?;
+    let version = rustc( &[ "-vV" ] )?;
+    let host = version
+    .lines()
+    .find_map( | l | l.strip_prefix( "host:" ) )
+    .ok_or_else( || format_err!( "Failed to find the host triple of the `{channel}` toolchain" ) )?;
+    let tool = Path::new( sysroot.trim() )
+    .join( "lib" ).join( "rustlib" ).join( host.trim() ).join( "bin" )
+    .join( format!( "{name}{}", std::env::consts::EXE_SUFFIX ) );
+    if !tool.exists()
+    {
+      return Err( format_err!( "`{name}` is not found. Install it with `rustup component add llvm-tools-preview --toolchain {channel}`" ) );
+    }
+
+    Run::former()
+    .bin_path( tool )
+    .args( args )
+    .current_path( path.to_path_buf() )
+    .run()
+    .map_err( | report | format_err!( report.to_string() ) )
+  }
+
+  /// Badge of the coverage for the health table.
+  pub fn coverage_badge( coverage : &Coverage ) -> String
+  {
+    let percent = coverage.percent();
+    let color = if percent >= 80.0 { "green" } else if percent >= 50.0 { "yellow" } else { "red" };
+    format!( "![coverage](https://raster.shields.io/static/v1?label=&message={percent:.0}%25&color={color})" )
+  }
+}
+
+//
+
+crate::mod_interface!
+{
+  own use COVERAGE_DIR;
+  own use INSTRUMENT_FLAGS;
+  own use Coverage;
+  own use lcov_path;
+  own use profile_file;
+  own use lcov_lines;
+  own use lcov_filter;
+  own use CoverageOptions;
+  own use collect;
+  own use coverage_badge;
+}
@@ -40,6 +40,12 @@ mod private
       self.inner.name.clone()
     }
 
+    /// New name of the dependency if it is renamed in the Cargo.toml.
+    pub fn rename( &self ) -> Option< String >
+    {
+      self.inner.rename.clone()
+    }
+
     /// The kind of dependency this is.
     pub fn kind( &self ) -> DependencyKind
     {
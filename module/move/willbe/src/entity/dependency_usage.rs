@@ -0,0 +1,373 @@
+mod private
+{
+  use crate::*;
+
+  use std::
+  {
+    fmt,
+    fs,
+  };
+  use path::PathBuf;
+  use collection::{ BTreeMap, BTreeSet };
+  use iter::Itertools;
+  use error::untyped::{ Context, Result };
+
+  /// Key of `[package.metadata]` with names of dependencies which are used without being referenced from sources, e.g. only to enable features.
+  pub const UNUSED_IGNORE_METADATA : &str = "unused_dependencies_ignore";
+
+  /// Place in a manifest where a dependency is declared.
+  #[ derive( Debug, Clone, PartialEq, Eq, PartialOrd, Ord, serde::Serialize ) ]
+  pub struct DeclarationLocation
+  {
+    /// Path to the manifest.
+    pub file : PathBuf,
+    /// Number of the line, starting from 1.
+    pub line : usize,
+  }
+
+  impl fmt::Display for DeclarationLocation
+  {
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      write!( f, "{}:{}", self.file.display(), self.line )
+    }
+  }
+
+  /// A dependency which is declared, but not referenced from any source file of the package.
+  #[ derive( Debug, Clone, PartialEq, Eq, serde::Serialize ) ]
+  pub struct UnusedDependency
+  {
+    /// Name of the package which declares the dependency.
+    pub package : String,
+    /// Name of the dependency as it is written in the manifest.
+    pub name : String,
+    /// Table of the manifest : `dependencies`, `dev-dependencies` or `build-dependencies`.
+    pub table : String,
+    /// Line of the declaration.
+    pub location : Option< DeclarationLocation >,
+  }
+
+  impl fmt::Display for UnusedDependency
+  {
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      write!( f, "{} : `{}.{}` is not used", self.package, self.table, self.name )?;
+      if let Some( location ) = &self.location
+      {
+        write!( f, " ( {location} )" )?;
+      }
+
+      Ok( () )
+    }
+  }
+
+  /// A dependency which is resolved to several semver incompatible versions in the lockfile.
+  #[ derive( Debug, Clone, PartialEq, Eq, serde::Serialize ) ]
+  pub struct DuplicatedDependency
+  {
+    /// Name of the dependency.
+    pub name : String,
+    /// Versions from the lockfile.
+    pub versions : Vec< semver::Version >,
+    /// Lines of manifests of the workspace which declare the dependency. The lockfile if it is only a transitive dependency.
+    pub locations : Vec< DeclarationLocation >,
+  }
+
+  impl fmt::Display for DuplicatedDependency
+  {
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      writeln!( f, "{} : {}", self.name, self.versions.iter().join( ", " ) )?;
+      for location in &self.locations
+      {
+        writeln!( f, "  {location}" )?;
+      }
+
+      Ok( () )
+    }
+  }
+
+  /// Name of the table of a manifest with dependencies of the kind.
+  pub fn dependencies_table( kind : DependencyKind ) -> Option< &'static str >
+  {
+    match kind
+    {
+      DependencyKind::Normal => Some( "dependencies" ),
+      DependencyKind::Development => Some( "dev-dependencies" ),
+      DependencyKind::Build => Some( "build-dependencies" ),
+      DependencyKind::Unknown => None,
+    }
+  }
+
+  /// Number of the line ( starting from 1 ) where the dependency is declared in the table of the manifest.
+  ///
+  /// Both `name = ...` inside of `[table]` ( or `[target.'...'.table]` ) and `[table.name]` are recognized.
+  pub fn declaration_line( manifest : &str, table : &str, name : &str ) -> Option< usize >
+  {
+    let mut current = String::new();
+    let in_table = | header : &str | header == table || header.ends_with( &format!( ".{table}" ) );
+    for ( i, line ) in manifest.lines().enumerate()
+    {
+      let line = line.trim();
+      if line.starts_with( '[' )
+      {
+        current = line.trim_start_matches( '[' ).split( ']' ).next().unwrap_or_default().replace( ' ', "" );
+        if let Some( ( header, dependency ) ) = current.rsplit_once( '.' )
+        {
+          if in_table( header ) && dependency.trim_matches( '"' ) == name
+          {
+            return Some( i + 1 );
+          }
+        }
+        continue;
+      }
+      if !in_table( &current ) { continue }
+      let key = line.split( [ '=', '.' ] ).next().unwrap_or_default().trim().trim_matches( '"' );
+      if key == name
+      {
+        return Some( i + 1 );
+      }
+    }
+
+    None
+  }
+
+  /// First segments of paths imported by grouped imports from the root : `use { a, b::c }` and `use ::{ a, b::c }`.
+  fn grouped_use_roots( code : &str ) -> Vec< &str >
+  {
+    let is_ident = | c : char | c.is_alphanumeric() || c == '_';
+    let mut result = vec![];
+    for ( start, _ ) in code.match_indices( "use" )
+    {
+      let after = &code[ start + 3.. ];
+      if code[ ..start ].ends_with( is_ident ) || after.starts_with( is_ident ) { continue }
+      let Some( group ) = after.trim_start().trim_start_matches( "::" ).trim_start().strip_prefix( '{' ) else { continue };
+      // items are split by commas outside of nested groups
+      let mut depth = 0;
+      let mut item_start = 0;
+      for ( i, c ) in group.char_indices()
+      {
+        match c
+        {
+          '{' => depth += 1,
+          '}' if depth == 0 =>
+          {
+            result.push( &group[ item_start..i ] );
+            break;
+          }
+          '}' => depth -= 1,
+          ',' if depth == 0 =>
+          {
+            result.push( &group[ item_start..i ] );
+            item_start = i + 1;
+          }
+          _ => {}
+        }
+      }
+    }
+
+    result
+    .into_iter()
+    .filter_map( | item | item.trim().trim_start_matches( "::" ).split( | c : char | !is_ident( c ) ).next() )
+    .filter( | root | !root.is_empty() )
+    .collect()
+  }
+
+  /// Returns `true` if the code references the crate : `ident::`, `use ident`, `use { ident }` or `extern crate ident`.
+  fn referenced( code : &str, ident : &str ) -> bool
+  {
+    let is_ident = | c : char | c.is_alphanumeric() || c == '_';
+    if grouped_use_roots( code ).contains( &ident )
+    {
+      return true;
+    }
+    code.match_indices( ident ).any( |( start, _ )|
+    {
+      let before = &code[ ..start ];
+      let after = &code[ start + ident.len().. ];
+      if before.ends_with( is_ident ) || after.starts_with( is_ident ) || before.ends_with( '.' )
+      {
+        return false;
+      }
+      let before = before.trim_end().trim_end_matches( "::" ).trim_end();
+      after.trim_start().starts_with( "::" )
+      || before.ends_with( "use" ) && !before.ends_with( "_use" )
+      || before.ends_with( "extern crate" )
+    })
+  }
+
+  /// Possible names of the library of a dependency : `prettytable-rs` is `prettytable`, `sha-1` is `sha1`.
+  fn library_names( name : &str ) -> Vec< String >
+  {
+    let stripped = name.strip_suffix( "-rs" ).or_else( || name.strip_prefix( "rs-" ) ).unwrap_or( name );
+    [ name.replace( '-', "_" ), stripped.replace( '-', "_" ), name.replace( '-', "" ) ]
+    .into_iter()
+    .unique()
+    .collect()
+  }
+
+  /// Dependencies of the package which are not referenced from any of its source files.
+  ///
+  /// Dependencies listed in `[package.metadata] unused_dependencies_ignore` are skipped.
+  /// Metadata of dependencies is not loaded, so the name of the library is guessed from the name of the package.
+  /// A renamed dependency is referenced by its new name.
+  pub fn unused( package : &WorkspacePackageRef< '_ > ) -> Result< Vec< UnusedDependency > >
+  {
+    let manifest_file = package.manifest_file()?;
+    let manifest = fs::read_to_string( &manifest_file )?;
+    let mut sources = vec![];
+    for source in package.sources()
+    {
+      sources.push( source.as_code().with_context( || format!( "Failed to read {source}" ) )?.into_owned() );
+    }
+    let ignore : BTreeSet< &str > = package.metadata()[ UNUSED_IGNORE_METADATA ]
+    .as_array()
+    .map( | a | a.iter().filter_map( | v | v.as_str() ).collect() )
+    .unwrap_or_default();
+
+    let mut result = vec![];
+    for dependency in package.dependencies()
+    {
+      let Some( table ) = dependencies_table( dependency.kind() ) else { continue };
+      let name = dependency.rename().unwrap_or_else( || dependency.name() );
+      if ignore.contains( name.as_str() ) { continue }
+      let idents = if dependency.rename().is_some() { vec![ name.replace( '-', "_" ) ] } else { library_names( &name ) };
+      if sources.iter().any( | code | idents.iter().any( | ident | referenced( code, ident ) ) ) { continue }
+
+      result.push( UnusedDependency
+      {
+        package : package.name().into(),
+        location : declaration_line( &manifest, table, &name )
+        .map( | line | DeclarationLocation { file : manifest_file.clone().into(), line } ),
+        name,
+        table : table.into(),
+      });
+    }
+
+    Ok( result )
+  }
+
+  /// Compatibility class of a version : releases of the same class are semver compatible.
+  fn compatibility( version : &semver::Version ) -> ( u64, u64, u64 )
+  {
+    match ( version.major, version.minor )
+    {
+      ( 0, 0 ) => ( 0, 0, version.patch ),
+      ( 0, minor ) => ( 0, minor, 0 ),
+      ( major, _ ) => ( major, 0, 0 ),
+    }
+  }
+
+  /// Packages of the lockfile reachable by dependencies from the roots, as pairs of a name and a version.
+  ///
+  /// A dependency of an entry is written as `name`, `name version` or `name version (source)`, the version is omitted if it is unambiguous.
+  fn lock_reachable( data : &toml_edit::Document, roots : Vec< ( String, semver::Version ) > ) -> BTreeSet< ( String, semver::Version ) >
+  {
+    let mut graph : BTreeMap< ( String, semver::Version ), Vec< ( String, Option< semver::Version > ) > > = BTreeMap::new();
+    for entry in data.get( "package" ).and_then( | p | p.as_array_of_tables() ).into_iter().flat_map( | e | e.iter() )
+    {
+      let ( Some( name ), Some( version ) ) = ( entry.get( "name" ).and_then( | n | n.as_str() ), entry.get( "version" ).and_then( | v | v.as_str() ) )
+      else { continue };
+      let Ok( version ) = semver::Version::parse( version ) else { continue };
+      let dependencies = entry.get( "dependencies" )
+      .and_then( | d | d.as_array() )
+      .into_iter()
+      .flat_map( | d | d.iter() )
+      .filter_map( | d | d.as_str() )
+      .filter_map( | d |
+      {
+        let mut parts = d.split_whitespace();
+        let name = parts.next()?.to_string();
+        Some( ( name, parts.next().and_then( | v | semver::Version::parse( v ).ok() ) ) )
+      })
+      .collect();
+      graph.insert( ( name.into(), version ), dependencies );
+    }
+
+    let mut reachable = BTreeSet::new();
+    let mut stack = roots;
+    while let Some( node ) = stack.pop()
+    {
+      let Some( dependencies ) = graph.get( &node ) else { continue };
+      if !reachable.insert( node ) { continue }
+      for ( name, version ) in dependencies
+      {
+        stack.extend
+        (
+          graph.keys()
+          .filter( |( n, v )| n == name && ( version.is_none() || version.as_ref() == Some( v ) ) )
+          .cloned()
+        );
+      }
+    }
+
+    reachable
+  }
+
+  /// Dependencies of `packages` which are resolved to several semver incompatible versions in `Cargo.lock` of the workspace.
+  ///
+  /// Only packages of the lockfile reachable from `packages` are considered.
+  /// Each finding is mapped to declarations of the dependency by `packages`, or to the lockfile if none of them declares it.
+  pub fn duplicated< 'a >( workspace : &Workspace, packages : impl Iterator< Item = WorkspacePackageRef< 'a > > ) -> Result< Vec< DuplicatedDependency > >
+  {
+    let packages : Vec< _ > = packages.collect();
+    let lock_file = workspace.workspace_root().as_ref().join( "Cargo.lock" );
+    let lock = fs::read_to_string( &lock_file ).with_context( || format!( "Failed to read {}", lock_file.display() ) )?;
+    let data = lock.parse::< toml_edit::Document >().with_context( || format!( "Failed to parse {}", lock_file.display() ) )?;
+    let roots = packages.iter().map( | p | ( p.name().to_string(), p.version() ) ).collect();
+    let mut versions : BTreeMap< String, BTreeSet< semver::Version > > = BTreeMap::new();
+    for ( name, version ) in lock_reachable( &data, roots )
+    {
+      versions.entry( name ).or_default().insert( version );
+    }
+
+    let mut declarations : BTreeMap< String, BTreeSet< DeclarationLocation > > = BTreeMap::new();
+    for package in packages
+    {
+      let manifest_file = package.manifest_file()?;
+      let manifest = fs::read_to_string( &manifest_file )?;
+      for dependency in package.dependencies()
+      {
+        let Some( table ) = dependencies_table( dependency.kind() ) else { continue };
+        let declared = dependency.rename().unwrap_or_else( || dependency.name() );
+        if let Some( line ) = declaration_line( &manifest, table, &declared )
+        {
+          declarations.entry( dependency.name() ).or_default().insert( DeclarationLocation { file : manifest_file.clone().into(), line } );
+        }
+      }
+    }
+
+    let result = versions
+    .into_iter()
+    .filter( |( _, v )| v.iter().map( compatibility ).unique().count() > 1 )
+    .map( |( name, versions )|
+    {
+      let locations = declarations.remove( &name ).map( | d | d.into_iter().collect() ).unwrap_or_else( ||
+      {
+        let entry = format!( "name = \"{name}\"" );
+        lock.lines().enumerate()
+        .filter( |( _, line )| line.trim() == entry )
+        .map( |( i, _ )| DeclarationLocation { file : lock_file.clone(), line : i + 1 } )
+        .collect()
+      });
+      DuplicatedDependency { name, versions : versions.into_iter().collect(), locations }
+    })
+    .collect();
+
+    Ok( result )
+  }
+}
+
+//
+
+crate::mod_interface!
+{
+  own use UNUSED_IGNORE_METADATA;
+  own use DeclarationLocation;
+  own use UnusedDependency;
+  own use DuplicatedDependency;
+  own use dependencies_table;
+  own use declaration_line;
+  own use unused;
+  own use duplicated;
+}
@@ -6,6 +6,7 @@ mod private
   {
     fmt::Formatter,
   };
+  use former::Former;
   use path::PathBuf;
   use collection::HashMap;
   use colored::Colorize;
@@ -36,8 +37,47 @@ mod private
     Rem( T ),
   }
 
+  /// Default count of unchanged lines around changes of a content diff.
+  pub const DIFF_CONTEXT : usize = 3;
+
+  /// Default size of the largest file in bytes which content is compared.
+  pub const DIFF_MAX_SIZE : usize = 1024 * 1024;
+
+  /// Options of comparison of crate archives.
+  #[ derive( Debug, Clone, Former ) ]
+  pub struct DiffOptions
+  {
+    /// Compare contents of modified text files line by line.
+    #[ former( default = true ) ]
+    pub lines : bool,
+    /// Count of unchanged lines around changes.
+    #[ former( default = DIFF_CONTEXT ) ]
+    pub context : usize,
+    /// Files larger than this size in bytes are reported as modified without a content diff.
+    #[ former( default = DIFF_MAX_SIZE ) ]
+    pub max_size : usize,
+  }
+
+  impl Default for DiffOptions
+  {
+    fn default() -> Self
+    {
+      Self::former().form()
+    }
+  }
+
+  /// A hunk of a unified diff : changed lines with unchanged lines around them.
+  #[ derive( Debug, Clone ) ]
+  pub struct DiffHunk
+  {
+    /// Header of the hunk, like `@@ -1,4 +1,5 @@`.
+    pub header : String,
+    /// Lines of the hunk, each of them ends with a line break unless it is the last line of a file without one.
+    pub lines : Vec< Diff< String > >,
+  }
+
   /// The `DiffItem` enum is designed to represent differences between two versions
-  /// of an item. It contains two variants `File` and `Content`.
+  /// of an item. It contains three variants `File`, `Content` and `Modified`.
   #[ derive( Debug, Clone ) ]
   pub enum DiffItem
   {
@@ -48,14 +88,17 @@ mod private
     ///   - `Add(())`: Represents that the file was added in the new version.
     ///   - `Rem(())`: Represents that the file was removed in the new version.
     File( Diff< () > ),
-    /// - `Content(Vec<Diff<String>>): Represents differences in the content of the item.
-    ///   The `Diff` enum inside `Vec` represents differences in strings present in the file.
+    /// - `Content(Vec<DiffHunk>): Represents differences in the content of the item as hunks of a unified diff.
+    ///   The `Diff` enum inside hunks represents differences in strings present in the file.
     ///   The `Diff` enum contains three possible variants `Same`, `Add`, and `Rem`. Each variant
     ///   of `Diff` represents the status of the string.
     ///   - `Same(String)`: Represents that the string is identical or the same in both versions.
     ///   - `Add(String)`: Represents that the string was added in the new version.
     ///   - `Rem(String)`: Represents that the string was removed in the new version.
-    Content( Vec< Diff< String > > ),
+    Content( Vec< DiffHunk > ),
+    /// - `Modified(Option<String>)`: Represents a modified file which content was not compared.
+    ///   Contains the reason : the file is binary or too large. `None` if line diffs were not requested.
+    Modified( Option< String > ),
   }
 
   /// The `DiffReport` struct represents a diff report containing a list of `Diff` objects.
@@ -100,6 +143,88 @@ mod private
     }
   }
 
+  /// Serialized as `{ path : { status, hunks?, reason? } }`, where `status` is one of `same`, `added`, `removed`, `modified`.
+  /// `hunks` of a modified file is a list of `{ header, changes : [ { tag, line } ] }` with `tag` one of `same`, `add`, `rem`,
+  /// `reason` explains why the content of a modified file was not compared.
+  impl serde::Serialize for DiffReport
+  {
+    fn serialize< S >( &self, serializer : S ) -> Result< S::Ok, S::Error >
+    where
+      S : serde::Serializer,
+    {
+      serializer.collect_map( self.0.iter().sorted_by_key( |( k, _ )| k.as_path() ).map( |( k, v )| ( k.to_string_lossy(), v ) ) )
+    }
+  }
+
+  impl serde::Serialize for DiffItem
+  {
+    fn serialize< S >( &self, serializer : S ) -> Result< S::Ok, S::Error >
+    where
+      S : serde::Serializer,
+    {
+      use serde::ser::SerializeStruct;
+
+      #[ derive( serde::Serialize ) ]
+      struct Line< 'a >
+      {
+        tag : &'a str,
+        line : &'a str,
+      }
+
+      #[ derive( serde::Serialize ) ]
+      struct Hunk< 'a >
+      {
+        header : &'a str,
+        changes : Vec< Line< 'a > >,
+      }
+
+      match self
+      {
+        DiffItem::File( item ) =>
+        {
+          let mut s = serializer.serialize_struct( "DiffItem", 1 )?;
+          let status = match item
+          {
+            Diff::Same( _ ) => "same",
+            Diff::Add( _ ) => "added",
+            Diff::Rem( _ ) => "removed",
+          };
+          s.serialize_field( "status", status )?;
+          s.end()
+        }
+        DiffItem::Content( hunks ) =>
+        {
+          let mut s = serializer.serialize_struct( "DiffItem", 2 )?;
+          s.serialize_field( "status", "modified" )?;
+          let hunks : Vec< _ > = hunks.iter().map( | hunk | Hunk
+          {
+            header : &hunk.header,
+            changes : hunk.lines.iter().map( | item | match item
+            {
+              Diff::Same( line ) => Line { tag : "same", line },
+              Diff::Add( line ) => Line { tag : "add", line },
+              Diff::Rem( line ) => Line { tag : "rem", line },
+            })
+            .collect(),
+          })
+          .collect();
+          s.serialize_field( "hunks", &hunks )?;
+          s.end()
+        }
+        DiffItem::Modified( reason ) =>
+        {
+          let mut s = serializer.serialize_struct( "DiffItem", 2 )?;
+          s.serialize_field( "status", "modified" )?;
+          if let Some( reason ) = reason
+          {
+            s.serialize_field( "reason", reason )?;
+          }
+          s.end()
+        }
+      }
+    }
+  }
+
   impl std::fmt::Display for DiffReport
   {
     fn fmt( &self, f : &mut Formatter< '_ > ) -> std::fmt::Result
@@ -117,22 +242,38 @@ mod private
               Diff::Rem( _ ) => writeln!( f, "- {} REMOVED", path.to_string_lossy().red() )?,
             };
           }
-          DiffItem::Content( items ) =>
+          DiffItem::Content( hunks ) =>
           {
             let path = path.to_string_lossy();
-            let len = path.len() + "~  MODIFIED".len();
             writeln!( f, "~ {} MODIFIED", path.yellow() )?;
-            writeln!( f, "{}", "=".repeat( len + 2 ) )?;
-            for item in items
+            writeln!( f, "--- {path} (published)" )?;
+            writeln!( f, "+++ {path} (local)" )?;
+            for hunk in hunks
             {
-              match item
+              writeln!( f, "{}", hunk.header.cyan() )?;
+              for item in &hunk.lines
               {
-                Diff::Same( t ) => write!( f, "|   {}", t )?,
-                Diff::Add( t ) => write!( f, "| + {}", t.green() )?,
-                Diff::Rem( t ) => write!( f, "| - {}", t.red() )?,
-              };
+                let ( sign, line ) = match item
+                {
+                  Diff::Same( t ) => ( " ", t.normal() ),
+                  Diff::Add( t ) => ( "+", t.green() ),
+                  Diff::Rem( t ) => ( "-", t.red() ),
+                };
+                write!( f, "{sign}{line}" )?;
+                if !line.ends_with( '\n' )
+                {
+                  writeln!( f, "\n\\ No newline at end of file" )?;
+                }
+              }
+            }
+          }
+          DiffItem::Modified( reason ) =>
+          {
+            match reason
+            {
+              Some( reason ) => writeln!( f, "~ {} MODIFIED ({reason})", path.to_string_lossy().yellow() )?,
+              None => writeln!( f, "~ {} MODIFIED", path.to_string_lossy().yellow() )?,
             }
-            writeln!( f, "{}", "=".repeat( len + 2 ) )?;
           }
         };
       }
@@ -157,6 +298,15 @@ mod private
   ///
   /// A `DiffReport` struct, representing the unique and shared attributes of the two crate archives.
   pub fn crate_diff( left : &CrateArchive, right : &CrateArchive ) -> DiffReport
+  {
+    crate_diff_with( left, right, &DiffOptions::default() )
+  }
+
+  /// Creates a differential report between two crate archives, like `crate_diff`, with the specified options.
+  ///
+  /// Contents of modified text files are compared line by line if `lines` is enabled. Binary files and files larger than `max_size`
+  /// are reported as modified without a content diff.
+  pub fn crate_diff_with( left : &CrateArchive, right : &CrateArchive, options : &DiffOptions ) -> DiffReport
   {
     let mut report = DiffReport::default();
 
@@ -189,29 +339,197 @@ mod private
       }
       else
       {
-        let mut items = vec![];
-        let local_str = String::from_utf8_lossy( local );
-        let remote_str = String::from_utf8_lossy( remote );
-        let diff = TextDiff::from_lines( &remote_str, &local_str );
-        for hunk in diff.unified_diff().context_radius( 5 ).iter_hunks()
+        report.0.insert( path.to_path_buf(), content_diff( remote, local, options ) );
+      }
+    }
+
+    report
+  }
+
+  /// Change of a dependency requirement between two manifests.
+  #[ derive( Debug, Clone, PartialEq, Eq, serde::Serialize ) ]
+  pub struct DependencyChange
+  {
+    /// Section of the manifest, like `dependencies` or `target.cfg(unix).dev-dependencies`.
+    pub section : String,
+    /// Name of the dependency.
+    pub name : String,
+    /// Requirement of the published version, `None` if the dependency was added.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub old : Option< String >,
+    /// Requirement of the local version, `None` if the dependency was removed.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub new : Option< String >,
+  }
+
+  /// Differences between manifests of two versions of a crate : the version of the package and requirements of dependencies.
+  ///
+  /// Serialized as `{ version?, dependencies : [ { section, name, old?, new? } ] }`, where `version` is `[ old, new ]`.
+  #[ derive( Debug, Default, Clone, PartialEq, Eq, serde::Serialize ) ]
+  pub struct ManifestDiff
+  {
+    /// Versions of the package, if they differ.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub version : Option< ( String, String ) >,
+    /// Changed, added and removed dependencies.
+    pub dependencies : Vec< DependencyChange >,
+  }
+
+  impl ManifestDiff
+  {
+    /// Checks if the manifests differ.
+    pub fn has_changes( &self ) -> bool
+    {
+      self.version.is_some() || !self.dependencies.is_empty()
+    }
+  }
+
+  impl std::fmt::Display for ManifestDiff
+  {
+    fn fmt( &self, f : &mut Formatter< '_ > ) -> std::fmt::Result
+    {
+      if let Some(( old, new )) = &self.version
+      {
+        writeln!( f, "version : {old} -> {new}" )?;
+      }
+      for change in &self.dependencies
+      {
+        match ( &change.old, &change.new )
         {
-          for change in hunk.iter_changes()
-          {
-            let item = match change.tag()
-            {
-              ChangeTag::Delete => Diff::Rem( change.to_string() ),
-              ChangeTag::Insert => Diff::Add( change.to_string() ),
-              ChangeTag::Equal => Diff::Same( change.to_string() ),
-            };
-            items.push( item );
-          }
+          ( Some( old ), Some( new ) ) => writeln!( f, "[{}] {} : {old} -> {}", change.section, change.name, new.yellow() )?,
+          ( None, Some( new ) ) => writeln!( f, "[{}] {} : {} {new}", change.section, change.name, "added".green() )?,
+          ( Some( old ), None ) => writeln!( f, "[{}] {} : {} {old}", change.section, change.name, "removed".red() )?,
+          ( None, None ) => {}
         }
-        report.0.insert( path.to_path_buf(), DiffItem::Content( items ) );
+      }
+
+      Ok( () )
+    }
+  }
+
+  /// Compares manifests of two crate archives. The manifests are parsed, so formatting and order of keys do not matter.
+  ///
+  /// # Arguments
+  ///
+  /// * `left`: The new ( local ) version of the crate.
+  /// * `right`: The old ( published ) version of the crate.
+  pub fn manifest_diff( left : &CrateArchive, right : &CrateArchive ) -> ManifestDiff
+  {
+    let new = manifest_read( left );
+    let old = manifest_read( right );
+    let version = | manifest : &Option< toml_edit::Document > |
+    manifest.as_ref().and_then( | m | m.get( "package" )?.get( "version" )?.as_str().map( String::from ) );
+
+    let mut report = ManifestDiff::default();
+    if let ( Some( old ), Some( new ) ) = ( version( &old ), version( &new ) )
+    {
+      if old != new
+      {
+        report.version = Some(( old, new ));
+      }
+    }
+    let old = old.as_ref().map( dependencies_collect ).unwrap_or_default();
+    let new = new.as_ref().map( dependencies_collect ).unwrap_or_default();
+    let keys : collection::BTreeSet< _ > = old.keys().chain( new.keys() ).collect();
+    for key in keys
+    {
+      let ( old, new ) = ( old.get( key ), new.get( key ) );
+      if old != new
+      {
+        report.dependencies.push( DependencyChange
+        {
+          section : key.0.clone(),
+          name : key.1.clone(),
+          old : old.cloned(),
+          new : new.cloned(),
+        });
       }
     }
 
     report
   }
+
+  /// Parses the manifest of a crate archive ( `name-version/Cargo.toml` ).
+  fn manifest_read( archive : &CrateArchive ) -> Option< toml_edit::Document >
+  {
+    let path = archive.list().into_iter().find( | p | p.components().count() == 2 && p.file_name().map_or( false, | f | f == "Cargo.toml" ) )?;
+    String::from_utf8_lossy( archive.content_bytes( path )? ).parse().ok()
+  }
+
+  /// Collects requirements of dependencies of a manifest by sections and names.
+  fn dependencies_collect( manifest : &toml_edit::Document ) -> collection::BTreeMap< ( String, String ), String >
+  {
+    const SECTIONS : [ &str; 3 ] = [ "dependencies", "dev-dependencies", "build-dependencies" ];
+
+    let mut sections = vec![];
+    for section in SECTIONS
+    {
+      sections.push(( section.to_string(), manifest.get( section ) ));
+    }
+    if let Some( targets ) = manifest.get( "target" ).and_then( | t | t.as_table_like() )
+    {
+      for ( target, table ) in targets.iter()
+      {
+        for section in SECTIONS
+        {
+          sections.push(( format!( "target.{target}.{section}" ), table.get( section ) ));
+        }
+      }
+    }
+
+    let mut result = collection::BTreeMap::new();
+    for ( section, table ) in sections
+    {
+      let Some( table ) = table.and_then( | t | t.as_table_like() ) else { continue };
+      for ( name, dependency ) in table.iter()
+      {
+        let requirement = dependency.as_str()
+        .or_else( || dependency.get( "version" ).and_then( | v | v.as_str() ) )
+        .unwrap_or( "*" );
+        result.insert( ( section.clone(), name.to_string() ), requirement.to_string() );
+      }
+    }
+
+    result
+  }
+
+  /// Compares contents of two versions of a modified file.
+  fn content_diff( old : &[ u8 ], new : &[ u8 ], options : &DiffOptions ) -> DiffItem
+  {
+    if !options.lines
+    {
+      return DiffItem::Modified( None );
+    }
+    let size = old.len().max( new.len() );
+    if size > options.max_size
+    {
+      return DiffItem::Modified( Some( format!( "larger than {} bytes", options.max_size ) ) );
+    }
+    let ( Ok( old ), Ok( new ) ) = ( std::str::from_utf8( old ), std::str::from_utf8( new ) ) else
+    {
+      return DiffItem::Modified( Some( "binary".into() ) );
+    };
+    if old.contains( '\0' ) || new.contains( '\0' )
+    {
+      return DiffItem::Modified( Some( "binary".into() ) );
+    }
+
+    let diff = TextDiff::from_lines( old, new );
+    let hunks = diff.unified_diff().context_radius( options.context ).iter_hunks().map( | hunk |
+    {
+      let lines = hunk.iter_changes().map( | change | match change.tag()
+      {
+        ChangeTag::Delete => Diff::Rem( change.to_string() ),
+        ChangeTag::Insert => Diff::Add( change.to_string() ),
+        ChangeTag::Equal => Diff::Same( change.to_string() ),
+      })
+      .collect();
+      DiffHunk { header : hunk.header().to_string(), lines }
+    })
+    .collect();
+
+    DiffItem::Content( hunks )
+  }
 }
 
 //
@@ -220,7 +538,15 @@ crate::mod_interface!
 {
   own use Diff;
   own use DiffItem;
+  own use DiffHunk;
+  own use DiffOptions;
   own use DiffReport;
+  own use DependencyChange;
+  own use ManifestDiff;
   own use crate_diff;
+  own use crate_diff_with;
+  own use manifest_diff;
+  own use DIFF_CONTEXT;
+  own use DIFF_MAX_SIZE;
   own use PUBLISH_IGNORE_LIST;
 }
\ No newline at end of file
@@ -0,0 +1,129 @@
+mod private
+{
+  use crate::*;
+
+  use std::
+  {
+    fmt,
+    fs,
+    io,
+  };
+  use path::{ Path, PathBuf };
+  use similar::TextDiff;
+
+  /// A generated file which differs from the file on disk.
+  #[ derive( Debug, Clone, PartialEq, Eq ) ]
+  pub struct FileDrift
+  {
+    /// Path to the file.
+    pub file : PathBuf,
+    /// Unified diff from the file on disk to the generated content.
+    pub diff : String,
+  }
+
+  impl fmt::Display for FileDrift
+  {
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      write!( f, "{}", self.diff )
+    }
+  }
+
+  /// Generated files are out of date.
+  #[ derive( Debug, error::typed::Error ) ]
+  #[ error( "{} generated file(s) are out of date :\n{}", .0.len(), .0.iter().map( | d | d.to_string() ).collect::< String >() ) ]
+  pub struct DriftError( pub Vec< FileDrift > );
+
+  /// Unified diff from `old` to `new` with paths `a/<file>` and `b/<file>` in the header.
+  pub fn unified_diff( file : &Path, old : &str, new : &str ) -> String
+  {
+    let file = file.display();
+    TextDiff::from_lines( old, new )
+    .unified_diff()
+    .context_radius( 3 )
+    .header( &format!( "a/{file}" ), &format!( "b/{file}" ) )
+    .to_string()
+  }
+
+  /// Destination of generated files.
+  ///
+  /// Writes files, or in the check mode compares the generated content with files on disk and collects differences without writing anything.
+  /// A missing file differs from any content.
+  #[ derive( Debug, Default ) ]
+  pub struct GeneratedFiles
+  {
+    check : bool,
+    drifts : Vec< FileDrift >,
+  }
+
+  impl GeneratedFiles
+  {
+    /// Files are compared instead of being written if `check` is set.
+    pub fn new( check : bool ) -> Self
+    {
+      Self { check, drifts : vec![] }
+    }
+
+    /// Files are compared instead of being written.
+    pub fn is_check( &self ) -> bool
+    {
+      self.check
+    }
+
+    /// Writes the content into the file creating missing directories, or compares it with the file in the check mode.
+    pub fn write< C : AsRef< [ u8 ] > >( &mut self, file : &Path, content : C ) -> io::Result< () >
+    {
+      let content = content.as_ref();
+      if self.check
+      {
+        let current = match fs::read( file )
+        {
+          Ok( current ) => current,
+          Err( e ) if e.kind() == io::ErrorKind::NotFound => vec![],
+          Err( e ) => return Err( e ),
+        };
+        if current != content
+        {
+          let diff = unified_diff( file, &String::from_utf8_lossy( &current ), &String::from_utf8_lossy( content ) );
+          self.drifts.push( FileDrift { file : file.to_path_buf(), diff } );
+        }
+        return Ok( () );
+      }
+
+      if let Some( folder ) = file.parent()
+      {
+        fs::create_dir_all( folder )?;
+      }
+      fs::write( file, content )
+    }
+
+    /// Differences found in the check mode.
+    pub fn drifts( &self ) -> &[ FileDrift ]
+    {
+      &self.drifts
+    }
+
+    /// Fails if any generated file differs from the file on disk.
+    pub fn finish( self ) -> Result< (), DriftError >
+    {
+      if self.drifts.is_empty()
+      {
+        Ok( () )
+      }
+      else
+      {
+        Err( DriftError( self.drifts ) )
+      }
+    }
+  }
+}
+
+//
+
+crate::mod_interface!
+{
+  own use FileDrift;
+  own use DriftError;
+  own use unified_diff;
+  own use GeneratedFiles;
+}
@@ -1,10 +1,50 @@
 mod private
 {
   use crate::*;
-  use collection::{ BTreeSet, HashSet };
-  use error::untyped::{ bail }; // xxx
+  use std::{ fmt, str };
+  use collection::{ BTreeMap, BTreeSet, HashMap, HashSet };
+  use error::untyped::{ bail, format_err }; // xxx
   use iter::Itertools;
 
+  /// Strategy of selection of feature combinations to test.
+  #[ derive( Debug, Default, Copy, Clone, PartialEq, Eq, serde::Serialize ) ]
+  #[ serde( rename_all = "snake_case" ) ]
+  pub enum FeaturesStrategy
+  {
+    /// All combinations up to `power` features, see [`features_powerset`].
+    #[ default ]
+    Powerset,
+    /// Combinations which cover every pair of features in each of four on/off states, see [`features_pairwise`].
+    Pairwise,
+  }
+
+  impl fmt::Display for FeaturesStrategy
+  {
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      match self
+      {
+        Self::Powerset => write!( f, "powerset" ),
+        Self::Pairwise => write!( f, "pairwise" ),
+      }
+    }
+  }
+
+  impl str::FromStr for FeaturesStrategy
+  {
+    type Err = error::untyped::Error;
+
+    fn from_str( s : &str ) -> Result< Self, Self::Err >
+    {
+      match s
+      {
+        "powerset" => Ok( Self::Powerset ),
+        "pairwise" => Ok( Self::Pairwise ),
+        e => Err( format_err!( "Unknown features strategy '{e}'. Available values : [powerset, pairwise]" ) ),
+      }
+    }
+  }
+
   /// Generates a powerset of the features available in the given `package`,
   /// filtered according to specified inclusion and exclusion criteria,
   /// and limited by a specified maximum size (`power`).
@@ -95,6 +135,146 @@ mod private
     Ok( features_powerset )
   }
 
+  /// Generates combinations of the features available in the given `package` which cover every pair of features
+  /// in each of four states : both disabled, only the first enabled, only the second enabled and both enabled.
+  ///
+  /// The count of combinations grows logarithmically with the count of features, while the powerset grows exponentially,
+  /// so it is suited for packages with many features. Combinations are built greedily and the result is deterministic.
+  ///
+  /// Arguments have the same meaning as arguments of [`features_powerset`].
+  /// `variants_cap` limits the count of generated combinations.
+  pub fn features_pairwise
+  (
+    package : WorkspacePackageRef< '_ >,
+    exclude_features : &[ String ],
+    include_features : &[ String ],
+    enabled_features : &[ String ],
+    with_all_features : bool,
+    with_none_features : bool,
+    variants_cap : u32,
+  )
+  // qqq : for Petro : typed error
+  -> error::untyped::Result< HashSet< BTreeSet< String > > >
+  {
+    let filtered_features : Vec< _ > = package
+    .features()
+    .keys()
+    .filter( | f | !exclude_features.contains( f ) && (include_features.contains(f) || include_features.is_empty()) )
+    .cloned()
+    .collect();
+
+    let mut result = HashSet::new();
+    for row in pairwise_rows( filtered_features.len() )
+    {
+      let mut subset : BTreeSet< String > = filtered_features
+      .iter()
+      .zip( row )
+      .filter_map( |( f, on )| on.then( || f.clone() ) )
+      .collect();
+      subset.extend( enabled_features.iter().cloned() );
+      result.insert( subset );
+    }
+
+    if with_all_features
+    {
+      result.insert( filtered_features.into_iter().collect() );
+    }
+
+    if with_none_features
+    {
+      result.insert( [].into_iter().collect() );
+      result.insert( enabled_features.iter().cloned().collect() );
+    }
+
+    if result.len() > variants_cap as usize
+    {
+      bail!( "Feature combinations longer then cap." )
+    }
+
+    Ok( result )
+  }
+
+  /// Rows of a covering array of strength 2 for `n` boolean parameters.
+  fn pairwise_rows( n : usize ) -> Vec< Vec< bool > >
+  {
+    if n < 2
+    {
+      return ( 0 .. n ).flat_map( | _ | [ vec![ false ], vec![ true ] ] ).collect();
+    }
+    let mut uncovered : BTreeSet< ( usize, bool, usize, bool ) > = ( 0 .. n )
+    .tuple_combinations()
+    .flat_map( |( i, j )| [ false, true ].into_iter().cartesian_product( [ false, true ] ).map( move |( a, b )| ( i, a, j, b ) ) )
+    .collect();
+
+    let mut rows = vec![];
+    while let Some( &( i, a, j, b ) ) = uncovered.first()
+    {
+      let mut row = vec![ None; n ];
+      row[ i ] = Some( a );
+      row[ j ] = Some( b );
+      for k in 0 .. n
+      {
+        if row[ k ].is_some() { continue }
+        // value which covers more uncovered pairs with already assigned features
+        let gain = | v : bool | row
+        .iter()
+        .enumerate()
+        .filter_map( |( m, value )| value.map( | value | if m < k { ( m, value, k, v ) } else { ( k, v, m, value ) } ) )
+        .filter( | pair | uncovered.contains( pair ) )
+        .count();
+        row[ k ] = Some( gain( true ) > gain( false ) );
+      }
+      let row : Vec< bool > = row.into_iter().map( Option::unwrap_or_default ).collect();
+      for ( i, j ) in ( 0 .. n ).tuple_combinations()
+      {
+        uncovered.remove( &( i, row[ i ], j, row[ j ] ) );
+      }
+      rows.push( row );
+    }
+
+    rows
+  }
+
+  /// Features enabled by the given features, including the given ones.
+  ///
+  /// Entries of features which are not features of the package, like `dep:name` or `name/feature`, are kept in the result as is,
+  /// so combinations which enable different optional dependencies differ. `name/feature` also enables the feature `name` if it exists.
+  pub fn features_closure( features : &BTreeMap< String, Vec< String > >, enabled : &BTreeSet< String > ) -> BTreeSet< String >
+  {
+    let mut result = BTreeSet::new();
+    let mut stack : Vec< _ > = enabled.iter().cloned().collect();
+    while let Some( feature ) = stack.pop()
+    {
+      if !result.insert( feature.clone() ) { continue }
+      if let Some( implied ) = features.get( &feature )
+      {
+        stack.extend( implied.iter().cloned() );
+      }
+      // weak dependency features ( `name?/feature` ) do not enable the dependency
+      if let Some( ( dependency, _ ) ) = feature.split_once( '/' ).filter( |( d, _ )| !d.ends_with( '?' ) && features.contains_key( *d ) )
+      {
+        stack.push( dependency.to_string() );
+      }
+    }
+
+    result
+  }
+
+  /// Removes combinations which enable the same features as other combinations.
+  ///
+  /// Of combinations with an identical closure ( see [`features_closure`] ) the one with the fewest features is kept,
+  /// for example `[ a ]` is kept and `[ a, b ]` is removed if feature `a` enables feature `b`.
+  pub fn dedup_by_closure( package : WorkspacePackageRef< '_ >, combinations : HashSet< BTreeSet< String > > ) -> HashSet< BTreeSet< String > >
+  {
+    let mut by_closure = HashMap::new();
+    for combination in combinations.into_iter().sorted_by( | a, b | a.len().cmp( &b.len() ).then_with( || a.cmp( b ) ) )
+    {
+      by_closure.entry( features_closure( package.features(), &combination ) ).or_insert( combination );
+    }
+
+    by_closure.into_values().collect()
+  }
+
   /// Calculate estimate for `features_powerset.length`
   pub fn estimate_with
   (
@@ -143,6 +323,10 @@ mod private
 crate::mod_interface!
 {
   /// Features
+  own use FeaturesStrategy;
   own use features_powerset;
+  own use features_pairwise;
+  own use features_closure;
+  own use dedup_by_closure;
   own use estimate_with;
 }
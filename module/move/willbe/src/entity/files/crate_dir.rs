@@ -26,7 +26,7 @@ use std::
 use path::{ AbsolutePath, Utf8Path, Utf8PathBuf };
 
 /// Path to crate directory
-#[ derive( Clone, Ord, PartialOrd, Eq, PartialEq, Hash ) ]
+#[ derive( Clone, Ord, PartialOrd, Eq, PartialEq, Hash, serde::Serialize ) ]
 pub struct CrateDir( AbsolutePath );
 
 impl CrateDir
@@ -28,7 +28,7 @@ use path::{ AbsolutePath, Utf8Path, Utf8PathBuf };
 // };
 
 /// Path to crate directory
-#[ derive( Clone, Ord, PartialOrd, Eq, PartialEq, Hash ) ]
+#[ derive( Clone, Ord, PartialOrd, Eq, PartialEq, Hash, serde::Serialize ) ]
 pub struct ManifestFile( AbsolutePath );
 
 impl ManifestFile
@@ -0,0 +1,213 @@
+mod private
+{
+  use crate::*;
+
+  use std::
+  {
+    fmt,
+    fs,
+    io,
+  };
+  use path::{ Path, PathBuf };
+  use iter::Itertools;
+  use test::{ TestReport, TestsReport, TestVariant };
+  use process_tools::process::Report;
+
+  /// Name of the JUnit XML file inside of the report directory.
+  pub const JUNIT_FILE : &str = "junit.xml";
+
+  /// Name of the directory with logs of variants inside of the report directory.
+  pub const LOGS_DIR : &str = "logs";
+
+  /// Files written for a test run.
+  #[ derive( Debug, Default, Clone, serde::Serialize ) ]
+  pub struct ArtifactsReport
+  {
+    /// Path to the JUnit XML file.
+    pub junit : PathBuf,
+    /// Paths to logs of variants.
+    pub logs : Vec< PathBuf >,
+  }
+
+  impl fmt::Display for ArtifactsReport
+  {
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      writeln!( f, "JUnit report : {}", self.junit.display() )?;
+      writeln!( f, "Logs of {} variant(s) : {}", self.logs.len(), self.junit.with_file_name( LOGS_DIR ).display() )
+    }
+  }
+
+  /// File name of a variant, unique inside of a package : `stable_debug_no_features`, `nightly_release_a+b`.
+  pub fn variant_file_stem( variant : &TestVariant ) -> String
+  {
+    let features = if variant.features().is_empty()
+    {
+      "no_features".to_string()
+    }
+    else
+    {
+      variant.features().iter().join( "+" )
+    };
+
+    format!( "{}_{}_{features}", variant.channel(), variant.optimization() )
+    .chars()
+    .map( | c | if c.is_ascii_alphanumeric() || "+-_".contains( c ) { c } else { '_' } )
+    .collect()
+  }
+
+  /// Escapes text for an attribute or an element of XML. Characters which are not allowed in XML 1.0 are dropped.
+  fn escape( text : &str ) -> String
+  {
+    let mut result = String::with_capacity( text.len() );
+    for c in text.chars()
+    {
+      match c
+      {
+        '&' => result.push_str( "&amp;" ),
+        '<' => result.push_str( "&lt;" ),
+        '>' => result.push_str( "&gt;" ),
+        '"' => result.push_str( "&quot;" ),
+        '\'' => result.push_str( "&apos;" ),
+        '\t' | '\n' | '\r' => result.push( c ),
+        c if c < ' ' => {},
+        c => result.push( c ),
+      }
+    }
+
+    result
+  }
+
+  /// Full output of a variant : the command, then stdout and stderr.
+  fn variant_log( report : &Report ) -> String
+  {
+    let mut log = format!( "> {}\n", report.command );
+    log.push_str( &report.out );
+    if !report.err.is_empty()
+    {
+      if !log.ends_with( '\n' ) { log.push( '\n' ) }
+      log.push_str( &report.err );
+    }
+
+    log
+  }
+
+  fn testsuite( report : &TestReport ) -> String
+  {
+    let name = escape( &report.package_name );
+    let failures = report.tests.values().filter( | r | r.is_err() ).count();
+    let mut xml = format!
+    (
+      "  <testsuite name=\"{name}\" tests=\"{}\" failures=\"{failures}\" errors=\"0\" skipped=\"0\">\n",
+      report.tests.len()
+    );
+    for ( variant, result ) in &report.tests
+    {
+      let case = escape( variant.to_string().trim() );
+      xml.push_str( &format!( "    <testcase name=\"{case}\" classname=\"{name}\"" ) );
+      match result
+      {
+        Ok( _ ) if report.cached.contains( variant ) =>
+        {
+          xml.push_str( ">\n      <properties>\n        <property name=\"cached\" value=\"true\"/>\n      </properties>\n    </testcase>\n" );
+        }
+        Ok( _ ) if report.flaky.contains_key( variant ) =>
+        {
+          let failed = report.flaky[ variant ].iter().map( variant_log ).join( "\n" );
+          xml.push_str
+          (
+            &format!
+            (
+              ">\n      <properties>\n        <property name=\"flaky\" value=\"true\"/>\n      </properties>\n      <system-out>{}</system-out>\n    </testcase>\n",
+              escape( &failed ),
+            )
+          );
+        }
+        Ok( _ ) => xml.push_str( "/>\n" ),
+        Err( r ) =>
+        {
+          xml.push_str
+          (
+            &format!
+            (
+              ">\n      <failure message=\"{}\">{}</failure>\n    </testcase>\n",
+              escape( &r.command ),
+              escape( &variant_log( r ) ),
+            )
+          );
+        }
+      }
+    }
+    xml.push_str( "  </testsuite>\n" );
+
+    xml
+  }
+
+  /// JUnit XML of a test run : one `testsuite` per package and one `testcase` per variant.
+  /// Output of a failed variant is the body of its `failure` element, output of failed runs of a flaky variant is its `system-out`.
+  pub fn junit_xml( report : &TestsReport ) -> String
+  {
+    let reports = report.success_reports.iter().chain( &report.failure_reports ).sorted_by( | a, b | a.package_name.cmp( &b.package_name ) );
+    let tests : usize = reports.clone().map( | r | r.tests.len() ).sum();
+    let failures : usize = reports.clone().map( | r | r.tests.values().filter( | r | r.is_err() ).count() ).sum();
+    let mut xml = format!( "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<testsuites name=\"willbe\" tests=\"{tests}\" failures=\"{failures}\">\n" );
+    for r in reports
+    {
+      xml.push_str( &testsuite( r ) );
+    }
+    xml.push_str( "</testsuites>\n" );
+
+    xml
+  }
+
+  /// Writes the JUnit XML file and the full output of each variant into the directory.
+  ///
+  /// Layout of the directory :
+  ///
+  /// ```text
+  /// junit.xml
+  /// logs/<package>/<channel>_<optimization>_<features>.log
+  /// ```
+  ///
+  /// Streams of a variant are joined, so a log holds stdout and stderr in the order they were produced.
+  /// Logs of failed runs of a flaky variant precede the log of the passed run.
+  /// Logs of the previous run are removed.
+  pub fn write_artifacts( report : &TestsReport, dir : &Path ) -> io::Result< ArtifactsReport >
+  {
+    let logs_dir = dir.join( LOGS_DIR );
+    if logs_dir.exists()
+    {
+      fs::remove_dir_all( &logs_dir )?;
+    }
+    let mut result = ArtifactsReport { junit : dir.join( JUNIT_FILE ), logs : vec![] };
+    for package in report.success_reports.iter().chain( &report.failure_reports )
+    {
+      let package_dir = logs_dir.join( package.package_name.as_str() );
+      fs::create_dir_all( &package_dir )?;
+      for ( variant, r ) in &package.tests
+      {
+        let path = package_dir.join( format!( "{}.log", variant_file_stem( variant ) ) );
+        let mut log : String = package.flaky.get( variant ).into_iter().flatten().map( | f | variant_log( f ) + "\n" ).collect();
+        log.push_str( &variant_log( r.as_ref().unwrap_or_else( | e | e ) ) );
+        fs::write( &path, log )?;
+        result.logs.push( path );
+      }
+    }
+    fs::create_dir_all( dir )?;
+    fs::write( &result.junit, junit_xml( report ) )?;
+
+    Ok( result )
+  }
+}
+
+//
+
+crate::mod_interface!
+{
+  own use JUNIT_FILE;
+  own use LOGS_DIR;
+  own use ArtifactsReport;
+  own use variant_file_stem;
+  own use junit_xml;
+  own use write_artifacts;
+}
@@ -0,0 +1,347 @@
+mod private
+{
+  use crate::*;
+
+  use std::fmt;
+  use path::{ Path, PathBuf };
+  use collection::{ BTreeSet, HashMap };
+  use toml_edit::{ value, InlineTable, Item, Value };
+
+  /// Tables of a manifest with dependencies.
+  const DEPENDENCIES_TABLES : [ &str; 3 ] = [ "dependencies", "dev-dependencies", "build-dependencies" ];
+
+  /// Fields of `[package]` required for publishing.
+  const REQUIRED_FIELDS : [ &str; 3 ] = [ "repository", "description", "license" ];
+
+  /// Keys of a dependency which are taken from `[workspace.dependencies]` when the dependency is inherited.
+  const INHERITED_KEYS : [ &str; 8 ] = [ "version", "path", "git", "branch", "tag", "rev", "registry", "default-features" ];
+
+  /// Kind of a problem of a manifest.
+  #[ derive( Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize ) ]
+  #[ serde( rename_all = "snake_case" ) ]
+  pub enum LintKind
+  {
+    /// A dependency is declared in `[workspace.dependencies]`, but the package does not inherit it.
+    NotInherited,
+    /// `repository`, `description` or `license` is missing in `[package]`.
+    MissingField,
+    /// A dependency with `path` has no `version`, so the package can not be published.
+    PathWithoutVersion,
+    /// A feature references a feature or a dependency which is not declared, or a feature of a local dependency which does not exist.
+    UnknownFeatureReference,
+    /// The package has no `Readme.md`.
+    MissingReadme,
+  }
+
+  impl fmt::Display for LintKind
+  {
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      match self
+      {
+        Self::NotInherited => write!( f, "not inherited" ),
+        Self::MissingField => write!( f, "missing field" ),
+        Self::PathWithoutVersion => write!( f, "path without version" ),
+        Self::UnknownFeatureReference => write!( f, "unknown feature reference" ),
+        Self::MissingReadme => write!( f, "missing readme" ),
+      }
+    }
+  }
+
+  /// A problem of a manifest of a package.
+  #[ derive( Debug, Clone, PartialEq, Eq, serde::Serialize ) ]
+  pub struct LintIssue
+  {
+    /// Name of the package.
+    pub package : String,
+    /// Kind of the problem.
+    pub kind : LintKind,
+    /// Description of the problem.
+    pub details : String,
+    /// The problem can be fixed automatically.
+    pub fixable : bool,
+    /// The problem was fixed.
+    pub fixed : bool,
+  }
+
+  impl fmt::Display for LintIssue
+  {
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      let status = if self.fixed { " ( fixed )" } else if self.fixable { " ( fixable )" } else { "" };
+      write!( f, "[{}] {} : {}{status}", self.kind, self.package, self.details )
+    }
+  }
+
+  /// Checks hygiene of the manifest of a package and fixes problems which can be fixed if `fix` is set.
+  ///
+  /// Fixes are applied to `manifest` in memory, formatting and comments of the document are preserved.
+  /// `workspace_manifest` is the manifest of the root of the workspace, it is source of `[workspace.dependencies]` and `[workspace.package]`.
+  ///
+  /// Packages which are not published ( `publish = false` ) are not checked for required fields and versions of path dependencies.
+  pub fn lint( workspace_manifest : &Manifest, manifest : &mut Manifest, fix : bool ) -> Vec< LintIssue >
+  {
+    let package = manifest.data[ "package" ][ "name" ].as_str().unwrap_or_default().to_string();
+    let crate_dir = manifest.crate_dir();
+    let publishable = !manifest.local_is();
+    let mut issues = vec![];
+    let mut issue = | kind, details : String, fixable, fixed |
+    {
+      issues.push( LintIssue { package : package.clone(), kind, details, fixable, fixed } );
+    };
+
+    let workspace = workspace_manifest.data.get( "workspace" );
+    let workspace_dependencies = workspace.and_then( | w | w.get( "dependencies" ) ).and_then( Item::as_table_like );
+    let workspace_package = workspace.and_then( | w | w.get( "package" ) ).and_then( Item::as_table_like );
+
+    for table in DEPENDENCIES_TABLES
+    {
+      let Some( dependencies ) = manifest.data.get_mut( table ).and_then( Item::as_table_like_mut ) else { continue };
+      let names : Vec< String > = dependencies.iter().map( |( name, _ )| name.to_string() ).collect();
+      for name in names
+      {
+        let dependency = dependencies.get_mut( &name ).unwrap();
+        if inherited( dependency ) { continue }
+
+        if workspace_dependencies.is_some_and( | d | d.contains_key( &name ) )
+        {
+          let fixed = fix && inherit( dependency );
+          issue( LintKind::NotInherited, format!( "`{table}.{name}` is declared in `[workspace.dependencies]`, use `{name} = {{ workspace = true }}`" ), true, fixed );
+          continue;
+        }
+
+        let Some( path ) = dependency.get( "path" ).and_then( | p | p.as_str() ) else { continue };
+        if table == "dev-dependencies" || !publishable || dependency.get( "version" ).is_some() { continue }
+        let version = local_version( workspace_manifest, &crate_dir.as_ref().join( path ) );
+        let fixed = match ( fix, &version )
+        {
+          ( true, Some( version ) ) =>
+          {
+            dependency[ "version" ] = value( format!( "~{version}" ) );
+            if let Some( table ) = dependency.as_inline_table_mut()
+            {
+              table.fmt();
+            }
+            true
+          }
+          _ => false,
+        };
+        issue( LintKind::PathWithoutVersion, format!( "`{table}.{name}` has `path`, but no `version`" ), version.is_some(), fixed );
+      }
+    }
+
+    if publishable
+    {
+      for field in REQUIRED_FIELDS
+      {
+        if manifest.data[ "package" ].get( field ).is_some() { continue }
+        let fixable = workspace_package.is_some_and( | p | p.contains_key( field ) );
+        let fixed = fix && fixable &&
+        {
+          let mut inherited = InlineTable::default();
+          inherited.insert( "workspace", true.into() );
+          manifest.data[ "package" ][ field ] = value( inherited );
+          true
+        };
+        issue( LintKind::MissingField, format!( "`package.{field}` is missing" ), fixable, fixed );
+      }
+    }
+
+    for reference in unknown_feature_references( workspace_manifest, manifest )
+    {
+      issue( LintKind::UnknownFeatureReference, reference, false, false );
+    }
+
+    if !readme_exists( crate_dir.as_ref() )
+    {
+      issue( LintKind::MissingReadme, "`Readme.md` is missing".into(), false, false );
+    }
+
+    issues
+  }
+
+  /// Returns `true` if the dependency is `{ workspace = true }`.
+  fn inherited( dependency : &Item ) -> bool
+  {
+    dependency.get( "workspace" ).and_then( | w | w.as_bool() ).unwrap_or( false )
+  }
+
+  /// Replaces the declaration of a dependency by inheritance from the workspace, keeping `features` and `optional`.
+  fn inherit( dependency : &mut Item ) -> bool
+  {
+    if let Some( table ) = dependency.as_table_like_mut()
+    {
+      for key in INHERITED_KEYS
+      {
+        table.remove( key );
+      }
+      table.insert( "workspace", value( true ) );
+      if let Some( table ) = dependency.as_inline_table_mut()
+      {
+        table.fmt();
+      }
+    }
+    else
+    {
+      let mut table = InlineTable::default();
+      table.insert( "workspace", true.into() );
+      *dependency = Item::Value( Value::InlineTable( table ) );
+    }
+
+    true
+  }
+
+  /// Version of a local package, used for a version of a path dependency.
+  fn local_version( workspace_manifest : &Manifest, crate_dir : &Path ) -> Option< String >
+  {
+    let manifest = Manifest::try_from( CrateDir::try_from( crate_dir ).ok()? ).ok()?;
+    let version = &manifest.data.get( "package" )?[ "version" ];
+    if inherited( version )
+    {
+      return workspace_manifest.data.get( "workspace" )?.get( "package" )?.get( "version" )?.as_str().map( String::from );
+    }
+
+    version.as_str().map( String::from )
+  }
+
+  /// Directory of a local dependency : its own `path` or `path` of the declaration in `[workspace.dependencies]`.
+  fn dependency_dir( workspace_manifest : &Manifest, crate_dir : &Path, name : &str, dependency : &Item ) -> Option< PathBuf >
+  {
+    if inherited( dependency )
+    {
+      let path = workspace_manifest.data.get( "workspace" )?.get( "dependencies" )?.get( name )?.get( "path" )?.as_str()?;
+      return Some( workspace_manifest.crate_dir().as_ref().join( path ) );
+    }
+
+    dependency.get( "path" )?.as_str().map( | path | crate_dir.join( path ) )
+  }
+
+  /// Features of a local package including implicit features of its optional dependencies.
+  fn local_features( crate_dir : &Path ) -> Option< BTreeSet< String > >
+  {
+    let manifest = Manifest::try_from( CrateDir::try_from( crate_dir ).ok()? ).ok()?;
+    let mut features : BTreeSet< String > = manifest.data.get( "features" )
+    .and_then( Item::as_table_like )
+    .map( | f | f.iter().map( |( name, _ )| name.to_string() ).collect() )
+    .unwrap_or_default();
+    if let Some( dependencies ) = manifest.data.get( "dependencies" ).and_then( Item::as_table_like )
+    {
+      features.extend
+      (
+        dependencies.iter()
+        .filter( |( _, d )| d.get( "optional" ).and_then( | o | o.as_bool() ).unwrap_or( false ) )
+        .map( |( name, _ )| name.to_string() )
+      );
+    }
+
+    Some( features )
+  }
+
+  /// Tables of dependencies features can reference : `[dependencies]`, `[build-dependencies]` and their platform specific versions.
+  fn feature_dependencies_tables( data : &toml_edit::Document ) -> Vec< &dyn toml_edit::TableLike >
+  {
+    let kinds = [ "dependencies", "build-dependencies" ];
+    let targets = data.get( "target" )
+    .and_then( Item::as_table_like )
+    .into_iter()
+    .flat_map( | targets | targets.iter().map( |( _, target )| target ) );
+
+    kinds.iter().filter_map( | t | data.get( t ) )
+    .chain( targets.flat_map( | target | kinds.iter().filter_map( | t | target.get( t ) ) ) )
+    .filter_map( Item::as_table_like )
+    .collect()
+  }
+
+  /// References of features to features and dependencies which are not declared, and to features of local dependencies which do not exist.
+  ///
+  /// Every item of a feature is checked : `x` must be a feature or a dependency of the package, `dep:x`, `x/feature` and `x?/feature` must reference a dependency.
+  /// Features of local dependencies are known, so `feature` of `x/feature` is checked too.
+  fn unknown_feature_references( workspace_manifest : &Manifest, manifest : &Manifest ) -> Vec< String >
+  {
+    let data = &manifest.data;
+    let Some( features ) = data.get( "features" ).and_then( Item::as_table_like ) else { return vec![] };
+    let crate_dir = manifest.crate_dir();
+    // features of local dependencies, `None` for remote ones
+    let mut dependencies = HashMap::new();
+    for table in feature_dependencies_tables( data )
+    {
+      for ( name, dependency ) in table.iter()
+      {
+        let known = dependency_dir( workspace_manifest, crate_dir.as_ref(), name, dependency ).and_then( | dir | local_features( &dir ) );
+        dependencies.insert( name.to_string(), known );
+      }
+    }
+
+    let mut result = vec![];
+    for ( feature, items ) in features.iter()
+    {
+      let Some( items ) = items.as_array() else { continue };
+      for item in items.iter().filter_map( | i | i.as_str() )
+      {
+        if let Some( dependency ) = item.strip_prefix( "dep:" )
+        {
+          if !dependencies.contains_key( dependency )
+          {
+            result.push( format!( "feature `{feature}` references `{item}`, but `{dependency}` is not a dependency" ) );
+          }
+        }
+        else if let Some( ( dependency, dependency_feature ) ) = item.split_once( '/' )
+        {
+          let name = dependency.trim_end_matches( '?' );
+          match dependencies.get( name )
+          {
+            None => result.push( format!( "feature `{feature}` references `{item}`, but `{name}` is not a dependency" ) ),
+            Some( Some( known ) ) if !known.contains( dependency_feature ) =>
+            {
+              result.push( format!( "feature `{feature}` references `{item}`, but `{name}` has no feature `{dependency_feature}`" ) );
+            }
+            Some( _ ) => {}
+          }
+        }
+        else if !features.contains_key( item ) && !dependencies.contains_key( item )
+        {
+          result.push( format!( "feature `{feature}` references `{item}`, but it is neither a feature nor a dependency" ) );
+        }
+      }
+    }
+
+    result
+  }
+
+  /// Returns `true` if the directory has `Readme.md` in any case.
+  fn readme_exists( dir : &Path ) -> bool
+  {
+    std::fs::read_dir( dir )
+    .map( | entries | entries.flatten().any( | e | e.file_name().to_string_lossy().eq_ignore_ascii_case( "readme.md" ) ) )
+    .unwrap_or( false )
+  }
+
+  /// Path to the manifest of the root of the workspace.
+  pub fn workspace_manifest_file( workspace : &Workspace ) -> PathBuf
+  {
+    workspace.workspace_root().as_ref().join( "Cargo.toml" )
+  }
+
+  /// Path to the manifest of the root of the workspace found without Cargo : the nearest manifest with `[workspace]`
+  /// in the directory of the package or above it, the manifest of the package itself if there is none.
+  ///
+  /// Cargo can not load a workspace with a broken manifest, for example with a feature which references an unknown dependency.
+  pub fn workspace_manifest_find( crate_dir : &Path ) -> PathBuf
+  {
+    crate_dir.ancestors()
+    .map( | dir | dir.join( "Cargo.toml" ) )
+    .find( | manifest | std::fs::read_to_string( manifest ).is_ok_and( | content | content.lines().any( | l | l.trim() == "[workspace]" ) ) )
+    .unwrap_or_else( || crate_dir.join( "Cargo.toml" ) )
+  }
+}
+
+//
+
+crate::mod_interface!
+{
+  own use LintKind;
+  own use LintIssue;
+  own use lint;
+  own use workspace_manifest_file;
+  own use workspace_manifest_find;
+}
@@ -1,5 +1,13 @@
 crate::mod_interface!
 {
+  /// Packages affected by changes.
+  layer affected;
+  orphan use super::affected;
+
+  /// Changelog of a package.
+  layer changelog;
+  orphan use super::changelog;
+
   /// Rust toolchain channel: stable/nightly.
   layer channel;
   orphan use super::channel;
@@ -8,14 +16,26 @@ crate::mod_interface!
   layer code;
   orphan use super::code;
 
+  /// Coverage of sources by tests.
+  layer coverage;
+  orphan use super::coverage;
+
   /// Dependency of a package.
   layer dependency;
   orphan use super::dependency;
 
+  /// Unused and duplicated dependencies.
+  layer dependency_usage;
+  orphan use super::dependency_usage;
+
   /// Compare two crate archives and create a difference report.
   layer diff;
   orphan use super::diff;
 
+  /// Check of generated files against files on disk.
+  layer drift;
+  orphan use super::drift;
+
   /// Operation with features
   layer features;
   orphan use super::features;
@@ -28,6 +48,14 @@ crate::mod_interface!
   layer git;
   orphan use super::git;
 
+  /// JUnit XML and logs of test runs.
+  layer junit;
+  orphan use super::junit;
+
+  /// Manifest hygiene checks.
+  layer lint;
+  orphan use super::lint;
+
   /// To manipulate manifest data.
   layer manifest;
   orphan use super::manifest;
@@ -56,10 +84,22 @@ crate::mod_interface!
   layer progress_bar;
   orphan use super::progress_bar;
 
+  /// Public API of a crate.
+  layer public_api;
+  orphan use super::public_api;
+
   /// Publish.
   layer publish;
   orphan use super::publish;
 
+  /// Journal of a publication, used to resume and to roll it back.
+  layer publish_journal;
+  orphan use super::publish_journal;
+
+  /// Source of published versions of packages.
+  layer registry;
+  orphan use super::registry;
+
   /// Facade for `preatytable` crate.
   layer table;
   orphan use super::table;
@@ -68,6 +108,10 @@ crate::mod_interface!
   layer test;
   orphan use super::test;
 
+  /// Cache of passed tests.
+  layer test_cache;
+  orphan use super::test_cache;
+
   /// Provides an opportunity to work with versions.
   layer version;
   orphan use super::version;
@@ -1,8 +1,9 @@
 mod private
 {
   /// Rust optimization
-  #[ derive( Debug, Default, Copy, Clone, Hash, Eq, PartialEq, Ord, PartialOrd, derive_tools::Display ) ]
+  #[ derive( Debug, Default, Copy, Clone, Hash, Eq, PartialEq, Ord, PartialOrd, derive_tools::Display, serde::Serialize ) ]
   #[ display( style = "snake_case" ) ]
+  #[ serde( rename_all = "snake_case" ) ]
   pub enum Optimization
   {
     /// Debug
@@ -21,6 +21,7 @@ mod private
   (
     Debug, Default, Clone, Hash, Ord, PartialOrd, Eq, PartialEq,
     derive_tools::Display, derive_tools::Deref, derive_tools::From, derive_tools::AsRef,
+    serde::Serialize,
   ) ]
   pub struct PackageName( String );
 
@@ -199,6 +200,8 @@ mod private
   /// Determines whether a package needs to be published by comparing `.crate` files from the local and remote package.
   ///
   /// This function requires the local package to be previously packed.
+  /// The release tag of the version is used instead of the remote package if it is available locally,
+  /// otherwise the remote package is downloaded from the `registry`.
   ///
   /// # Returns :
   /// - `true` if the package needs to be published.
@@ -206,7 +209,7 @@ mod private
   ///
   /// Panics if the package is not loaded or local package is not packed.
 
-  pub fn publish_need< 'a >( package : &Package< 'a >, path : Option< path::PathBuf > ) -> Result< bool, PackageError >
+  pub fn publish_need< 'a >( package : &Package< 'a >, path : Option< path::PathBuf >, registry : &registry::Registry ) -> Result< bool, PackageError >
   {
     let name = package.name()?;
     let version = package.version()?;
@@ -215,11 +218,10 @@ mod private
     .unwrap_or( packed_crate::local_path( &name, &version, package.crate_dir() ).map_err( | _ | PackageError::LocalPath )? );
 
     let local_package = CrateArchive::read( local_package_path ).map_err( | _ | PackageError::ReadArchive )?;
-    let remote_package = match CrateArchive::download_crates_io( name, version )
+    let remote_package = match packed_crate::published( registry, &name, &version, package.crate_dir() )
     {
       Ok( archive ) => archive,
-      // qqq : fix. we don't have to know about the http status code
-      Err( ureq::Error::Status( 403, _ ) ) => return Ok( true ),
+      Err( registry::RegistryError::NotFound( .. ) ) => return Ok( true ),
       _ => return Err( PackageError::LoadRemotePackage ),
     };
 
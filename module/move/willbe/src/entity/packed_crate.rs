@@ -4,13 +4,11 @@ mod private
 
   use std::
   {
-    io::Read,
-    fmt::Write,
-    time::Duration,
+    env,
+    fs,
     path::PathBuf,
   };
-  use error::{ untyped::Context };
-  use ureq::Agent;
+  use crates_tools::CrateArchive;
 
   /// Returns the local path of a packed `.crate` file based on its name, version, and manifest path.
   ///
@@ -35,30 +33,82 @@ mod private
   }
 
   ///
-  /// Get data of remote package from crates.io.
+  /// Get data of remote package from the registry.
   ///
+  pub fn download< 'a >( registry : &registry::Registry, name : &'a str, version : &'a str ) -> Result< Vec< u8 >, registry::RegistryError >
+  {
+    registry.download( name, version )
+  }
+
+  ///
+  /// Get data of a package version from its local release tag `name-vX.Y.Z`.
+  ///
+  /// The tag is checked out into a temporary worktree and the package is packed there by `cargo package`,
+  /// so the archive has the same files and the same normalized manifest as the published one.
+  ///
+  /// # Returns :
+  /// `None` if there is no such tag in the local repository.
   // qqq : typed error
-  pub fn download< 'a >( name : &'a str, version : &'a str ) -> error::untyped::Result< Vec< u8 > >
+  pub fn tagged< 'a >( name : &'a str, version : &'a str, crate_dir : CrateDir ) -> error::untyped::Result< Option< CrateArchive > >
   {
-    let agent : Agent = ureq::AgentBuilder::new()
-    .timeout_read( Duration::from_secs( 5 ) )
-    .timeout_write( Duration::from_secs( 5 ) )
-    .build();
-    let mut buf = String::new();
-    write!( &mut buf, "https://static.crates.io/crates/{0}/{0}-{1}.crate", name, version )?;
+    let tag = changelog::release_tag( name, version );
+    let tags = tool::git::tag_list( &crate_dir, Some( &tag ) )?;
+    if !tags.out.lines().any( | t | t.trim() == tag ) { return Ok( None ) }
 
-    let resp = agent.get( &buf[ .. ] ).call().context( "Get data of remote package" )?;
+    let prefix = tool::git::show_prefix( &crate_dir )?.out;
+    let temp_dir = env::temp_dir().join( format!( "willbe_release_tag_{}", path::unique_folder_name()? ) );
+    let worktree = temp_dir.join( "worktree" );
+    let target = temp_dir.join( "target" );
+    tool::git::worktree_add( &crate_dir, &worktree, &tag )?;
+    let packed = cargo::pack
+    (
+      cargo::PackOptions::former()
+      .path( worktree.join( prefix.trim() ) )
+      .option_temp_path( target.clone() )
+      .allow_dirty( true )
+      .checking_consistency( false )
+      .dry( false )
+      .form()
+    )
+    .and_then( | _ | Ok( fs::read( target.join( "package" ).join( format!( "{name}-{version}.crate" ) ) )? ) );
+    // the worktree is removed whatever the result of packing is
+    let removed = tool::git::worktree_remove( &crate_dir, &worktree );
+    let _ = fs::remove_dir_all( &temp_dir );
+    let bytes = packed?;
+    removed?;
+
+    Ok( Some( CrateArchive::decode( bytes )? ) )
+  }
 
-    let len : usize = resp.header( "Content-Length" )
-    .unwrap()
-    .parse()?;
+  ///
+  /// Versions of a package which have local release tags `name-vX.Y.Z`.
+  ///
+  // qqq : typed error
+  pub fn tagged_versions( name : &str, crate_dir : &CrateDir ) -> error::untyped::Result< Vec< semver::Version > >
+  {
+    let prefix = changelog::release_tag( name, "" );
+    let tags = tool::git::tag_list( crate_dir, Some( &format!( "{prefix}*" ) ) )?;
+    let versions = tags.out
+    .lines()
+    .filter_map( | t | t.trim().strip_prefix( &prefix ) )
+    .filter_map( | v | semver::Version::parse( v ).ok() )
+    .collect();
 
-    let mut bytes : Vec< u8 > = Vec::with_capacity( len );
-    resp.into_reader()
-    .take( u64::MAX )
-    .read_to_end( &mut bytes )?;
+    Ok( versions )
+  }
 
-    Ok( bytes )
+  ///
+  /// Get the published version of a package.
+  ///
+  /// The release tag of the version is used if it is available locally, otherwise the archive is downloaded from the registry.
+  ///
+  pub fn published< 'a >( registry : &registry::Registry, name : &'a str, version : &'a str, crate_dir : CrateDir ) -> Result< CrateArchive, registry::RegistryError >
+  {
+    match tagged( name, version, crate_dir )
+    {
+      Ok( Some( archive ) ) => Ok( archive ),
+      _ => registry.archive( name, version ),
+    }
   }
 
 }
@@ -70,5 +120,8 @@ crate::mod_interface!
 
   own use local_path;
   own use download;
+  own use tagged;
+  own use tagged_versions;
+  own use published;
 
 }
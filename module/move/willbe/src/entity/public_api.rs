@@ -0,0 +1,365 @@
+mod private
+{
+  use crate::*;
+
+  use std::
+  {
+    fmt,
+    path::{ Path, Component },
+  };
+  use collection::BTreeMap;
+  use crates_tools::CrateArchive;
+  use quote::ToTokens;
+  use version::BumpLevel;
+
+  /// Public API surface of a crate.
+  ///
+  /// Maps a path of a public item ( `crate::module::Item`, `crate::Type::method`, `impl Trait for Type`, ... )
+  /// to a normalized signature of the item. Bodies, attributes and doc comments are not a part of the signature.
+  ///
+  /// The surface is collected syntactically : an item is public when it is declared with `pub`,
+  /// regardless of the visibility of the module it is declared in. This covers crates that re-export
+  /// items of private modules through `mod_interface`.
+  ///
+  /// Features of the manifest are a part of the surface too, they are mapped as ``feature `name` `` to the sorted list of what they enable.
+  #[ derive( Debug, Default, Clone, PartialEq, Eq ) ]
+  pub struct PublicApi( pub( crate ) BTreeMap< String, String > );
+
+  impl PublicApi
+  {
+    /// Collects the public API of a crate from sources of a `.crate` archive.
+    ///
+    /// Files that can not be parsed are skipped.
+    pub fn from_archive( archive : &CrateArchive ) -> Self
+    {
+      let mut api = Self::default();
+      for path in archive.list()
+      {
+        if manifest_is( path )
+        {
+          if let Some( content ) = archive.content_bytes( path )
+          {
+            api.features_collect( &String::from_utf8_lossy( content ) );
+          }
+          continue;
+        }
+        let Some( module ) = module_path( path ) else { continue };
+        let Some( content ) = archive.content_bytes( path ) else { continue };
+        let content = String::from_utf8_lossy( content );
+        let Ok( file ) = syn::parse_file( &content ) else { continue };
+        api.items_collect( &module, &file.items );
+      }
+
+      api
+    }
+
+    /// Iterates over paths of public items.
+    pub fn items( &self ) -> impl Iterator< Item = &String >
+    {
+      self.0.keys()
+    }
+
+    fn insert< T : ToTokens >( &mut self, path : String, signature : &T )
+    {
+      self.0.insert( path, signature.to_token_stream().to_string() );
+    }
+
+    /// Collects features of the manifest. Optional dependencies are implicit features enabling `dep:name`, unless any feature refers to a dependency with `dep:`.
+    ///
+    /// The signature of a feature is the sorted list of features and dependencies it enables, so a change of the list is a change of the feature.
+    fn features_collect( &mut self, manifest : &str )
+    {
+      let Ok( manifest ) = manifest.parse::< toml_edit::Document >() else { return };
+      let mut features = vec![];
+      let mut dep_syntax = false;
+      if let Some( table ) = manifest.get( "features" ).and_then( | f | f.as_table_like() )
+      {
+        for ( name, enables ) in table.iter()
+        {
+          let enables : Vec< _ > = enables.as_array().into_iter().flat_map( | a | a.iter() ).filter_map( | v | v.as_str() ).map( String::from ).collect();
+          dep_syntax |= enables.iter().any( | v | v.starts_with( "dep:" ) );
+          features.push( ( name.to_string(), enables ) );
+        }
+      }
+      if !dep_syntax
+      {
+        if let Some( table ) = manifest.get( "dependencies" ).and_then( | d | d.as_table_like() )
+        {
+          let optional = table.iter()
+          .filter( |( _, dependency )| dependency.get( "optional" ).and_then( | o | o.as_bool() ).unwrap_or( false ) )
+          .map( |( name, _ )| ( name.to_string(), vec![ format!( "dep:{name}" ) ] ) );
+          features.extend( optional );
+        }
+      }
+      for ( feature, mut enables ) in features
+      {
+        enables.sort();
+        enables.dedup();
+        self.0.insert( format!( "feature `{feature}`" ), format!( "[ {} ]", enables.join( ", " ) ) );
+      }
+    }
+
+    fn items_collect( &mut self, module : &str, items : &[ syn::Item ] )
+    {
+      for item in items
+      {
+        match item
+        {
+          syn::Item::Fn( f ) if public_is( &f.vis ) =>
+          {
+            self.insert( format!( "{module}::{}", f.sig.ident ), &f.sig );
+          }
+          syn::Item::Struct( s ) if public_is( &s.vis ) =>
+          {
+            let mut s = s.clone();
+            s.attrs.clear();
+            if let syn::Fields::Named( fields ) = &mut s.fields
+            {
+              fields.named = fields.named.clone().into_iter().filter( | f | public_is( &f.vis ) ).collect();
+            }
+            s.fields.iter_mut().for_each( | f | f.attrs.clear() );
+            self.insert( format!( "{module}::{}", s.ident ), &s );
+          }
+          syn::Item::Enum( e ) if public_is( &e.vis ) =>
+          {
+            let mut e = e.clone();
+            e.attrs.clear();
+            for variant in &mut e.variants
+            {
+              variant.attrs.clear();
+              variant.fields.iter_mut().for_each( | f | f.attrs.clear() );
+            }
+            self.insert( format!( "{module}::{}", e.ident ), &e );
+          }
+          syn::Item::Trait( t ) if public_is( &t.vis ) =>
+          {
+            let mut t = t.clone();
+            t.attrs.clear();
+            for item in &mut t.items
+            {
+              match item
+              {
+                syn::TraitItem::Fn( f ) => { f.attrs.clear(); f.default = None; }
+                syn::TraitItem::Const( c ) => { c.attrs.clear(); c.default = None; }
+                syn::TraitItem::Type( ty ) => { ty.attrs.clear(); ty.default = None; }
+                _ => {}
+              }
+            }
+            self.insert( format!( "{module}::{}", t.ident ), &t );
+          }
+          syn::Item::Type( ty ) if public_is( &ty.vis ) =>
+          {
+            let mut ty = ty.clone();
+            ty.attrs.clear();
+            self.insert( format!( "{module}::{}", ty.ident ), &ty );
+          }
+          syn::Item::Const( c ) if public_is( &c.vis ) =>
+          {
+            self.insert( format!( "{module}::{}", c.ident ), &c.ty );
+          }
+          syn::Item::Static( s ) if public_is( &s.vis ) =>
+          {
+            self.insert( format!( "{module}::{}", s.ident ), &s.ty );
+          }
+          syn::Item::Use( u ) if public_is( &u.vis ) =>
+          {
+            let tree = u.tree.to_token_stream().to_string();
+            self.0.insert( format!( "{module}::{{ use {tree} }}" ), String::new() );
+          }
+          syn::Item::Macro( m ) if m.attrs.iter().any( | a | a.path().is_ident( "macro_export" ) ) =>
+          {
+            if let Some( ident ) = &m.ident
+            {
+              self.0.insert( format!( "crate::{ident}!" ), String::new() );
+            }
+          }
+          syn::Item::Mod( m ) =>
+          {
+            if let Some(( _, items )) = &m.content
+            {
+              self.items_collect( &format!( "{module}::{}", m.ident ), items );
+            }
+          }
+          syn::Item::Impl( i ) =>
+          {
+            let self_ty = i.self_ty.to_token_stream().to_string();
+            if let Some(( negative, trait_, _ )) = &i.trait_
+            {
+              let negative = if negative.is_some() { "!" } else { "" };
+              let trait_ = trait_.to_token_stream().to_string();
+              self.insert( format!( "{module}::{{ impl {negative}{trait_} for {self_ty} }}" ), &i.generics );
+            }
+            else
+            {
+              for item in &i.items
+              {
+                if let syn::ImplItem::Fn( f ) = item
+                {
+                  if public_is( &f.vis )
+                  {
+                    self.insert( format!( "{module}::{self_ty}::{}", f.sig.ident ), &f.sig );
+                  }
+                }
+              }
+            }
+          }
+          _ => {}
+        }
+      }
+    }
+  }
+
+  /// Returns `true` for items declared with plain `pub`.
+  fn public_is( vis : &syn::Visibility ) -> bool
+  {
+    matches!( vis, syn::Visibility::Public( _ ) )
+  }
+
+  /// Returns `true` for the manifest of a `.crate` archive ( `name-version/Cargo.toml` ).
+  fn manifest_is( path : &Path ) -> bool
+  {
+    path.components().count() == 2 && path.file_name().map_or( false, | f | f == "Cargo.toml" )
+  }
+
+  /// Converts a path of a file inside of a `.crate` archive ( `name-version/src/module/mod.rs` )
+  /// into a module path ( `crate::module` ). Returns `None` for files outside of `src` and for non-Rust files.
+  fn module_path( path : &Path ) -> Option< String >
+  {
+    if path.extension().map_or( true, | e | e != "rs" ) { return None }
+    let mut components = path.components().filter_map( | c | match c
+    {
+      Component::Normal( c ) => Some( c.to_string_lossy().to_string() ),
+      _ => None,
+    });
+    // skip `name-version` folder
+    components.next()?;
+    if components.next()? != "src" { return None }
+
+    let mut module = vec![ "crate".to_string() ];
+    let rest : Vec< _ > = components.collect();
+    for ( idx, part ) in rest.iter().enumerate()
+    {
+      if idx + 1 == rest.len()
+      {
+        let stem = part.trim_end_matches( ".rs" );
+        if idx == 0 && ( stem == "lib" || stem == "main" ) { break }
+        if stem != "mod" { module.push( stem.to_string() ); }
+      }
+      else
+      {
+        module.push( part.clone() );
+      }
+    }
+
+    Some( module.join( "::" ) )
+  }
+
+  /// Difference between public API of two versions of a crate.
+  ///
+  /// Serialized as `{ added : [ item ], removed : [ item ], changed : [ item ] }`.
+  #[ derive( Debug, Default, Clone, serde::Serialize ) ]
+  pub struct PublicApiDiff
+  {
+    /// Items that are present only in the new version.
+    pub added : Vec< String >,
+    /// Items that are present only in the old version.
+    pub removed : Vec< String >,
+    /// Items which signature differs between versions.
+    pub changed : Vec< String >,
+  }
+
+  impl PublicApiDiff
+  {
+    /// Returns `true` if the public API was not changed.
+    pub fn is_empty( &self ) -> bool
+    {
+      self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+
+    /// Minimal bump level required by the difference.
+    ///
+    /// Removals and changes of signatures are breaking, additions are minor, everything else is a patch.
+    pub fn level( &self ) -> BumpLevel
+    {
+      if !self.removed.is_empty() || !self.changed.is_empty()
+      {
+        BumpLevel::Major
+      }
+      else if !self.added.is_empty()
+      {
+        BumpLevel::Minor
+      }
+      else
+      {
+        BumpLevel::Patch
+      }
+    }
+
+    /// Short human readable explanation of the bump level.
+    pub fn reason( &self ) -> String
+    {
+      fn describe( action : &str, items : &[ String ] ) -> Option< String >
+      {
+        let first = items.first()?;
+        let more = if items.len() > 1 { format!( " and {} more", items.len() - 1 ) } else { String::new() };
+        Some( format!( "{action} `{first}`{more}" ) )
+      }
+
+      let parts : Vec< _ > = match self.level()
+      {
+        BumpLevel::Major => [ describe( "removed", &self.removed ), describe( "changed", &self.changed ) ].into_iter().flatten().collect(),
+        BumpLevel::Minor => describe( "added", &self.added ).into_iter().collect(),
+        BumpLevel::Patch => vec![ "no public API changes".to_string() ],
+      };
+
+      parts.join( ", " )
+    }
+  }
+
+  impl fmt::Display for PublicApiDiff
+  {
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      for item in &self.removed { writeln!( f, "- {item}" )?; }
+      for item in &self.changed { writeln!( f, "~ {item}" )?; }
+      for item in &self.added { writeln!( f, "+ {item}" )?; }
+
+      Ok( () )
+    }
+  }
+
+  /// Compares public API of two crate archives.
+  ///
+  /// # Arguments
+  ///
+  /// * `left` - The new ( local ) version of the crate.
+  /// * `right` - The old ( published ) version of the crate.
+  pub fn public_api_diff( left : &CrateArchive, right : &CrateArchive ) -> PublicApiDiff
+  {
+    let new = PublicApi::from_archive( left );
+    let old = PublicApi::from_archive( right );
+
+    let mut report = PublicApiDiff::default();
+    for ( path, signature ) in &old.0
+    {
+      match new.0.get( path )
+      {
+        None => report.removed.push( path.clone() ),
+        Some( new_signature ) if new_signature != signature => report.changed.push( path.clone() ),
+        Some( _ ) => {}
+      }
+    }
+    report.added = new.0.keys().filter( | path | !old.0.contains_key( *path ) ).cloned().collect();
+
+    report
+  }
+}
+
+//
+
+crate::mod_interface!
+{
+  own use PublicApi;
+  own use PublicApiDiff;
+  own use public_api_diff;
+}
@@ -2,7 +2,12 @@ mod private
 {
   use crate::*;
 
-  use std::fmt;
+  use std::
+  {
+    fmt,
+    sync,
+    time::Duration,
+  };
   use process_tools::process;
   use
   {
@@ -14,6 +19,8 @@ mod private
     }
   };
   use error::ErrWith;
+  use crates_tools::CrateArchive;
+  use publish_journal::{ PublishJournal, PublishStage };
 
   /// Represents instructions for publishing a package.
   #[ derive( Debug, Clone ) ]
@@ -42,6 +49,7 @@ mod private
     package : package::Package< 'a >,
     channel : channel::Channel,
     base_temp_dir : Option< path::PathBuf >,
+    registry : registry::Registry,
     #[ former( default = true ) ]
     dry : bool,
   }
@@ -62,7 +70,11 @@ mod private
         dry : self.dry,
       };
       let old_version : Version = self.package.version().as_ref().unwrap().try_into().unwrap();
-      let new_version = old_version.clone().bump();
+      let ( new_version, level, reason ) = match self.api_diff()
+      {
+        Some( diff ) => ( old_version.clone().bump_with( diff.level() ), Some( diff.level() ), diff.reason() ),
+        None => ( old_version.clone().bump(), None, "no published version to compare with".to_string() ),
+      };
       // bump the package version in dependents (so far, only workspace)
       let dependencies = vec![ CrateDir::try_from( workspace_root.clone() ).unwrap() ];
       let bump = version::BumpOptions
@@ -70,6 +82,8 @@ mod private
         crate_dir : crate_dir.clone(),
         old_version : old_version.clone(),
         new_version : new_version.clone(),
+        level,
+        reason : Some( reason ),
         dependencies : dependencies.clone(),
         dry : self.dry,
       };
@@ -77,7 +91,7 @@ mod private
       {
         git_root : workspace_root,
         items : dependencies.iter().chain([ &crate_dir ]).map( | d | d.clone().absolute_path().join( "Cargo.toml" ) ).collect(),
-        message : format!( "{}-v{}", self.package.name().unwrap(), new_version ),
+        message : changelog::release_tag( self.package.name().unwrap(), &new_version.to_string() ),
         dry : self.dry,
       };
       let publish = cargo::PublishOptions
@@ -85,6 +99,7 @@ mod private
         path : crate_dir.clone().absolute_path().inner(),
         temp_path : self.base_temp_dir.clone(),
         retry_count : 2,
+        index : self.registry.upload_index(),
         dry : self.dry,
       };
 
@@ -98,6 +113,23 @@ mod private
         dry : self.dry,
       }
     }
+
+    /// Compares public API of the packed local package with the last published version.
+    /// The published version is taken from the local release tag if it exists, otherwise it is downloaded from the registry.
+    /// Returns `None` if any of the archives is not available.
+    fn api_diff( &self ) -> Option< public_api::PublicApiDiff >
+    {
+      let name = self.package.name().ok()?;
+      let version = self.package.version().ok()?;
+      let local_path = self.base_temp_dir
+      .as_ref()
+      .map( | p | p.join( format!( "package/{0}-{1}.crate", name, version ) ) )
+      .map_or_else( || packed_crate::local_path( name, &version, self.package.crate_dir() ).ok(), Some )?;
+      let local = CrateArchive::read( local_path ).ok()?;
+      let remote = packed_crate::published( &self.registry, name, &version, self.package.crate_dir() ).ok()?;
+
+      Some( public_api::public_api_diff( &local, &remote ) )
+    }
   }
 
   /// `PublishPlan` manages the overall publication process for multiple packages.
@@ -121,6 +153,9 @@ mod private
     /// Release channels for rust.
     pub channel : channel::Channel,
 
+    /// Registry the local packages are compared with to find changes.
+    pub registry : registry::Registry,
+
     /// `dry` - A boolean value indicating whether to do a dry run. If set to `true`, the application performs
     /// a simulated run without making any actual changes. If set to `false`, the operations are actually executed.
     /// This property is optional and defaults to `true`.
@@ -130,6 +165,25 @@ mod private
     /// Required for tree view only
     pub roots : Vec< CrateDir >,
 
+    /// `journal` - Path to the journal of the publication. Steps of the publication are recorded in it, so a rerun
+    /// after a failure resumes packages from the stages they reached. It is not kept in the dry mode.
+    pub journal : Option< path::PathBuf >,
+
+    /// `concurrent` - Maximum count of packages of a layer which are published at the same time.
+    /// `1` publishes packages one by one.
+    #[ former( default = 1u32 ) ]
+    pub concurrent : u32,
+
+    /// `with_progress` - Shows progress of every package while a layer is published concurrently.
+    /// Works only with the `progress_bar` feature.
+    #[ former( default = false ) ]
+    pub with_progress : bool,
+
+    /// `layers` - Sizes of consecutive groups of `plans`. Packages of a group do not depend on each other, so they
+    /// can be published concurrently. Packages which are not covered by groups are published one by one.
+    #[ scalar( setter = false ) ]
+    pub layers : Vec< usize >,
+
     /// `plans` - This is a vector containing the instructions for publishing each package. Each item
     /// in the `plans` vector indicates a `PackagePublishInstruction` set for a single package. It outlines
     /// how to build and where to publish the package amongst other instructions. The `#[setter( false )]`
@@ -139,6 +193,60 @@ mod private
     pub plans : Vec< PackagePublishInstruction >,
   }
 
+  /// Serialized as `{ workspace_dir, channel, registry, dry, roots, packages : [ package ] }`, where `package` is
+  /// `{ name, crate_dir, old_version, new_version, level?, reason? }`.
+  impl serde::Serialize for PublishPlan
+  {
+    fn serialize< S >( &self, serializer : S ) -> Result< S::Ok, S::Error >
+    where
+      S : serde::Serializer,
+    {
+      #[ derive( serde::Serialize ) ]
+      struct PlanView< 'a >
+      {
+        workspace_dir : &'a CrateDir,
+        channel : channel::Channel,
+        registry : &'a registry::Registry,
+        dry : bool,
+        roots : &'a [ CrateDir ],
+        packages : Vec< PackageView< 'a > >,
+      }
+
+      #[ derive( serde::Serialize ) ]
+      struct PackageView< 'a >
+      {
+        name : &'a package::PackageName,
+        crate_dir : &'a CrateDir,
+        old_version : &'a Version,
+        new_version : &'a Version,
+        #[ serde( skip_serializing_if = "Option::is_none" ) ]
+        level : Option< version::BumpLevel >,
+        #[ serde( skip_serializing_if = "Option::is_none" ) ]
+        reason : Option< &'a String >,
+      }
+
+      PlanView
+      {
+        workspace_dir : &self.workspace_dir,
+        channel : self.channel,
+        registry : &self.registry,
+        dry : self.dry,
+        roots : &self.roots,
+        packages : self.plans.iter().map( | p | PackageView
+        {
+          name : &p.package_name,
+          crate_dir : &p.bump.crate_dir,
+          old_version : &p.bump.old_version,
+          new_version : &p.bump.new_version,
+          level : p.bump.level,
+          reason : p.bump.reason.as_ref(),
+        })
+        .collect(),
+      }
+      .serialize( serializer )
+    }
+  }
+
   impl PublishPlan
   {
     /// Displays a tree-like structure of crates and their dependencies.
@@ -157,7 +265,7 @@ mod private
       let name_bump_report : collection::HashMap< _, _ > = self
       .plans
       .iter()
-      .map( | x | ( x.package_name.as_ref(), ( x.bump.old_version.to_string(), x.bump.new_version.to_string() ) ) )
+      .map( | x | ( x.package_name.as_ref(), ( x.bump.old_version.to_string(), x.bump.new_version.to_string(), bump_describe( &x.bump ) ) ) )
       .collect();
       for wanted in &self.roots
       {
@@ -173,11 +281,11 @@ mod private
         .map_err( |( _, _e )| fmt::Error )?;
         let action::list::ListReport::Tree( list ) = list else { unreachable!() };
 
-        fn callback( name_bump_report : &collection::HashMap< &String, ( String, String ) >, mut r : tool::ListNodeReport ) -> tool::ListNodeReport
+        fn callback( name_bump_report : &collection::HashMap< &String, ( String, String, String ) >, mut r : tool::ListNodeReport ) -> tool::ListNodeReport
         {
-          if let Some(( old, new )) = name_bump_report.get( &r.name )
+          if let Some(( old, new, why )) = name_bump_report.get( &r.name )
           {
-            r.version = Some( format!( "({old} -> {new})" ) );
+            r.version = Some( format!( "({old} -> {new}) {why}" ) );
           }
           r.normal_dependencies = r.normal_dependencies.into_iter().map( | r | callback( name_bump_report, r ) ).collect();
           r.dev_dependencies = r.dev_dependencies.into_iter().map( | r | callback( name_bump_report, r ) ).collect();
@@ -213,13 +321,24 @@ mod private
       for ( idx, package ) in self.plans.iter().enumerate()
       {
         let bump = &package.bump;
-        writeln!( f, "[{idx}] {} ({} -> {})", package.package_name, bump.old_version, bump.new_version )?;
+        writeln!( f, "[{idx}] {} ({} -> {}) {}", package.package_name, bump.old_version, bump.new_version, bump_describe( bump ) )?;
       }
 
       Ok( () )
     }
   }
 
+  /// Describes the level and the reason of a planned version bump.
+  fn bump_describe( bump : &version::BumpOptions ) -> String
+  {
+    let level = bump.level.map_or_else( || "default".to_string(), | l | l.to_string() );
+    match &bump.reason
+    {
+      Some( reason ) => format!( "[{level} : {reason}]" ),
+      None => format!( "[{level}]" ),
+    }
+  }
+
   impl< 'a > PublishPlanFormer
   {
     pub fn option_base_temp_dir( mut self, path : Option< path::PathBuf > ) -> Self
@@ -242,6 +361,10 @@ mod private
       {
         plan = plan.base_temp_dir( base_temp_dir.clone() );
       }
+      if let Some( registry ) = &self.storage.registry
+      {
+        plan = plan.registry( registry.clone() );
+      }
       if let Some( dry ) = self.storage.dry
       {
         plan = plan.dry( dry );
@@ -271,24 +394,54 @@ mod private
       self
     }
 
+    /// Adds a group of packages which do not depend on each other.
+    pub fn layer< IntoPackageIter, IntoPackage >( mut self, packages : IntoPackageIter ) -> Self
+    where
+      IntoPackageIter : IntoIterator< Item = IntoPackage >,
+      IntoPackage : Into< package::Package< 'a > >,
+    {
+      let plans_len = | this : &Self | this.storage.plans.as_ref().map_or( 0, Vec::len );
+      let before = plans_len( &self );
+      self = self.packages( packages );
+      let size = plans_len( &self ) - before;
+      self.storage.layers.get_or_insert_with( Vec::new ).push( size );
+
+      self
+    }
+
   }
 
   /// Holds information about the publishing process.
-  #[ derive( Debug, Default, Clone ) ]
+  ///
+  /// Serialized as `{ get_info?, bump?, changelog?, add?, commit?, push?, publish?, tag? }`, where each step except of `bump` and `changelog` is
+  /// `{ command, current_path, out, err, error? }`. Steps that were not reached are omitted.
+  #[ derive( Debug, Default, Clone, serde::Serialize ) ]
   pub struct PublishReport
   {
     /// Retrieves information about the package.
+    #[ serde( skip_serializing_if = "Option::is_none", serialize_with = "output::process_report_option_serialize" ) ]
     pub get_info : Option< process::Report >,
     /// Bumps the version of the package.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
     pub bump : Option< version::ExtendedBumpReport >,
+    /// Changelog section written for the new version.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub changelog : Option< changelog::ChangelogReport >,
     /// Report of adding changes to the Git repository.
+    #[ serde( skip_serializing_if = "Option::is_none", serialize_with = "output::process_report_option_serialize" ) ]
     pub add : Option< process::Report >,
     /// Report of committing changes to the Git repository.
+    #[ serde( skip_serializing_if = "Option::is_none", serialize_with = "output::process_report_option_serialize" ) ]
     pub commit : Option< process::Report >,
     /// Report of pushing changes to the Git repository.
+    #[ serde( skip_serializing_if = "Option::is_none", serialize_with = "output::process_report_option_serialize" ) ]
     pub push : Option< process::Report >,
     /// Report of publishes the package using the `cargo publish` command.
+    #[ serde( skip_serializing_if = "Option::is_none", serialize_with = "output::process_report_option_serialize" ) ]
     pub publish : Option< process::Report >,
+    /// Report of creating the release tag of the published version.
+    #[ serde( skip_serializing_if = "Option::is_none", serialize_with = "output::process_report_option_serialize" ) ]
+    pub tag : Option< process::Report >,
   }
 
   impl fmt::Display for PublishReport
@@ -299,10 +452,12 @@ mod private
       {
         get_info,
         bump,
+        changelog,
         add,
         commit,
         push,
         publish,
+        tag,
       } = self;
 
       if get_info.is_none()
@@ -317,6 +472,10 @@ mod private
       {
         writeln!( f, "{}", bump )?;
       }
+      if let Some( changelog ) = changelog
+      {
+        writeln!( f, "{}", changelog )?;
+      }
       if let Some( add ) = add
       {
         write!( f, "{add}" )?;
@@ -333,27 +492,40 @@ mod private
       {
         write!( f, "{publish}" )?;
       }
+      if let Some( tag ) = tag
+      {
+        write!( f, "{tag}" )?;
+      }
 
       Ok( () )
     }
   }
 
-  /// Performs package publishing based on the given arguments.
-  ///
-  /// # Arguments
-  ///
-  /// * `args` - The package publishing instructions.
-  ///
-  /// # Returns
-  ///
-  /// * `Result<PublishReport>` - The result of the publishing operation, including information about the publish, version bump, and git operations.
+  /// Time to wait for a published package to appear in the index of the registry, before packages depending on it are published.
+  const INDEX_WAIT_TIMEOUT : Duration = Duration::from_secs( 600 );
 
-  pub fn perform_package_publish( instruction : PackagePublishInstruction ) -> ResultWithReport< PublishReport, Error >
+  /// Package which is committed with the bumped version and is ready to be published.
+  #[ derive( Debug ) ]
+  struct PackageStep
+  {
+    package_name : package::PackageName,
+    new_version : String,
+    publish : cargo::PublishOptions,
+    git_root : AbsolutePath,
+    dry : bool,
+    /// Stage of the package reached before this run.
+    stage : PublishStage,
+    report : PublishReport,
+  }
+
+  /// Packs the package, bumps its version, writes the changelog and commits them.
+  /// Only packing is done if the package was committed in a previous run.
+  fn package_prepare( instruction : PackagePublishInstruction, journal : Option< &mut PublishJournal > ) -> Result< PackageStep, ( PublishReport, Error ) >
   {
     let mut report = PublishReport::default();
     let PackagePublishInstruction
     {
-      package_name: _,
+      package_name,
       mut pack,
       mut bump,
       mut git_options,
@@ -365,44 +537,280 @@ mod private
     git_options.dry = dry;
     publish.dry = dry;
 
+    let stage = journal.as_deref().map_or( PublishStage::Planned, | j | j.stage( package_name.as_ref() ) );
     report.get_info = Some( cargo::pack( pack ).err_with_report( &report )? );
-    // aaa : redundant field? // aaa : removed
-    let bump_report = version::bump( bump ).err_with_report( &report )?;
-    report.bump = Some( bump_report.clone() );
+    let crate_dir = bump.crate_dir.clone();
+    let new_version = bump.new_version.to_string();
     let git_root = git_options.git_root.clone();
-    let git = match entity::git::perform_git_commit( git_options )
+    if stage < PublishStage::Committed
     {
-      Ok( git ) => git,
-      Err( e ) =>
+      let section = changelog::section_collect
+      (
+        &crate_dir,
+        package_name.as_ref(),
+        &bump.old_version.to_string(),
+        &new_version,
+      )
+      .err_with_report( &report )?;
+      // aaa : redundant field? // aaa : removed
+      let bump_report = version::bump( bump ).err_with_report( &report )?;
+      report.bump = Some( bump_report.clone() );
+      let changelog_report = match changelog::section_write( &crate_dir, section, dry )
+      {
+        Ok( changelog_report ) => changelog_report,
+        Err( e ) =>
+        {
+          version::revert( &bump_report )
+          .map_err( | le | format_err!( "Base error:\n{}\nRevert error:\n{}", e.to_string().replace( '\n', "\n\t" ), le.to_string().replace( '\n', "\n\t" ) ) )
+          .err_with_report( &report )?;
+          return Err(( report, e ));
+        }
+      };
+      git_options.items.push( crate_dir.clone().absolute_path().join( changelog::CHANGELOG_FILE ) );
+      report.changelog = Some( changelog_report.clone() );
+      let git = match entity::git::perform_git_commit( git_options )
+      {
+        Ok( git ) => git,
+        Err( e ) =>
+        {
+          version::revert( &bump_report )
+          .and_then( | _ | changelog::revert( &changelog_report ) )
+          .map_err( | le | format_err!( "Base error:\n{}\nRevert error:\n{}", e.to_string().replace( '\n', "\n\t" ), le.to_string().replace( '\n', "\n\t" ) ) )
+          .err_with_report( &report )?;
+          return Err(( report, e ));
+        }
+      };
+      report.add = git.add;
+      report.commit = git.commit;
+      if let Some( journal ) = journal
       {
-        version::revert( &bump_report )
-        .map_err( | le | format_err!( "Base error:\n{}\nRevert error:\n{}", e.to_string().replace( '\n', "\n\t" ), le.to_string().replace( '\n', "\n\t" ) ) )
-        .err_with_report( &report )?;
-        return Err(( report, e ));
+        let head = tool::git::rev_parse( &git_root, "HEAD" ).err_with_report( &report )?;
+        journal.commit_set( package_name.as_ref(), head.out.trim().to_string() ).err_with_report( &report )?;
       }
-    };
-    report.add = git.add;
-    report.commit = git.commit;
-    report.publish = match cargo::publish( publish )
+    }
+
+    Ok( PackageStep { package_name, new_version, publish, git_root, dry, stage, report } )
+  }
+
+  /// Publishes the package to the registry, unless it was published in a previous run.
+  fn package_upload( step : &mut PackageStep ) -> error::untyped::Result< () >
+  {
+    if step.stage < PublishStage::Published
     {
-      Ok( publish ) => Some( publish ),
-      Err( e ) =>
+      step.report.publish = Some( cargo::publish( step.publish.clone() )? );
+    }
+
+    Ok( () )
+  }
+
+  /// Creates the release tag of the package, unless it was created in a previous run.
+  fn package_tag( step : &mut PackageStep ) -> error::untyped::Result< () >
+  {
+    if step.stage < PublishStage::Tagged
+    {
+      let tag = changelog::release_tag( step.package_name.as_ref(), &step.new_version );
+      let message = format!( "{} v{}", step.package_name, step.new_version );
+      step.report.tag = Some( tool::git::tag( &step.git_root, &tag, message, step.dry )? );
+    }
+
+    Ok( () )
+  }
+
+  /// Moves the package to the stage in the journal, unless the package reached the stage in a previous run.
+  fn stage_reach( journal : Option< &mut PublishJournal >, step : &PackageStep, stage : PublishStage ) -> error::untyped::Result< () >
+  {
+    match journal
+    {
+      Some( journal ) if step.stage < stage => journal.stage_set( step.package_name.as_ref(), stage ),
+      _ => Ok( () ),
+    }
+  }
+
+  /// Performs package publishing based on the given arguments.
+  ///
+  /// # Arguments
+  ///
+  /// * `args` - The package publishing instructions.
+  /// * `journal` - The journal of the publication. Every step is recorded in it, steps the package passed in a previous run are skipped.
+  ///
+  /// # Returns
+  ///
+  /// * `Result<PublishReport>` - The result of the publishing operation, including information about the publish, version bump, and git operations.
+  ///
+  /// After the package is published, the commit is marked with an annotated `name-vX.Y.Z` tag, which is pushed together with the commit.
+
+  pub fn perform_package_publish( instruction : PackagePublishInstruction, mut journal : Option< &mut PublishJournal > ) -> ResultWithReport< PublishReport, Error >
+  {
+    let mut step = package_prepare( instruction, journal.as_deref_mut() )?;
+    if let Err( e ) = package_upload( &mut step )
+    {
+      // only the commit of this run is reverted, a commit of a previous run may be followed by commits of other packages
+      if step.stage < PublishStage::Committed
       {
-        tool::git::reset( git_root.as_ref(), true, 1, false )
+        tool::git::reset( step.git_root.as_ref(), true, 1, false )
+        .and_then( | _ | journal.map_or( Ok( () ), | j | j.stage_set( step.package_name.as_ref(), PublishStage::Planned ) ) )
         .map_err
         (
           | le |
           format_err!( "Base error:\n{}\nRevert error:\n{}", e.to_string().replace( '\n', "\n\t" ), le.to_string().replace( '\n', "\n\t" ) )
         )
-        .err_with_report( &report )?;
-        return Err(( report, e ));
+        .err_with_report( &step.report )?;
+      }
+      return Err(( step.report, e ));
+    }
+    stage_reach( journal.as_deref_mut(), &step, PublishStage::Published ).err_with_report( &step.report )?;
+    package_tag( &mut step ).err_with_report( &step.report )?;
+    stage_reach( journal.as_deref_mut(), &step, PublishStage::Tagged ).err_with_report( &step.report )?;
+
+    let res = tool::git::push( &step.git_root, step.dry ).err_with_report( &step.report )?;
+    step.report.push = Some( res );
+    stage_reach( journal, &step, PublishStage::Pushed ).err_with_report( &step.report )?;
+
+    Ok( step.report )
+  }
+
+  /// Publishes packages which do not depend on each other.
+  ///
+  /// Packages are committed one by one, then up to `concurrent` of them are published at the same time.
+  /// Every published package is awaited in the index of the registry it is uploaded to, so the next layer can depend on it.
+  /// When all packages are published, they are tagged and the commits are pushed at once.
+  ///
+  /// Commits of packages which failed to publish are not reverted, they stay `committed` in the journal and a rerun publishes them.
+  fn layer_publish
+  (
+    instructions : Vec< PackagePublishInstruction >,
+    registry : &registry::Registry,
+    concurrent : u32,
+    with_progress : bool,
+    mut journal : Option< &mut PublishJournal >,
+  )
+  -> Result< Vec< PublishReport >, ( Vec< PublishReport >, Error ) >
+  {
+    let mut steps = Vec::with_capacity( instructions.len() );
+    for instruction in instructions
+    {
+      match package_prepare( instruction, journal.as_deref_mut() )
+      {
+        Ok( step ) => steps.push( step ),
+        Err(( report, e )) =>
+        {
+          let mut reports : Vec< _ > = steps.into_iter().map( | s | s.report ).collect();
+          reports.push( report );
+          return Err(( reports, e ));
+        }
+      }
+    }
+
+    #[ cfg( feature = "progress_bar" ) ]
+    let multi_progress = progress_bar::MultiProgress::default();
+    #[ cfg( feature = "progress_bar" ) ]
+    let mm = &multi_progress;
+    #[ cfg( not( feature = "progress_bar" ) ) ]
+    let _ = with_progress;
+    let pool = match rayon::ThreadPoolBuilder::new().use_current_thread().num_threads( concurrent as usize ).build()
+    {
+      Ok( pool ) => pool,
+      Err( e ) =>
+      {
+        let reports = steps.into_iter().map( | s | s.report ).collect();
+        return Err(( reports, Error::from( e ).context( "Failed to create threads to publish packages" ) ));
       }
     };
+    let registry = &registry.upload_registry();
+    let mut failures : Vec< Option< Error > > = steps.iter().map( | _ | None ).collect();
+    let shared_journal = sync::Mutex::new( journal.as_deref_mut() );
+    pool.scope
+    (
+      | s |
+      {
+        for ( step, failure ) in steps.iter_mut().zip( failures.iter_mut() )
+        {
+          let shared_journal = &shared_journal;
+          // upload and waiting for the index
+          #[ cfg( feature = "progress_bar" ) ]
+          let progress = with_progress.then( || mm.progress_bar( 2 ) );
+          s.spawn
+          (
+            move | _ |
+            {
+              #[ cfg( feature = "progress_bar" ) ]
+              if let Some( progress ) = &progress
+              {
+                progress.progress_bar.set_message( format!( "{} v{} : publishing", step.package_name, step.new_version ) );
+              }
+              let result = package_upload( step )
+              .and_then( | _ | stage_reach( shared_journal.lock().unwrap().as_deref_mut(), step, PublishStage::Published ) );
+              #[ cfg( feature = "progress_bar" ) ]
+              if let Some( progress ) = &progress
+              {
+                progress.progress_bar.inc( 1 );
+                progress.progress_bar.set_message( format!( "{} v{} : waiting for the index", step.package_name, step.new_version ) );
+              }
+              let result = result.and_then( | _ |
+              {
+                if step.dry { return Ok( () ) }
+                registry.index_wait( step.package_name.as_ref(), &step.new_version, INDEX_WAIT_TIMEOUT ).map_err( Error::from )
+              });
+              #[ cfg( feature = "progress_bar" ) ]
+              if let Some( progress ) = &progress
+              {
+                progress.progress_bar.inc( 1 );
+                let status = if result.is_ok() { "published" } else { "failed" };
+                progress.progress_bar.finish_with_message( format!( "{} v{} : {status}", step.package_name, step.new_version ) );
+              }
+              *failure = result.err();
+            }
+          );
+        }
+      }
+    );
+    drop( shared_journal );
 
-    let res = tool::git::push( &git_root, dry ).err_with_report( &report )?;
-    report.push = Some( res );
+    let mut errors = vec![];
+    for ( step, failure ) in steps.iter_mut().zip( failures )
+    {
+      if let Some( e ) = failure
+      {
+        errors.push( format!( "{} : {}", step.package_name, e.to_string().replace( '\n', "\n\t" ) ) );
+        continue;
+      }
+      if let Err( e ) = package_tag( step ).and_then( | _ | stage_reach( journal.as_deref_mut(), step, PublishStage::Tagged ) )
+      {
+        errors.push( format!( "{} : {}", step.package_name, e.to_string().replace( '\n', "\n\t" ) ) );
+      }
+    }
+    if !errors.is_empty()
+    {
+      let reports = steps.into_iter().map( | s | s.report ).collect();
+      return Err(( reports, format_err!( "Failed to publish packages of the layer :\n{}", errors.join( "\n" ) ) ));
+    }
 
-    Ok( report )
+    let ( git_root, dry ) = ( steps[ 0 ].git_root.clone(), steps[ 0 ].dry );
+    let push = tool::git::push( &git_root, dry ).and_then
+    (
+      | push |
+      {
+        for step in &steps
+        {
+          stage_reach( journal.as_deref_mut(), step, PublishStage::Pushed )?;
+        }
+        Ok( push )
+      }
+    );
+    let mut reports : Vec< _ > = steps.into_iter().map( | s | s.report ).collect();
+    match push
+    {
+      Ok( push ) =>
+      {
+        // the push is shared by the layer, it is reported once
+        if let Some( last ) = reports.last_mut()
+        {
+          last.push = Some( push );
+        }
+        Ok( reports )
+      }
+      Err( e ) => Err(( reports, e )),
+    }
   }
 
   /// Perform publishing of multiple packages based on the provided publish plan.
@@ -414,14 +822,55 @@ mod private
   /// # Returns
   ///
   /// Returns a `Result` containing a vector of `PublishReport` if successful, else an error.
+  ///
+  /// Unless the plan is dry, steps are recorded in the journal of the plan. Packages which are already in the journal
+  /// continue from the stages they reached. The journal is removed when all its packages are pushed.
+  ///
+  /// If `concurrent` of the plan is greater than `1`, packages of every layer of the plan are published concurrently,
+  /// the next layer is started when all packages of the previous one are in the index of the registry.
   pub fn perform_packages_publish( plan : PublishPlan ) -> error::untyped::Result< Vec< PublishReport > >
   // qqq : use typed error
   {
+    let mut journal = match &plan.journal
+    {
+      Some( file ) if !plan.dry =>
+      {
+        let mut journal = PublishJournal::load( file )?
+        .unwrap_or_else( || PublishJournal::new( file, plan.workspace_dir.clone().absolute_path().inner() ) );
+        journal.plan_merge( &plan );
+        journal.store()?;
+        Some( journal )
+      }
+      _ => None,
+    };
+    // packages which are not covered by layers are published one by one
+    let covered : usize = plan.layers.iter().sum();
+    let sizes : Vec< _ > = plan.layers.iter().copied()
+    .chain( std::iter::repeat( 1 ).take( plan.plans.len().saturating_sub( covered ) ) )
+    .collect();
+    let mut instructions = plan.plans.into_iter();
     let mut report = vec![];
-    for package in plan.plans
+    for size in sizes
+    {
+      let layer : Vec< _ > = instructions.by_ref().take( size ).collect();
+      if plan.concurrent <= 1 || layer.len() <= 1
+      {
+        for package in layer
+        {
+          let res = perform_package_publish( package, journal.as_mut() ).map_err( |( current_rep, e )| format_err!( "{}\n{current_rep}\n{e}", report.iter().map( | r | format!( "{r}" ) ).join( "\n" ) ) )?;
+          report.push( res );
+        }
+      }
+      else
+      {
+        let res = layer_publish( layer, &plan.registry, plan.concurrent, plan.with_progress, journal.as_mut() )
+        .map_err( |( current_reps, e )| format_err!( "{}\n{e}", report.iter().chain( &current_reps ).map( | r | format!( "{r}" ) ).join( "\n" ) ) )?;
+        report.extend( res );
+      }
+    }
+    if let Some( journal ) = journal.filter( | j | j.unfinished().next().is_none() )
     {
-      let res = perform_package_publish( package ).map_err( |( current_rep, e )| format_err!( "{}\n{current_rep}\n{e}", report.iter().map( | r | format!( "{r}" ) ).join( "\n" ) ) )?;
-      report.push( res );
+      journal.remove()?;
     }
 
     Ok( report )
@@ -0,0 +1,271 @@
+mod private
+{
+  use crate::*;
+
+  use std::{ fmt, fs, io };
+  use path::{ Path, PathBuf };
+  use process_tools::process;
+  use iter::Itertools;
+  use error::untyped::{ Context, Result, format_err };
+
+  /// Name of the journal file in the target directory of the workspace.
+  pub const JOURNAL_FILE : &str = "publish_journal.json";
+
+  /// Stage of the publication of a package reached so far. Stages are ordered.
+  #[ derive( Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize ) ]
+  #[ serde( rename_all = "snake_case" ) ]
+  pub enum PublishStage
+  {
+    /// Nothing is changed yet.
+    Planned,
+    /// The bumped version and the changelog are committed, but the package is not in the registry.
+    Committed,
+    /// The package is in the registry.
+    Published,
+    /// The release tag is created.
+    Tagged,
+    /// The commit and the tag are pushed.
+    Pushed,
+  }
+
+  impl fmt::Display for PublishStage
+  {
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      match self
+      {
+        Self::Planned => write!( f, "planned" ),
+        Self::Committed => write!( f, "committed" ),
+        Self::Published => write!( f, "published" ),
+        Self::Tagged => write!( f, "tagged" ),
+        Self::Pushed => write!( f, "pushed" ),
+      }
+    }
+  }
+
+  /// State of the publication of a single package.
+  #[ derive( Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize ) ]
+  pub struct JournalEntry
+  {
+    /// Name of the package.
+    pub name : String,
+    /// Directory of the package.
+    pub crate_dir : PathBuf,
+    /// Version before the publication.
+    pub old_version : String,
+    /// Version being published.
+    pub new_version : String,
+    /// Stage reached so far.
+    pub stage : PublishStage,
+    /// Hash of the commit with the bumped version, known since the `committed` stage.
+    #[ serde( default, skip_serializing_if = "Option::is_none" ) ]
+    pub commit : Option< String >,
+  }
+
+  impl fmt::Display for JournalEntry
+  {
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      write!( f, "{} ({} -> {}) : {}", self.name, self.old_version, self.new_version, self.stage )
+    }
+  }
+
+  /// Journal of a publication of packages, stored after every step.
+  ///
+  /// If the publication fails, a rerun resumes packages of the journal from stages they reached,
+  /// and commits of packages which never reached the registry can be rolled back.
+  /// The journal is removed when all its packages are pushed.
+  #[ derive( Debug, Clone, serde::Serialize, serde::Deserialize ) ]
+  pub struct PublishJournal
+  {
+    /// Path to the file of the journal, it is not stored in the file itself.
+    #[ serde( skip ) ]
+    file : PathBuf,
+    /// Root of the git repository the commits are made in.
+    pub git_root : PathBuf,
+    /// Packages in the order of publication.
+    pub entries : Vec< JournalEntry >,
+  }
+
+  impl PublishJournal
+  {
+    /// Path to the journal of the workspace.
+    pub fn file( workspace : &Workspace ) -> PathBuf
+    {
+      workspace.target_directory().join( JOURNAL_FILE )
+    }
+
+    /// Creates an empty journal, it is not stored until `store` is called.
+    pub fn new< P : Into< PathBuf >, R : Into< PathBuf > >( file : P, git_root : R ) -> Self
+    {
+      Self { file : file.into(), git_root : git_root.into(), entries : vec![] }
+    }
+
+    /// Loads the journal. Returns `None` if there is no journal, that is the previous publication was finished.
+    pub fn load< P : AsRef< Path > >( file : P ) -> Result< Option< Self > >
+    {
+      let file = file.as_ref();
+      let content = match fs::read_to_string( file )
+      {
+        Ok( content ) => content,
+        Err( e ) if e.kind() == io::ErrorKind::NotFound => return Ok( None ),
+        Err( e ) => return Err( e ).with_context( || format!( "Failed to read {}", file.display() ) ),
+      };
+      let mut journal : Self = serde_json::from_str( &content ).with_context( || format!( "Failed to parse {}", file.display() ) )?;
+      journal.file = file.to_path_buf();
+
+      Ok( Some( journal ) )
+    }
+
+    /// Path to the file of the journal.
+    pub fn path( &self ) -> &Path
+    {
+      &self.file
+    }
+
+    /// Writes the journal.
+    pub fn store( &self ) -> Result< () >
+    {
+      if let Some( dir ) = self.file.parent()
+      {
+        fs::create_dir_all( dir )?;
+      }
+      fs::write( &self.file, serde_json::to_string_pretty( self )? ).with_context( || format!( "Failed to write {}", self.file.display() ) )
+    }
+
+    /// Removes the file of the journal.
+    pub fn remove( &self ) -> Result< () >
+    {
+      match fs::remove_file( &self.file )
+      {
+        Err( e ) if e.kind() != io::ErrorKind::NotFound => Err( e ).with_context( || format!( "Failed to remove {}", self.file.display() ) ),
+        _ => Ok( () ),
+      }
+    }
+
+    /// Entry of the package.
+    pub fn entry( &self, name : &str ) -> Option< &JournalEntry >
+    {
+      self.entries.iter().find( | e | e.name == name )
+    }
+
+    /// Stage of the package, `planned` if the package is not in the journal.
+    pub fn stage( &self, name : &str ) -> PublishStage
+    {
+      self.entry( name ).map_or( PublishStage::Planned, | e | e.stage )
+    }
+
+    /// Packages which are not pushed yet.
+    pub fn unfinished( &self ) -> impl Iterator< Item = &JournalEntry >
+    {
+      self.entries.iter().filter( | e | e.stage < PublishStage::Pushed )
+    }
+
+    /// Adds packages of the plan which are not in the journal yet. Versions of packages which are still `planned` are taken from the plan.
+    pub fn plan_merge( &mut self, plan : &publish::PublishPlan )
+    {
+      for instruction in &plan.plans
+      {
+        let entry = JournalEntry
+        {
+          name : instruction.package_name.to_string(),
+          crate_dir : instruction.bump.crate_dir.clone().absolute_path().inner(),
+          old_version : instruction.bump.old_version.to_string(),
+          new_version : instruction.bump.new_version.to_string(),
+          stage : PublishStage::Planned,
+          commit : None,
+        };
+        match self.entries.iter_mut().find( | e | e.name == entry.name )
+        {
+          Some( existing ) if existing.stage == PublishStage::Planned => *existing = entry,
+          Some( _ ) => {}
+          None => self.entries.push( entry ),
+        }
+      }
+    }
+
+    /// Sets the stage of the package and stores the journal. The hash of the commit is forgotten when the package returns to the `planned` stage.
+    pub fn stage_set( &mut self, name : &str, stage : PublishStage ) -> Result< () >
+    {
+      let entry = self.entry_mut( name )?;
+      entry.stage = stage;
+      if stage == PublishStage::Planned
+      {
+        entry.commit = None;
+      }
+
+      self.store()
+    }
+
+    /// Moves the package to the `committed` stage with the hash of the commit and stores the journal.
+    pub fn commit_set( &mut self, name : &str, commit : String ) -> Result< () >
+    {
+      let entry = self.entry_mut( name )?;
+      entry.stage = PublishStage::Committed;
+      entry.commit = Some( commit );
+
+      self.store()
+    }
+
+    /// Entry of the package, fails if the package is not in the journal.
+    fn entry_mut( &mut self, name : &str ) -> Result< &mut JournalEntry >
+    {
+      self.entries.iter_mut().find( | e | e.name == name ).ok_or_else( || format_err!( "Package `{name}` is not in the publish journal" ) )
+    }
+
+    /// Resets commits of packages which were committed, but never reached the registry, and returns them to the `planned` stage.
+    ///
+    /// The commits must form a block on top of the branch, in any order, because packages published at the same time commit in the order they finish.
+    /// A commit of a published package or any other commit inside of the block stops the rollback, such commits have to be reset manually.
+    /// Nothing is changed in the dry mode, reports contain commands which would be executed.
+    pub fn rollback( &mut self, dry : bool ) -> Result< Vec< process::Report > >
+    {
+      let mut committed : collection::HashMap< &str, &str > = collection::HashMap::new();
+      for entry in self.entries.iter().filter( | e | e.stage == PublishStage::Committed )
+      {
+        let commit = entry.commit.as_deref().ok_or_else( || format_err!( "Commit of `{}` is unknown, reset it manually", entry.name ) )?;
+        committed.insert( commit, &entry.name );
+      }
+
+      // names of packages from the latest commit
+      let mut names = vec![];
+      while names.len() < committed.len()
+      {
+        let revision = format!( "HEAD~{}", names.len() );
+        let head = tool::git::rev_parse( &self.git_root, &revision )?;
+        let Some( name ) = committed.get( head.out.trim() ) else
+        {
+          let rest = committed.values().filter( | n | !names.iter().any( | name | name == *n ) ).join( ", " );
+          return Err( format_err!
+          (
+            "Commits of `{rest}` are not on top of the branch ( `{revision}` is {} ), reset them manually",
+            head.out.trim(),
+          ));
+        };
+        names.push( name.to_string() );
+      }
+
+      let mut reports = vec![];
+      for name in names
+      {
+        reports.push( tool::git::reset( &self.git_root, true, 1, dry )? );
+        if !dry
+        {
+          self.stage_set( &name, PublishStage::Planned )?;
+        }
+      }
+
+      Ok( reports )
+    }
+  }
+}
+
+//
+
+crate::mod_interface!
+{
+  own use JOURNAL_FILE;
+  own use PublishStage;
+  own use JournalEntry;
+  own use PublishJournal;
+}
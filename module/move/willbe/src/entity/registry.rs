@@ -0,0 +1,327 @@
+mod private
+{
+  use crate::*;
+
+  use std::
+  {
+    fmt,
+    fs,
+    thread,
+    time::{ Duration, Instant },
+  };
+  use path::PathBuf;
+  use crates_tools::CrateArchive;
+  use error::typed::Error;
+
+  /// Default host of archives of `crates.io`.
+  const CRATES_IO_DOWNLOAD : &str = "https://static.crates.io/crates";
+
+  /// Sparse index of `crates.io`.
+  const CRATES_IO_INDEX : &str = "https://index.crates.io/";
+
+  /// Interval between requests to the index while waiting for a package.
+  const INDEX_POLL_INTERVAL : Duration = Duration::from_secs( 2 );
+
+  /// Key of the workspace metadata which selects the registry.
+  const REGISTRY_METADATA : &str = "registry";
+
+  /// Source of published versions of packages.
+  ///
+  /// The registry is selected by the `registry` table of the workspace metadata :
+  ///
+  /// ```toml
+  /// [workspace.metadata.registry]
+  /// # a directory with `name-version.crate` files, relative to the workspace root
+  /// path = "target/registry"
+  /// # or a sparse index of an alternative registry
+  /// # index = "sparse+https://registry.example.com/index/"
+  /// ```
+  ///
+  /// `crates.io` is used if the table is absent.
+  #[ derive( Debug, Default, Clone, PartialEq, Eq ) ]
+  pub enum Registry
+  {
+    /// `crates.io` over HTTP.
+    #[ default ]
+    CratesIo,
+    /// Local directory of `.crate` files. A file is looked up as `name-version.crate` or `name/name-version.crate`.
+    Local( PathBuf ),
+    /// Registry with a sparse index. Holds the url of the index without the `sparse+` prefix.
+    Sparse( String ),
+  }
+
+  /// Represents errors related to registries.
+  #[ derive( Debug, Error ) ]
+  pub enum RegistryError
+  {
+    /// The registry has no such version of the package.
+    #[ error( "Package `{0}` of version `{1}` is not found in the registry." ) ]
+    NotFound( String, String ),
+    /// The registry is specified incorrectly.
+    #[ error( "Invalid registry configuration. Details : {0}" ) ]
+    Config( String ),
+    /// Failed to get data from the registry.
+    #[ error( "Failed to get data from the registry. Details : {0}" ) ]
+    Request( String ),
+    /// The published version did not appear in the index in time.
+    #[ error( "Package `{0}` of version `{1}` did not appear in the index in {2:?}." ) ]
+    IndexTimeout( String, String, Duration ),
+    /// Files error.
+    #[ error( "I/O error: {0}" ) ]
+    IO( #[ from ] std::io::Error ),
+  }
+
+  impl fmt::Display for Registry
+  {
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      match self
+      {
+        Self::CratesIo => write!( f, "crates.io" ),
+        Self::Local( path ) => write!( f, "{}", path.display() ),
+        Self::Sparse( index ) => write!( f, "sparse+{index}" ),
+      }
+    }
+  }
+
+  impl serde::Serialize for Registry
+  {
+    fn serialize< S >( &self, serializer : S ) -> Result< S::Ok, S::Error >
+    where
+      S : serde::Serializer,
+    {
+      serializer.collect_str( self )
+    }
+  }
+
+  impl TryFrom< &Workspace > for Registry
+  {
+    type Error = RegistryError;
+
+    fn try_from( workspace : &Workspace ) -> Result< Self, Self::Error >
+    {
+      let Some( registry ) = workspace.metadata.workspace_metadata.get( REGISTRY_METADATA ) else { return Ok( Self::CratesIo ) };
+      let path = registry.get( "path" ).and_then( | p | p.as_str() );
+      let index = registry.get( "index" ).and_then( | i | i.as_str() );
+      match ( path, index )
+      {
+        ( Some( path ), None ) => Ok( Self::Local( workspace.workspace_root().as_ref().join( path ) ) ),
+        ( None, Some( index ) ) => Self::sparse( index ),
+        _ => Err( RegistryError::Config( format!( "expected either `path` or `index` in `workspace.metadata.{REGISTRY_METADATA}`, got : {registry}" ) ) ),
+      }
+    }
+  }
+
+  impl Registry
+  {
+    /// Creates a registry with a sparse index. The `sparse+` prefix of the url is optional.
+    pub fn sparse( index : &str ) -> Result< Self, RegistryError >
+    {
+      let index = index.strip_prefix( "sparse+" ).unwrap_or( index );
+      if !index.starts_with( "http://" ) && !index.starts_with( "https://" )
+      {
+        return Err( RegistryError::Config( format!( "sparse index must be an http(s) url, got : `{index}`" ) ) );
+      }
+      let index = if index.ends_with( '/' ) { index.to_string() } else { format!( "{index}/" ) };
+
+      Ok( Self::Sparse( index ) )
+    }
+
+    /// Downloads bytes of the `.crate` file of a package version.
+    pub fn download( &self, name : &str, version : &str ) -> Result< Vec< u8 >, RegistryError >
+    {
+      let not_found = || RegistryError::NotFound( name.into(), version.into() );
+      match self
+      {
+        Self::CratesIo =>
+        {
+          http_get( &format!( "{CRATES_IO_DOWNLOAD}/{name}/{name}-{version}.crate" ) )?.ok_or_else( not_found )
+        }
+        Self::Local( dir ) =>
+        {
+          let file = format!( "{name}-{version}.crate" );
+          let path = [ dir.join( &file ), dir.join( name ).join( &file ) ]
+          .into_iter()
+          .find( | p | p.is_file() )
+          .ok_or_else( not_found )?;
+
+          Ok( fs::read( path )? )
+        }
+        Self::Sparse( index ) =>
+        {
+          let config = http_get( &format!( "{index}config.json" ) )?
+          .ok_or_else( || RegistryError::Config( format!( "no `config.json` in the index `{index}`" ) ) )?;
+          let config : serde_json::Value = serde_json::from_slice( &config ).map_err( | e | RegistryError::Config( e.to_string() ) )?;
+          let dl = config[ "dl" ].as_str().ok_or_else( || RegistryError::Config( format!( "no `dl` in `{index}config.json`" ) ) )?;
+
+          let url = if [ "{crate}", "{version}", "{prefix}", "{lowerprefix}", "{sha256-checksum}" ].iter().any( | m | dl.contains( m ) )
+          {
+            let mut url = dl
+            .replace( "{crate}", name )
+            .replace( "{version}", version )
+            .replace( "{prefix}", &index_prefix( name ) )
+            .replace( "{lowerprefix}", &index_prefix( &name.to_lowercase() ) );
+            if url.contains( "{sha256-checksum}" )
+            {
+              url = url.replace( "{sha256-checksum}", &sparse_checksum( index, name, version )?.ok_or_else( not_found )? );
+            }
+            url
+          }
+          else
+          {
+            format!( "{}/{name}/{version}/download", dl.trim_end_matches( '/' ) )
+          };
+
+          http_get( &url )?.ok_or_else( not_found )
+        }
+      }
+    }
+
+    /// Downloads and decodes the `.crate` file of a package version.
+    pub fn archive( &self, name : &str, version : &str ) -> Result< CrateArchive, RegistryError >
+    {
+      Ok( CrateArchive::decode( self.download( name, version )? )? )
+    }
+
+    /// Returns `true` if the index of the registry has the package version, so packages depending on it can be published.
+    /// A local directory has no index, the `.crate` file is looked up instead.
+    pub fn indexed( &self, name : &str, version : &str ) -> Result< bool, RegistryError >
+    {
+      match self
+      {
+        Self::CratesIo => Ok( sparse_checksum( CRATES_IO_INDEX, name, version )?.is_some() ),
+        Self::Local( _ ) => match self.download( name, version )
+        {
+          Ok( _ ) => Ok( true ),
+          Err( RegistryError::NotFound( .. ) ) => Ok( false ),
+          Err( e ) => Err( e ),
+        },
+        Self::Sparse( index ) => Ok( sparse_checksum( index, name, version )?.is_some() ),
+      }
+    }
+
+    /// Versions of the package the registry has, yanked versions are skipped. Empty if the registry has no such package.
+    /// For a local directory these are versions of `.crate` files of the package.
+    pub fn versions( &self, name : &str ) -> Result< Vec< semver::Version >, RegistryError >
+    {
+      let versions = match self
+      {
+        Self::CratesIo | Self::Sparse( _ ) =>
+        {
+          let index = if let Self::Sparse( index ) = self { index.as_str() } else { CRATES_IO_INDEX };
+          sparse_entries( index, name )?
+          .into_iter()
+          .filter( | e | !e[ "yanked" ].as_bool().unwrap_or( false ) )
+          .filter_map( | e | e[ "vers" ].as_str().and_then( | v | semver::Version::parse( v ).ok() ) )
+          .collect()
+        }
+        Self::Local( dir ) =>
+        {
+          let prefix = format!( "{name}-" );
+          [ dir.clone(), dir.join( name ) ]
+          .iter()
+          .filter_map( | d | fs::read_dir( d ).ok() )
+          .flatten()
+          .flatten()
+          .filter_map( | entry |
+          {
+            let file = entry.file_name().to_string_lossy().to_string();
+            let version = file.strip_prefix( &prefix )?.strip_suffix( ".crate" )?.to_string();
+            semver::Version::parse( &version ).ok()
+          })
+          .collect()
+        }
+      };
+
+      Ok( versions )
+    }
+
+    /// Registry `cargo publish` uploads packages to : the registry itself for a sparse index, `crates.io` otherwise,
+    /// because nothing is uploaded into a local directory.
+    pub fn upload_registry( &self ) -> Self
+    {
+      match self
+      {
+        Self::Sparse( _ ) => self.clone(),
+        Self::CratesIo | Self::Local( _ ) => Self::CratesIo,
+      }
+    }
+
+    /// Value of the `--index` option of `cargo publish` to upload packages to the registry. `None` for `crates.io`.
+    pub fn upload_index( &self ) -> Option< String >
+    {
+      match self.upload_registry()
+      {
+        Self::Sparse( index ) => Some( format!( "sparse+{index}" ) ),
+        _ => None,
+      }
+    }
+
+    /// Waits until the index of the registry has the package version.
+    /// Returns immediately for a local directory : `cargo publish` does not write into it.
+    pub fn index_wait( &self, name : &str, version : &str, timeout : Duration ) -> Result< (), RegistryError >
+    {
+      if let Self::Local( _ ) = self { return Ok( () ) }
+      let start = Instant::now();
+      while !self.indexed( name, version )?
+      {
+        if start.elapsed() >= timeout
+        {
+          return Err( RegistryError::IndexTimeout( name.into(), version.into(), timeout ) );
+        }
+        thread::sleep( INDEX_POLL_INTERVAL );
+      }
+
+      Ok( () )
+    }
+  }
+
+  fn http_get( url : &str ) -> Result< Option< Vec< u8 > >, RegistryError >
+  {
+    tool::http::download( url ).map_err( | e | RegistryError::Request( format!( "{e:#}" ) ) )
+  }
+
+  /// Directory of a package in an index : `1/a`, `2/ab`, `3/a/abc`, `ab/cd/abcd...`.
+  fn index_prefix( name : &str ) -> String
+  {
+    match name.len()
+    {
+      1 => "1".into(),
+      2 => "2".into(),
+      3 => format!( "3/{}", &name[ .. 1 ] ),
+      _ => format!( "{}/{}", &name[ .. 2 ], &name[ 2 .. 4 ] ),
+    }
+  }
+
+  /// Entries of versions of a package in a sparse index, empty if the index has no such package.
+  fn sparse_entries( index : &str, name : &str ) -> Result< Vec< serde_json::Value >, RegistryError >
+  {
+    let lower = name.to_lowercase();
+    let Some( entries ) = http_get( &format!( "{index}{}/{lower}", index_prefix( &lower ) ) )? else { return Ok( vec![] ) };
+    let entries = String::from_utf8_lossy( &entries )
+    .lines()
+    .filter_map( | l | serde_json::from_str::< serde_json::Value >( l ).ok() )
+    .collect();
+
+    Ok( entries )
+  }
+
+  /// Looks up the checksum of a package version in a sparse index.
+  fn sparse_checksum( index : &str, name : &str, version : &str ) -> Result< Option< String >, RegistryError >
+  {
+    let checksum = sparse_entries( index, name )?
+    .into_iter()
+    .find( | e | e[ "vers" ].as_str() == Some( version ) )
+    .and_then( | e | e[ "cksum" ].as_str().map( String::from ) );
+
+    Ok( checksum )
+  }
+}
+
+//
+
+crate::mod_interface!
+{
+  own use Registry;
+  own use RegistryError;
+}
@@ -8,6 +8,8 @@ mod private
   {
     fmt,
     sync,
+    thread,
+    time::{ Duration, Instant },
   };
   use colored::Colorize as _;
   use process_tools::process::*;
@@ -27,8 +29,56 @@ mod private
     Path( #[ from ] PathError ),
   }
 
+  /// Key of the workspace metadata with defaults of the test command.
+  const TEST_METADATA : &str = "test";
+
+  /// Defaults of the test command from the workspace metadata :
+  ///
+  /// ```toml
+  /// [workspace.metadata.test]
+  /// # seconds, a variant is killed and failed when it runs longer
+  /// timeout = 600
+  /// # count of additional runs of a failed variant
+  /// retries = 1
+  /// ```
+  #[ derive( Debug, Default, Clone, Copy, PartialEq, Eq ) ]
+  pub struct TestDefaults
+  {
+    /// Time limit of a variant.
+    pub timeout : Option< Duration >,
+    /// Count of additional runs of a failed variant.
+    pub retries : Option< u32 >,
+  }
+
+  impl TryFrom< &Workspace > for TestDefaults
+  {
+    type Error = TestError;
+
+    fn try_from( workspace : &Workspace ) -> Result< Self, Self::Error >
+    {
+      let Some( test ) = workspace.metadata.workspace_metadata.get( TEST_METADATA ) else { return Ok( Self::default() ) };
+      let number = | key : &str | -> Result< Option< u64 >, TestError >
+      {
+        match test.get( key )
+        {
+          None => Ok( None ),
+          Some( value ) => value
+          .as_u64()
+          .map( Some )
+          .ok_or_else( || format_err!( "`workspace.metadata.{TEST_METADATA}.{key}` must be a non-negative integer, got : {value}" ).into() ),
+        }
+      };
+      let retries = number( "retries" )?
+      .map( u32::try_from )
+      .transpose()
+      .map_err( | e | TestError::Common( format_err!( "`workspace.metadata.{TEST_METADATA}.retries` is too large : {e}" ) ) )?;
+
+      Ok( Self { timeout : number( "timeout" )?.map( Duration::from_secs ), retries } )
+    }
+  }
+
   /// Represents a variant for testing purposes.
-  #[ derive( Debug, Clone, Eq, PartialEq, Ord, PartialOrd, former::Former ) ]
+  #[ derive( Debug, Clone, Eq, PartialEq, Ord, PartialOrd, former::Former, serde::Serialize ) ]
   pub struct TestVariant
   {
     /// Represents the channel for the test variant.
@@ -39,6 +89,27 @@ mod private
     features : collection::BTreeSet<String>,
   }
 
+  impl TestVariant
+  {
+    /// Channel of the variant.
+    pub fn channel( &self ) -> channel::Channel
+    {
+      self.channel
+    }
+
+    /// Optimization of the variant.
+    pub fn optimization( &self ) -> optimization::Optimization
+    {
+      self.optimization
+    }
+
+    /// Features enabled for the variant.
+    pub fn features( &self ) -> &collection::BTreeSet< String >
+    {
+      &self.features
+    }
+  }
+
   impl fmt::Display for TestVariant
   {
     fn fmt( &self, f : &mut fmt::Formatter< '_ >) -> fmt::Result
@@ -82,6 +153,7 @@ mod private
     /// `with_all_features` - If it's true - add to powerset one subset which contains all features.
     /// `with_none_features` - If it's true - add to powerset one empty subset.
     /// `variants_cap` - Maximum of subset in powerset
+    /// `features_strategy` - How combinations of features are selected. Combinations which enable the same features are tested once.
     pub fn try_from< 'a >
     (
       packages : impl core::iter::Iterator< Item = WorkspacePackageRef< 'a > >,
@@ -94,6 +166,7 @@ mod private
       with_all_features : bool,
       with_none_features : bool,
       variants_cap : u32,
+      features_strategy : features::FeaturesStrategy,
     )
     -> Result< Self, TestError >
     {
@@ -110,7 +183,7 @@ mod private
             include_features.as_slice(),
             exclude_features.as_slice(),
             optimizations,
-            enabled_features.as_slice(), with_all_features, with_none_features, variants_cap
+            enabled_features.as_slice(), with_all_features, with_none_features, variants_cap, features_strategy
           )?
         );
       }
@@ -127,6 +200,7 @@ mod private
   #[ derive( Debug ) ]
   pub struct TestPackagePlan
   {
+    package_name : PackageName,
     enabled_features : collection::BTreeSet< String >,
     // package : PathBuf,
     crate_dir : CrateDir,
@@ -202,6 +276,7 @@ mod private
     /// `with_all_features` - If it's true - add to powerset one subset which contains all features.
     /// `with_none_features` - If it's true - add to powerset one empty subset.
     /// `variants_cap` - Maximum of subset in powerset
+    /// `features_strategy` - How combinations of features are selected
     fn try_from< 'a >
     (
       package : WorkspacePackageRef< 'a >,
@@ -214,23 +289,38 @@ mod private
       with_all_features : bool,
       with_none_features : bool,
       variants_cap : u32,
+      features_strategy : features::FeaturesStrategy,
     )
     -> Result< Self, TestError >
     {
       // let crate_dir = package.manifest_file().parent().unwrap().as_std_path().to_path_buf();
       let crate_dir = package.crate_dir()?;
       let mut test_variants = collection::BTreeSet::new();
-      let features_powerset = features::features_powerset
-      (
-        package,
-        power as usize,
-        exclude_features,
-        include_features,
-        enabled_features,
-        with_all_features,
-        with_none_features,
-        variants_cap,
-      )?;
+      let features_powerset = match features_strategy
+      {
+        features::FeaturesStrategy::Powerset => features::features_powerset
+        (
+          package,
+          power as usize,
+          exclude_features,
+          include_features,
+          enabled_features,
+          with_all_features,
+          with_none_features,
+          variants_cap,
+        )?,
+        features::FeaturesStrategy::Pairwise => features::features_pairwise
+        (
+          package,
+          exclude_features,
+          include_features,
+          enabled_features,
+          with_all_features,
+          with_none_features,
+          variants_cap,
+        )?,
+      };
+      let features_powerset = features::dedup_by_closure( package, features_powerset );
       for optimization in optimizations
       {
         for channel in channels
@@ -253,6 +343,7 @@ mod private
       (
         Self
         {
+          package_name : package.name().to_string().into(),
           enabled_features: enabled_features.iter().cloned().collect(),
           crate_dir,
           test_variants,
@@ -299,6 +390,10 @@ mod private
     plan : &'a TestPackagePlan,
     dry : bool,
     with_progress : bool,
+    cache : Option< &'a test_cache::TestCache >,
+    timeout : Option< Duration >,
+    retries : u32,
+    coverage : Option< path::PathBuf >,
     #[ cfg( feature = "progress_bar" ) ]
     progress_bar : progress_bar::ProgressBar< 'a >
   }
@@ -338,6 +433,10 @@ mod private
     /// RUST_BACKTRACE
     #[ former( default = true ) ]
     backtrace : bool,
+    /// Time limit of the run. The command and processes spawned by it are killed when it is exceeded.
+    timeout : Option< Duration >,
+    /// Directory for raw profiles of an instrumented run. The run is not instrumented if it is not specified.
+    coverage : Option< path::PathBuf >,
   }
 
   impl SingleTestOptions
@@ -359,6 +458,22 @@ mod private
       .chain( self.temp_directory_path.clone().map( | p | vec![ "--target-dir".to_string(), p.to_string_lossy().into() ] ).into_iter().flatten() )
       .collect()
     }
+
+    fn envs( &self ) -> collection::HashMap< String, String >
+    {
+      let mut envs = collection::HashMap::new();
+      if self.backtrace
+      {
+        envs.insert( "RUST_BACKTRACE".to_string(), "full".to_string() );
+      }
+      if let Some( profraw_dir ) = &self.coverage
+      {
+        let flags = std::env::var( "RUSTFLAGS" ).map_or_else( | _ | coverage::INSTRUMENT_FLAGS.to_string(), | f | format!( "{f} {}", coverage::INSTRUMENT_FLAGS ) );
+        envs.insert( "RUSTFLAGS".to_string(), flags );
+        envs.insert( "LLVM_PROFILE_FILE".to_string(), coverage::profile_file( profraw_dir ).to_string_lossy().into() );
+      }
+      envs
+    }
   }
 
   /// Executes a test command with the given arguments.
@@ -382,11 +497,12 @@ mod private
 
     if options.dry
     {
+      let instrument = if options.coverage.is_some() { format!( "RUSTFLAGS=\"{}\" ", coverage::INSTRUMENT_FLAGS ) } else { String::new() };
       Ok
       (
         Report
         {
-          command : format!( "{program} {}", args.join( " " ) ),
+          command : format!( "{instrument}{program} {}", args.join( " " ) ),
           out : String::new(),
           err : String::new(),
           current_path: path.as_ref().to_path_buf(),
@@ -396,7 +512,11 @@ mod private
     }
     else
     {
-      let envs = if options.backtrace { [( "RUST_BACKTRACE".to_string(), "full".to_string() )].into_iter().collect() } else { collection::HashMap::new() };
+      let envs = options.envs();
+      if let Some( timeout ) = options.timeout
+      {
+        return run_with_timeout( program, &args, path.as_ref(), envs, timeout );
+      }
       Run::former()
       .bin_path( program )
       .args( args.into_iter().map( std::ffi::OsString::from ).collect::< Vec< _ > >() )
@@ -407,6 +527,133 @@ mod private
     }
   }
 
+  /// Test binaries built by the run of the options, an instrumented run needs them to map its profiles to sources.
+  /// They are already built, so nothing is compiled.
+  fn test_executables( path : &path::Path, options : &SingleTestOptions ) -> error::untyped::Result< Vec< path::PathBuf > >
+  {
+    let args = options.as_rustup_args().into_iter().chain( [ "--no-run".to_string(), "--message-format=json".to_string() ] );
+    let report = Run::former()
+    .bin_path( "rustup" )
+    .args( args.map( std::ffi::OsString::from ).collect::< Vec< _ > >() )
+    .current_path( path.to_path_buf() )
+    .env_variable( options.envs() )
+    .run()
+    .map_err( | report | format_err!( report.to_string() ) )?;
+
+    let executables = report
+    .out
+    .lines()
+    .filter_map( | line | serde_json::from_str::< serde_json::Value >( line ).ok() )
+    .filter( | message | message[ "reason" ] == "compiler-artifact" )
+    .filter_map( | message | message[ "executable" ].as_str().map( path::PathBuf::from ) )
+    .collect();
+
+    Ok( executables )
+  }
+
+  /// Runs the command like `Run` with joined streams, but kills it with all processes spawned by it when the time is out.
+  fn run_with_timeout
+  (
+    program : &str,
+    args : &[ String ],
+    path : &path::Path,
+    envs : collection::HashMap< String, String >,
+    timeout : Duration,
+  )
+  -> Result< Report, Report >
+  {
+    let mut report = Report
+    {
+      command : format!( "{program} {}", args.join( " " ) ),
+      current_path : path.to_path_buf(),
+      .. Report::default()
+    };
+
+    let expression = envs
+    .into_iter()
+    .fold( duct::cmd( program, args ).dir( path ), | e, ( k, v ) | e.env( k, v ) )
+    .stderr_to_stdout()
+    .stdout_capture()
+    .unchecked();
+    // own process group, so the whole tree of `rustup`, `cargo` and test binaries can be killed at once
+    #[ cfg( unix ) ]
+    let expression = expression.before_spawn( | cmd | { std::os::unix::process::CommandExt::process_group( cmd, 0 ); Ok( () ) } );
+
+    let handle = match expression.start()
+    {
+      Ok( handle ) => handle,
+      Err( e ) =>
+      {
+        report.error = Err( e.into() );
+        return Err( report );
+      }
+    };
+    let deadline = Instant::now() + timeout;
+    let timed_out = loop
+    {
+      match handle.try_wait()
+      {
+        Ok( Some( _ ) ) => break false,
+        Ok( None ) if Instant::now() >= deadline => break true,
+        Ok( None ) => thread::sleep( Duration::from_millis( 100 ) ),
+        Err( e ) =>
+        {
+          report.error = Err( e.into() );
+          return Err( report );
+        }
+      }
+    };
+    if timed_out
+    {
+      for pid in handle.pids()
+      {
+        kill_tree( pid );
+      }
+      // the tree is killed already, it only reaps the child
+      _ = handle.kill();
+    }
+
+    let output = match handle.into_output()
+    {
+      Ok( output ) => output,
+      Err( e ) =>
+      {
+        report.error = Err( e.into() );
+        return Err( report );
+      }
+    };
+    report.out = String::from_utf8_lossy( &output.stdout ).into_owned();
+
+    if timed_out
+    {
+      report.error = Err( format_err!( "Process was killed after the timeout of {} s", timeout.as_secs() ) );
+      Err( report )
+    }
+    else if output.status.success()
+    {
+      Ok( report )
+    }
+    else
+    {
+      report.error = Err( format_err!( "Process was finished with error code : {}", output.status ) );
+      Err( report )
+    }
+  }
+
+  /// Kills a process and processes spawned by it.
+  fn kill_tree( pid : u32 )
+  {
+    #[ cfg( unix ) ]
+    let status = std::process::Command::new( "kill" ).args( [ "-KILL", "--", &format!( "-{pid}" ) ] ).status();
+    #[ cfg( windows ) ]
+    let status = std::process::Command::new( "taskkill" ).args( [ "/T", "/F", "/PID", &pid.to_string() ] ).status();
+    // there is no way to kill a tree of processes, the child itself is killed by the caller
+    #[ cfg( not( any( unix, windows ) ) ) ]
+    let status = pid;
+    // the process could exit by itself in the meantime
+    _ = status;
+  }
+
   /// `TestOptions` is a structure used to store the arguments for tests.
   #[ derive( former::Former ) ]
   pub struct TestOptions
@@ -425,6 +672,18 @@ mod private
 
     /// Progress bar flag.
     pub with_progress : bool,
+
+    /// Cache of passed variants. Variants found in the cache are not run.
+    pub cache : Option< test_cache::TestCache >,
+
+    /// Time limit of a variant. The variant is killed and failed when it runs longer.
+    pub timeout : Option< Duration >,
+
+    /// Count of additional runs of a failed variant. A variant which passes on a retry is reported as flaky.
+    pub retries : u32,
+
+    /// Directory to write coverage of packages to. Coverage is not collected if it is not specified.
+    pub coverage : Option< path::PathBuf >,
   }
 
   // aaa : for Petro : remove after Former fix
@@ -439,6 +698,7 @@ mod private
       .field( "concurrent", &self.concurrent)
       .field( "temp_path", &self.temp_path)
       .field( "plan", &self.plan)
+      .field( "coverage", &self.coverage)
       .finish()
     }
   }
@@ -454,6 +714,10 @@ mod private
 
 
   /// Represents a report of test results.
+  ///
+  /// Serialized as `{ dry, package_name, tests : [ variant ], enabled_features, coverage? }`, where `variant` is
+  /// `{ channel, optimization, features, status, failed_attempts?, command, current_path, out, err, error? }` and `status` is
+  /// one of `success`, `cached`, `flaky` or `failure`. `failed_attempts` are reports of failed runs of a flaky variant. `coverage` is `{ lcov, lines_found, lines_hit }` or `{ error }`.
   #[ derive( Debug, Default, Clone ) ]
   pub struct TestReport
   {
@@ -475,8 +739,14 @@ mod private
     ///   feature names and the values are `Report` structs representing the test results for
     ///   the specific feature and channel.
     pub tests : collection::BTreeMap< TestVariant, Result< Report, Report > >,
+    /// Variants which were not run, because they passed before with the same sources.
+    pub cached : collection::BTreeSet< TestVariant >,
+    /// Variants which failed, but passed on a retry, with reports of the failed runs.
+    pub flaky : collection::BTreeMap< TestVariant, Vec< Report > >,
     /// Enabled features
     pub enabled_features : collection::BTreeSet<String>,
+    /// Coverage of the package or the reason it was not collected. `None` if coverage was not requested.
+    pub coverage : Option< Result< coverage::Coverage, String > >,
   }
 
   impl fmt::Display for TestReport
@@ -529,6 +799,22 @@ mod private
         let mut row = Row::new();
         let result_text = match result
         {
+          Ok( _ ) if self.cached.contains( variant ) =>
+          {
+            success += 1;
+            "✅ cached"
+          },
+          Ok( _ ) if self.flaky.contains_key( variant ) =>
+          {
+            success += 1;
+            for report in &self.flaky[ variant ]
+            {
+              let mut out = report.out.replace( "\n", "\n      " );
+              out.push_str( "\n" );
+              write!( f, " ⚠️  > {}\n\n{out}", report.command )?;
+            }
+            "⚠️ flaky"
+          },
           Ok( _ ) =>
           {
             success += 1;
@@ -557,12 +843,79 @@ mod private
       // aaa : replace with method
       writeln!( f, "{}", table )?;
       writeln!( f, "  {}", generate_summary_message( failed, success ) )?;
+      match &self.coverage
+      {
+        Some( Ok( coverage ) ) => writeln!( f, "  Coverage : {coverage}\n  {}", coverage.lcov.display() )?,
+        Some( Err( e ) ) => writeln!( f, "  ❌  Coverage was not collected : {e}" )?,
+        None => {}
+      }
 
       Ok( () )
     }
   }
 
 
+  /// Results of variants are serialized as a sequence, because variants can not be keys of a map in all formats.
+  impl serde::Serialize for TestReport
+  {
+    fn serialize< S >( &self, serializer : S ) -> Result< S::Ok, S::Error >
+    where
+      S : serde::Serializer,
+    {
+      #[ derive( serde::Serialize ) ]
+      struct ReportView< 'a >
+      {
+        dry : bool,
+        package_name : &'a PackageName,
+        tests : Vec< VariantReport< 'a > >,
+        enabled_features : &'a collection::BTreeSet< String >,
+        #[ serde( skip_serializing_if = "Option::is_none" ) ]
+        coverage : Option< CoverageView< 'a > >,
+      }
+
+      #[ derive( serde::Serialize ) ]
+      #[ serde( untagged ) ]
+      enum CoverageView< 'a >
+      {
+        Collected( &'a coverage::Coverage ),
+        Failed { error : &'a str },
+      }
+
+      #[ derive( serde::Serialize ) ]
+      struct VariantReport< 'a >
+      {
+        #[ serde( flatten ) ]
+        variant : &'a TestVariant,
+        status : &'a str,
+        #[ serde( skip_serializing_if = "<[ Report ]>::is_empty", serialize_with = "output::process_report_vec_serialize" ) ]
+        failed_attempts : &'a [ Report ],
+        #[ serde( flatten, serialize_with = "output::process_report_serialize" ) ]
+        report : &'a Report,
+      }
+
+      ReportView
+      {
+        dry : self.dry,
+        package_name : &self.package_name,
+        tests : self.tests.iter().map( |( variant, result )| match result
+        {
+          Ok( report ) if self.cached.contains( variant ) => VariantReport { variant, status : "cached", failed_attempts : &[], report },
+          Ok( report ) if self.flaky.contains_key( variant ) => VariantReport { variant, status : "flaky", failed_attempts : &self.flaky[ variant ], report },
+          Ok( report ) => VariantReport { variant, status : "success", failed_attempts : &[], report },
+          Err( report ) => VariantReport { variant, status : "failure", failed_attempts : &[], report },
+        })
+        .collect(),
+        enabled_features : &self.enabled_features,
+        coverage : self.coverage.as_ref().map( | c | match c
+        {
+          Ok( coverage ) => CoverageView::Collected( coverage ),
+          Err( error ) => CoverageView::Failed { error },
+        }),
+      }
+      .serialize( serializer )
+    }
+  }
+
   fn generate_summary_message( failed : i32, success : i32 ) -> String
   {
     if success == failed + success
@@ -576,7 +929,9 @@ mod private
   }
 
   /// Represents a vector of reposts
-  #[ derive( Debug, Default, Clone ) ]
+  ///
+  /// Serialized as `{ dry, affected?, artifacts?, success_reports : [ TestReport ], failure_reports : [ TestReport ] }`.
+  #[ derive( Debug, Default, Clone, serde::Serialize ) ]
   pub struct TestsReport
   {
     /// A boolean flag indicating whether or not the code is being run in dry mode.
@@ -590,6 +945,12 @@ mod private
     /// where it is important to verify the correctness of the actions being performed before
     /// actually executing them.
     pub dry : bool,
+    /// Packages selected by changes and the reasons they were selected. `None` if all packages were tested.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub affected : Option< affected::AffectedPackages >,
+    /// Files with the JUnit XML and logs of variants. `None` if they were not requested.
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    pub artifacts : Option< junit::ArtifactsReport >,
     /// Vector of succses reports.
     pub success_reports : Vec< TestReport >,
     /// Vector of failure reports.
@@ -607,6 +968,10 @@ mod private
       //   // aaa : coment in because its redundant, this behavior already implemented
       // return Ok( () )
       // }
+      if let Some( affected ) = &self.affected
+      {
+        writeln!( f, "{affected}" )?;
+      }
       if self.success_reports.is_empty() && self.failure_reports.is_empty()
       {
         writeln!( f, "The tests have not been run."  )?;
@@ -628,8 +993,45 @@ mod private
           writeln!( f, "{}", report )?;
         }
       }
+      let covered : Vec< _ > = self.success_reports.iter().chain( &self.failure_reports )
+      .filter_map( | r | r.coverage.as_ref().map( | c | ( &r.package_name, c ) ) )
+      .sorted_by( | a, b | a.0.cmp( b.0 ) )
+      .collect();
+      if !covered.is_empty()
+      {
+        let mut table = Table::default();
+        let mut header_row = Row::new();
+        header_row.add_cell( "Module" );
+        header_row.add_cell( "Lines" );
+        header_row.add_cell( "Coverage" );
+        table.set_header( header_row );
+        for ( package_name, coverage ) in covered
+        {
+          let mut row = Row::new();
+          row.add_cell( package_name );
+          match coverage
+          {
+            Ok( coverage ) =>
+            {
+              row.add_cell( &format!( "{} / {}", coverage.lines_hit, coverage.lines_found ) );
+              row.add_cell( &format!( "{:.1} %", coverage.percent() ) );
+            }
+            Err( _ ) =>
+            {
+              row.add_cell( "" );
+              row.add_cell( "❌" );
+            }
+          }
+          table.add_row( row );
+        }
+        writeln!( f, "Coverage :\n{table}" )?;
+      }
       writeln!( f, "Global report" )?;
       writeln!( f, "  {}", generate_summary_message( self.failure_reports.len() as i32, self.success_reports.len() as i32 ) )?;
+      if let Some( artifacts ) = &self.artifacts
+      {
+        write!( f, "{artifacts}" )?;
+      }
 
       Ok( () )
     }
@@ -643,19 +1045,58 @@ mod private
   {
     let mut report = TestReport::default();
     report.dry = options.dry;
+    report.package_name = options.plan.package_name.clone();
     report.enabled_features = options.plan.enabled_features.clone();
     let report = sync::Arc::new( sync::Mutex::new( report ) );
     let crate_dir = options.plan.crate_dir.clone();
+    // profiles written by different versions of LLVM can not be merged, so only variants of one channel are instrumented
+    let coverage_channel = options.coverage.as_ref().and( options.plan.test_variants.iter().map( | v | v.channel ).min() );
+    let profraw_dir = options.coverage.as_ref().map( | dir | dir.join( options.plan.package_name.as_str() ) );
+    if let ( false, Some( dir ) ) = ( options.dry, &profraw_dir )
+    {
+      // profiles of previous runs belong to other sources
+      _ = std::fs::remove_dir_all( dir );
+    }
+    let objects = sync::Mutex::new( vec![] );
+    // test binaries of instrumented runs are needed until their profiles are merged
+    let kept_dirs = sync::Mutex::new( vec![] );
 
     rayon::scope
     (
       | s |
       {
-        for variant in &options.plan.test_variants
+        for ( index, variant ) in options.plan.test_variants.iter().enumerate()
         {
           let TestVariant{ channel, optimization, features } = variant;
           let r = report.clone();
+          let cache_key = options.cache.and_then( | cache | cache.key( &crate_dir, variant ) );
+          let instrumented = Some( *channel ) == coverage_channel;
+          if let ( false, false, Some( cache ), Some( key ) ) = ( instrumented, options.dry, options.cache, &cache_key )
+          {
+            if cache.contains( key )
+            {
+              let cached = Report
+              {
+                command : String::new(),
+                current_path : crate_dir.clone().absolute_path().inner(),
+                out : "cached pass".into(),
+                err : String::new(),
+                error : Ok( () ),
+              };
+              let mut r = r.lock().unwrap();
+              r.tests.insert( variant.clone(), Ok( cached ) );
+              r.cached.insert( variant.clone() );
+              #[ cfg( feature = "progress_bar" ) ]
+              if options.with_progress
+              {
+                options.progress_bar.progress_bar.inc( 1 );
+              }
+              continue;
+            }
+          }
           let crate_dir = crate_dir.clone();
+          let profraw_dir = profraw_dir.as_ref().filter( | _ | instrumented ).map( | dir | dir.join( index.to_string() ) );
+          let ( objects, kept_dirs ) = ( &objects, &kept_dirs );
           s.spawn
           (
             move | _ |
@@ -666,6 +1107,14 @@ mod private
               .with_default_features( false )
               .enable_features( features.clone() )
               .dry( options.dry );
+              if let Some( timeout ) = options.timeout
+              {
+                args_t = args_t.timeout( timeout );
+              }
+              if let Some( dir ) = profraw_dir
+              {
+                args_t = args_t.coverage( dir );
+              }
 
               if let Some( p ) = options.temp_path.clone()
               {
@@ -685,7 +1134,30 @@ mod private
               }
               let args = args_t.form();
               let temp_dir = args.temp_directory_path.clone();
-              let cmd_rep = _run( crate_dir, args );
+              // failed runs are kept, they are the evidence that the variant is flaky
+              let mut failed = vec![];
+              let cmd_rep = loop
+              {
+                match _run( &crate_dir, args.clone() )
+                {
+                  Err( report ) if !options.dry && ( failed.len() as u32 ) < options.retries => failed.push( report ),
+                  result => break result,
+                }
+              };
+              if cmd_rep.is_ok() && !failed.is_empty()
+              {
+                r.lock().unwrap().flaky.insert( variant.clone(), failed );
+              }
+              if let ( false, Ok( _ ), Some( cache ), Some( key ) ) = ( options.dry, &cmd_rep, options.cache, &cache_key )
+              {
+                // a failure to store only means the variant will be run again next time
+                _ = cache.insert( key, variant );
+              }
+              if let ( false, Some( _ ) ) = ( options.dry, &args.coverage )
+              {
+                // a variant without binaries does not add to the coverage, the collection fails if there is none
+                objects.lock().unwrap().extend( test_executables( crate_dir.as_ref(), &args ).unwrap_or_default() );
+              }
               r.lock().unwrap().tests.insert( variant.clone(), cmd_rep );
               #[ cfg( feature = "progress_bar" ) ]
               if options.with_progress
@@ -694,7 +1166,14 @@ mod private
               }
               if let Some( path ) = temp_dir
               {
-                std::fs::remove_dir_all( path ).unwrap();
+                if args.coverage.is_some()
+                {
+                  kept_dirs.lock().unwrap().push( path );
+                }
+                else
+                {
+                  std::fs::remove_dir_all( path ).unwrap();
+                }
               }
             }
           );
@@ -703,11 +1182,30 @@ mod private
     );
 
     // unpack. all tasks must be completed until now
-    let report = sync::Mutex::into_inner( sync::Arc::into_inner( report ).unwrap() ).unwrap();
+    let mut report = sync::Mutex::into_inner( sync::Arc::into_inner( report ).unwrap() ).unwrap();
+    if let ( false, Some( coverage_dir ), Some( profraw_dir ), Some( channel ) ) = ( options.dry, &options.coverage, profraw_dir, coverage_channel )
+    {
+      let collected = coverage::collect
+      (
+        coverage::CoverageOptions::former()
+        .channel( channel )
+        .crate_dir( crate_dir.clone() )
+        .profraw_dir( profraw_dir )
+        .objects( objects.into_inner().unwrap() )
+        .lcov( coverage::lcov_path( coverage_dir, options.plan.package_name.as_str() ) )
+        .form()
+      );
+      report.coverage = Some( collected.map_err( | e | format!( "{e:#}" ) ) );
+    }
+    for path in kept_dirs.into_inner().unwrap()
+    {
+      std::fs::remove_dir_all( path ).unwrap();
+    }
     let at_least_one_failed = report
     .tests
     .iter()
-    .any( | ( _, result ) | result.is_err() );
+    .any( | ( _, result ) | result.is_err() )
+    || report.coverage.as_ref().is_some_and( | c | c.is_err() );
     if at_least_one_failed { Err( ( report, format_err!( "Some tests was failed" ).into() ) ) } else { Ok( report ) }
   }
 
@@ -739,7 +1237,23 @@ mod private
               .option_temp( args.temp_path.clone() )
               .plan( plan )
               .dry( args.dry )
-              .with_progress( args.with_progress );
+              .with_progress( args.with_progress )
+              .retries( args.retries );
+              let test_package_options = match &args.coverage
+              {
+                Some( coverage ) => test_package_options.coverage( coverage.clone() ),
+                None => test_package_options,
+              };
+              let test_package_options = match args.timeout
+              {
+                Some( timeout ) => test_package_options.timeout( timeout ),
+                None => test_package_options,
+              };
+              let test_package_options = match &args.cache
+              {
+                Some( cache ) => test_package_options.cache( cache ),
+                None => test_package_options,
+              };
 
               #[ cfg( feature = "progress_bar" ) ]
               let test_package_options =
@@ -778,6 +1292,7 @@ mod private
 crate::mod_interface!
 {
 
+  own use TestDefaults;
   own use SingleTestOptions;
   own use TestVariant;
   own use _run;
@@ -0,0 +1,231 @@
+mod private
+{
+  use crate::*;
+
+  use std::
+  {
+    fmt,
+    fs,
+    io,
+    time::{ Duration, SystemTime },
+  };
+  use path::{ Path, PathBuf };
+  use collection::{ BTreeSet, HashMap, HashSet };
+  use sha1::{ Digest, Sha1 };
+  use test::TestVariant;
+  use channel::Channel;
+
+  /// Directories which are not a part of sources of a package.
+  const IGNORED_DIRS : [ &str; 2 ] = [ "target", ".git" ];
+
+  /// Cache of passed test variants.
+  ///
+  /// An entry is keyed by the fingerprint of a package and a test variant. The fingerprint covers
+  /// the content of the package, of its local dependencies ( transitively ) and the workspace manifest and lock file,
+  /// so any change of sources invalidates entries of the package and of its dependents.
+  /// The key also covers the version of the compiler of the channel of the variant, so an update of the toolchain invalidates all entries.
+  /// Only passed variants are stored.
+  #[ derive( Debug, Clone ) ]
+  pub struct TestCache
+  {
+    dir : PathBuf,
+    fingerprints : HashMap< CrateDir, String >,
+    toolchains : HashMap< Channel, String >,
+  }
+
+  impl TestCache
+  {
+    /// Directory of the cache of the workspace : `target/willbe/test_cache`.
+    pub fn dir( workspace : &Workspace ) -> PathBuf
+    {
+      workspace.target_directory().join( "willbe" ).join( "test_cache" )
+    }
+
+    /// Creates the cache for packages of the workspace and computes fingerprints of the packages and versions of compilers of the channels.
+    // qqq : typed error
+    pub fn new< 'a >
+    (
+      workspace : &Workspace,
+      packages : impl Iterator< Item = WorkspacePackageRef< 'a > >,
+      channels : &HashSet< Channel >,
+    )
+    -> error::untyped::Result< Self >
+    {
+      let root = workspace.workspace_root();
+      let toolchains = channels
+      .iter()
+      .map( | &c | Ok( ( c, channel::toolchain_version( c, &root )? ) ) )
+      .collect::< error::untyped::Result< _ > >()?;
+      let mut common = Sha1::new();
+      for file in [ "Cargo.toml", "Cargo.lock" ]
+      {
+        let path = root.as_ref().join( file );
+        if path.is_file()
+        {
+          common.update( file );
+          common.update( fs::read( path )? );
+        }
+      }
+      let common = common.finalize();
+
+      let local : HashMap< _, _ > = workspace
+      .packages()
+      .filter_map( | p | Some( ( p.crate_dir().ok()?, p ) ) )
+      .collect();
+      let mut dir_hashes = HashMap::new();
+      let mut fingerprints = HashMap::new();
+      for package in packages
+      {
+        let crate_dir = package.crate_dir()?;
+        let mut hasher = Sha1::new();
+        hasher.update( common );
+        for dir in dependencies_dirs( &local, &crate_dir )
+        {
+          if !dir_hashes.contains_key( &dir )
+          {
+            dir_hashes.insert( dir.clone(), dir_hash( dir.as_ref() )? );
+          }
+          hasher.update( &dir_hashes[ &dir ] );
+        }
+        fingerprints.insert( crate_dir, hex( &hasher.finalize() ) );
+      }
+
+      Ok( Self { dir : Self::dir( workspace ), fingerprints, toolchains } )
+    }
+
+    /// Key of the entry of a test variant of a package. `None` if the package or the channel of the variant is unknown to the cache.
+    pub fn key( &self, crate_dir : &CrateDir, variant : &TestVariant ) -> Option< String >
+    {
+      let fingerprint = self.fingerprints.get( crate_dir )?;
+      let toolchain = self.toolchains.get( &variant.channel() )?;
+      let mut hasher = Sha1::new();
+      hasher.update( fingerprint );
+      hasher.update( toolchain );
+      hasher.update( variant.to_string() );
+
+      Some( hex( &hasher.finalize() ) )
+    }
+
+    /// Returns `true` if the variant has passed with the same sources. Marks the entry as recently used.
+    pub fn contains( &self, key : &str ) -> bool
+    {
+      let path = self.dir.join( key );
+      // rewriting updates the modification time which is used by pruning
+      path.is_file() && fs::read( &path ).and_then( | content | fs::write( &path, content ) ).is_ok()
+    }
+
+    /// Stores the passed variant.
+    pub fn insert( &self, key : &str, variant : &TestVariant ) -> io::Result< () >
+    {
+      fs::create_dir_all( &self.dir )?;
+      fs::write( self.dir.join( key ), variant.to_string() )
+    }
+  }
+
+  /// Directories of a package and of its local dependencies, including development ones.
+  fn dependencies_dirs( packages : &HashMap< CrateDir, WorkspacePackageRef< '_ > >, crate_dir : &CrateDir ) -> BTreeSet< CrateDir >
+  {
+    let mut result = BTreeSet::new();
+    let mut queue = vec![ crate_dir.clone() ];
+    while let Some( dir ) = queue.pop()
+    {
+      if !result.insert( dir.clone() ) { continue }
+      // dependencies outside of the workspace are hashed, but their dependencies are not followed
+      if let Some( package ) = packages.get( &dir )
+      {
+        queue.extend( package.dependencies().filter_map( | d | d.crate_dir() ) );
+      }
+    }
+
+    result
+  }
+
+  /// Hash of paths and content of files of a directory, except of build artifacts and nested packages.
+  fn dir_hash( dir : &Path ) -> io::Result< [ u8; 20 ] >
+  {
+    let mut files = vec![];
+    let walker = walkdir::WalkDir::new( dir ).into_iter().filter_entry( | e |
+    {
+      let name = e.file_name().to_string_lossy();
+      let nested_package = e.depth() > 0 && e.file_type().is_dir() && e.path().join( "Cargo.toml" ).is_file();
+      e.depth() == 0 || !( IGNORED_DIRS.contains( &name.as_ref() ) || nested_package )
+    });
+    for entry in walker
+    {
+      let entry = entry.map_err( io::Error::from )?;
+      if entry.file_type().is_file()
+      {
+        files.push( entry.into_path() );
+      }
+    }
+    files.sort();
+
+    let mut hasher = Sha1::new();
+    for file in files
+    {
+      hasher.update( file.strip_prefix( dir ).unwrap_or( &file ).to_string_lossy().as_bytes() );
+      hasher.update( fs::read( &file )? );
+    }
+
+    Ok( hasher.finalize().into() )
+  }
+
+  fn hex( bytes : &[ u8 ] ) -> String
+  {
+    bytes.iter().map( | b | format!( "{b:02x}" ) ).collect()
+  }
+
+  /// Report about pruning of the cache.
+  #[ derive( Debug, Default, Clone, serde::Serialize ) ]
+  pub struct TestCachePruneReport
+  {
+    /// Directory of the cache.
+    pub dir : PathBuf,
+    /// Count of removed entries.
+    pub removed : usize,
+    /// Count of kept entries.
+    pub kept : usize,
+  }
+
+  impl fmt::Display for TestCachePruneReport
+  {
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      writeln!( f, "Test cache `{}` : removed {} entries, kept {} entries.", self.dir.display(), self.removed, self.kept )
+    }
+  }
+
+  /// Removes entries of the cache which were not used for the given time. All entries are removed if `unused_for` is zero.
+  // qqq : typed error
+  pub fn prune( dir : &Path, unused_for : Duration ) -> error::untyped::Result< TestCachePruneReport >
+  {
+    let mut report = TestCachePruneReport { dir : dir.to_path_buf(), ..Default::default() };
+    if !dir.is_dir() { return Ok( report ) }
+    let now = SystemTime::now();
+    for entry in fs::read_dir( dir )?
+    {
+      let entry = entry?;
+      let age = now.duration_since( entry.metadata()?.modified()? ).unwrap_or_default();
+      if age >= unused_for
+      {
+        fs::remove_file( entry.path() )?;
+        report.removed += 1;
+      }
+      else
+      {
+        report.kept += 1;
+      }
+    }
+
+    Ok( report )
+  }
+}
+
+//
+
+crate::mod_interface!
+{
+  own use TestCache;
+  own use TestCachePruneReport;
+  own use prune;
+}
@@ -51,6 +51,14 @@ mod private
     }
   }
 
+  impl From< SemVersion > for Version
+  {
+    fn from( value : SemVersion ) -> Self
+    {
+      Self( value )
+    }
+  }
+
   impl fmt::Display for Version
   {
     fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
@@ -59,6 +67,16 @@ mod private
     }
   }
 
+  impl serde::Serialize for Version
+  {
+    fn serialize< S >( &self, serializer : S ) -> std::result::Result< S::Ok, S::Error >
+    where
+      S : serde::Serializer,
+    {
+      serializer.collect_str( &self.0 )
+    }
+  }
+
   impl Version
   {
     /// Bump a version with default strategy
@@ -80,10 +98,87 @@ mod private
 
       Self( ver )
     }
+
+    /// Bump a version according to the level of changes.
+    ///
+    /// Follows the Cargo flavour of semantic versioning :
+    /// - `0.0.x` - every change is breaking, so the patch number is increased;
+    /// - `0.x.y` - breaking changes increase the minor number, other changes increase the patch number;
+    /// - `x.y.z` - breaking changes increase the major number, additions increase the minor number, other changes increase the patch number.
+    pub fn bump_with( self, level : BumpLevel ) -> Self
+    {
+      let mut ver = self.0;
+      match ( ver.major, ver.minor, level )
+      {
+        ( 0, 0, _ ) | ( 0, _, BumpLevel::Minor | BumpLevel::Patch ) | ( _, _, BumpLevel::Patch ) =>
+        {
+          ver.patch += 1;
+        }
+        ( 0, _, BumpLevel::Major ) | ( _, _, BumpLevel::Minor ) =>
+        {
+          ver.minor += 1;
+          ver.patch = 0;
+        }
+        ( _, _, BumpLevel::Major ) =>
+        {
+          ver.major += 1;
+          ver.minor = 0;
+          ver.patch = 0;
+        }
+      }
+
+      Self( ver )
+    }
+
+    /// The highest level of changes the move from this version to the `new` one allows, by the same rules as `bump_with`.
+    ///
+    /// Returns `None` if the `new` version is not greater.
+    pub fn level_allowed( &self, new : &Version ) -> Option< BumpLevel >
+    {
+      let ( old, new ) = ( &self.0, &new.0 );
+      if new <= old { return None }
+      let level = match ( old.major, old.minor )
+      {
+        ( 0, 0 ) => BumpLevel::Major,
+        _ if new.major != old.major => BumpLevel::Major,
+        ( 0, _ ) if new.minor != old.minor => BumpLevel::Major,
+        ( 0, _ ) => BumpLevel::Minor,
+        _ if new.minor != old.minor => BumpLevel::Minor,
+        _ => BumpLevel::Patch,
+      };
+
+      Some( level )
+    }
+  }
+
+  /// Level of changes between two versions of a package.
+  #[ derive( Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize ) ]
+  #[ serde( rename_all = "lowercase" ) ]
+  pub enum BumpLevel
+  {
+    /// Changes which do not affect the public API.
+    Patch,
+    /// Backward compatible additions to the public API.
+    Minor,
+    /// Removals or changes of the public API.
+    Major,
+  }
+
+  impl fmt::Display for BumpLevel
+  {
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      match self
+      {
+        Self::Patch => write!( f, "patch" ),
+        Self::Minor => write!( f, "minor" ),
+        Self::Major => write!( f, "major" ),
+      }
+    }
   }
 
   /// A structure that represents a bump report, which contains information about a version bump.
-  #[ derive( Debug, Default, Clone ) ]
+  #[ derive( Debug, Default, Clone, serde::Serialize ) ]
   pub struct BumpReport
   {
     /// Pacakge name.
@@ -92,22 +187,37 @@ mod private
     pub old_version : Option< String >,
     /// Package new version.
     pub new_version : Option< String >,
+    /// Level of changes the bump was chosen for.
+    pub level : Option< BumpLevel >,
+    /// Why the level was chosen.
+    pub reason : Option< String >,
   }
 
   impl fmt::Display for BumpReport
   {
     fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
     {
-      let Self { name, old_version, new_version } = self;
+      let Self { name, old_version, new_version, level, reason } = self;
       match ( name, old_version, new_version )
       {
         ( Some( name ), Some( old_version ), Some( new_version ) )
-        => f.write_fmt( format_args!( "`{name}` bumped from {old_version} to {new_version}" ) ),
+        => f.write_fmt( format_args!( "`{name}` bumped from {old_version} to {new_version}{}", level_describe( level, reason ) ) ),
         _ => f.write_fmt( format_args!( "Bump failed" ) )
       }
     }
   }
 
+  fn level_describe( level : &Option< BumpLevel >, reason : &Option< String > ) -> String
+  {
+    match ( level, reason )
+    {
+      ( Some( level ), Some( reason ) ) => format!( " ({level} : {reason})" ),
+      ( Some( level ), None ) => format!( " ({level})" ),
+      ( None, Some( reason ) ) => format!( " ({reason})" ),
+      ( None, None ) => String::new(),
+    }
+  }
+
   // qqq : we have to replace the implementation above with the implementation below, don't we?
   // qqq : for Bohdan : duplication?
 
@@ -129,6 +239,13 @@ mod private
     /// by `Version` which denotes the new version number of the crate.
     pub new_version : Version,
 
+    /// `level` - The level of changes the new version was chosen for. `None` means that the default
+    /// strategy of `Version::bump` was used.
+    pub level : Option< BumpLevel >,
+
+    /// `reason` - A short explanation of why the new version was chosen.
+    pub reason : Option< String >,
+
     /// `dependencies` - This is a vector containing the directories of all the dependencies of the crate.
     /// Each item in the `dependencies` vector indicates a `CrateDir` directory of a single dependency.
     pub dependencies : Vec< CrateDir >,
@@ -140,7 +257,7 @@ mod private
   }
 
   /// Report about a changing version.
-  #[ derive( Debug, Default, Clone ) ]
+  #[ derive( Debug, Default, Clone, serde::Serialize ) ]
   pub struct ExtendedBumpReport
   {
     /// Pacakge name.
@@ -149,6 +266,10 @@ mod private
     pub old_version : Option< String >,
     /// Package new version.
     pub new_version : Option< String >,
+    /// Level of changes the bump was chosen for.
+    pub level : Option< BumpLevel >,
+    /// Why the level was chosen.
+    pub reason : Option< String >,
     /// Files that should(already) changed for bump.
     pub changed_files : Vec< ManifestFile >
   }
@@ -157,7 +278,7 @@ mod private
   {
     fn fmt( &self, f : &mut Formatter< '_ > ) -> std::fmt::Result
     {
-      let Self { name, old_version, new_version, changed_files } = self;
+      let Self { name, old_version, new_version, level, reason, changed_files } = self;
       if self.changed_files.is_empty()
       {
         write!( f, "Files were not changed during bumping the version" )?;
@@ -168,7 +289,7 @@ mod private
       match ( name, old_version, new_version )
       {
         ( Some( name ), Some( old_version ), Some( new_version ) )
-        => writeln!( f, "`{name}` bumped from {old_version} to {new_version}\n  changed files :\n    {files}" ),
+        => writeln!( f, "`{name}` bumped from {old_version} to {new_version}{}\n  changed files :\n    {files}", level_describe( level, reason ) ),
         _ => writeln!( f, "Bump failed" )
       }?;
 
@@ -205,6 +326,8 @@ mod private
     }
     report.old_version = Some( o.old_version.to_string() );
     report.new_version = Some( o.new_version.to_string() );
+    report.level = o.level;
+    report.reason = o.reason.clone();
 
     let mut package_manifest = package.manifest().map_err( | e | format_err!( "{report:?}\n{e:#?}" ) )?;
     if !o.dry
@@ -372,6 +495,8 @@ crate::mod_interface!
 {
   /// Version entity.
   exposed use Version;
+  /// Level of changes between versions.
+  own use BumpLevel;
 
   /// Report for bump operation.
   own use BumpReport;
@@ -14,6 +14,8 @@ mod private
   /// If no arguments are provided, the function identifies this as an ambiguous state and prompts the user with a help message, suggesting possible commands they might want to execute.
   /// It then terminates the program with an exit code of 1 to indicate an error due to the lack of input.
   ///
+  /// The `output:text|json|toml` property selects the format of printed reports and is accepted by every command which prints a report.
+  ///
   /// Do not support interactive mode.
   pub fn run( args : Vec< String > ) -> Result< (), error::untyped::Error >
   {
@@ -26,10 +28,10 @@ mod private
 
     let ca = command::ca()
     .help_variants( [ wca::HelpVariants::General, wca::HelpVariants::SubjectCommand ] )
+    .with_completion( true )
     .perform();
 
-    let program = args.join( " " );
-    if program.is_empty()
+    if args.is_empty()
     {
       eprintln!( "Ambiguity. Did you mean?" );
       ca.perform( ".help" )?;
@@ -37,7 +39,8 @@ mod private
     }
     else
     {
-      Ok( ca.perform( program.as_str() )? )
+      // arguments are already split by the shell, so they are not tokenized again
+      Ok( ca.perform( args )? )
     }
 
   }
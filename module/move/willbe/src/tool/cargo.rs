@@ -131,6 +131,8 @@ mod private
     pub( crate ) temp_path : Option< PathBuf >,
     #[ former( default = 0usize ) ]
     pub( crate ) retry_count : usize,
+    /// Url of the index of the registry to upload to, `crates.io` if it is not specified.
+    pub( crate ) index : Option< String >,
     pub( crate ) dry : bool,
   }
 
@@ -148,7 +150,8 @@ mod private
     fn as_publish_args( &self ) -> Vec< String >
     {
       let target_dir = self.temp_path.clone().map( | p | vec![ "--target-dir".to_string(), p.to_string_lossy().into() ] );
-      [ "publish".to_string() ].into_iter().chain( target_dir.into_iter().flatten() ).collect()
+      let index = self.index.clone().map( | i | vec![ "--index".to_string(), i ] );
+      [ "publish".to_string() ].into_iter().chain( target_dir.into_iter().flatten() ).chain( index.into_iter().flatten() ).collect()
     }
   }
 
@@ -108,6 +108,8 @@ mod private
 
   /// Pushes changes to the remote Git repository.
   ///
+  /// Annotated tags that point to pushed commits are pushed as well.
+  ///
   /// # Args :
   ///
   /// - `path` - the root path
@@ -126,7 +128,7 @@ mod private
   where
     P : AsRef< Path >,
   {
-    let ( program, args ) = ( "git", [ "push" ] );
+    let ( program, args ) = ( "git", [ "push", "--follow-tags" ] );
 
     if dry
     {
@@ -234,6 +236,268 @@ mod private
     .current_path( path.as_ref().to_path_buf() )
     .run().map_err( | report | err!( report.to_string() ) )
   }
+
+  /// Lists commits of the repository in the reverse chronological order.
+  ///
+  /// Each commit is printed as `hash\x1fsubject\x1fbody\x1e`.
+  ///
+  /// # Args :
+  ///
+  /// - `path` - the directory in which the command is executed
+  /// - `range` - optional revision range, for example `tag..HEAD`
+  /// - `pathspec` - limits commits to the ones that touch the given paths ( relative to `path` )
+  ///
+  /// # Returns :
+  /// Returns a result containing a report with the list of commits in the `out` field.
+  // qqq : should be typed error, apply err_with
+  // qqq : don't use 1-prameter Result
+  pub fn log< P, S >( path : P, range : Option< &str >, pathspec : &[ S ] ) -> error::untyped::Result< Report >
+  where
+    P : AsRef< Path >,
+    S : AsRef< str >,
+  {
+    let ( program, args ) : ( _, Vec< _ > ) =
+    (
+      "git",
+      [ "log", "--format=%H%x1f%s%x1f%b%x1e" ]
+      .into_iter()
+      .chain( range )
+      .chain( Some( "--" ) )
+      .chain( pathspec.iter().map( | p | p.as_ref() ) )
+      .map( String::from )
+      .collect()
+    );
+
+    Run::former()
+    .bin_path( program )
+    .args( args.into_iter().map( OsString::from ).collect::< Vec< _ > >() )
+    .current_path( path.as_ref().to_path_buf() )
+    .run().map_err( | report | err!( report.to_string() ) )
+  }
+
+  /// Resolves a revision ( a tag, a branch or a commit ) to a commit hash.
+  ///
+  /// # Args :
+  ///
+  /// - `path` - the directory in which the command is executed
+  /// - `revision` - the revision to resolve
+  ///
+  /// # Returns :
+  /// Returns a result containing a report with the hash in the `out` field. Fails if the revision does not exist.
+  // qqq : should be typed error, apply err_with
+  // qqq : don't use 1-prameter Result
+  pub fn rev_parse< P, R >( path : P, revision : R ) -> error::untyped::Result< Report >
+  where
+    P : AsRef< Path >,
+    R : AsRef< str >,
+  {
+    let ( program, args ) = ( "git", [ "rev-parse", "--verify", "--quiet", &format!( "{}^{{commit}}", revision.as_ref() ) ] );
+
+    Run::former()
+    .bin_path( program )
+    .args( args.into_iter().map( OsString::from ).collect::< Vec< _ > >() )
+    .current_path( path.as_ref().to_path_buf() )
+    .run().map_err( | report | err!( report.to_string() ) )
+  }
+
+  /// Lists files changed since a revision, including uncommitted changes of tracked files.
+  ///
+  /// # Args :
+  ///
+  /// - `path` - the directory in which the command is executed
+  /// - `since` - the revision to compare the working tree with
+  ///
+  /// # Returns :
+  /// Returns a result containing a report with paths relative to `path`, one per line, in the `out` field.
+  /// Files outside of `path` are not listed.
+  // qqq : should be typed error, apply err_with
+  // qqq : don't use 1-prameter Result
+  pub fn diff_names< P, S >( path : P, since : S ) -> error::untyped::Result< Report >
+  where
+    P : AsRef< Path >,
+    S : AsRef< str >,
+  {
+    let ( program, args ) = ( "git", [ "diff", "--name-only", "--relative", since.as_ref(), "--" ] );
+
+    Run::former()
+    .bin_path( program )
+    .args( args.into_iter().map( OsString::from ).collect::< Vec< _ > >() )
+    .current_path( path.as_ref().to_path_buf() )
+    .run().map_err( | report | err!( report.to_string() ) )
+  }
+
+  /// Lists untracked files which are not ignored.
+  ///
+  /// # Args :
+  ///
+  /// - `path` - the directory in which the command is executed
+  ///
+  /// # Returns :
+  /// Returns a result containing a report with paths relative to `path`, one per line, in the `out` field.
+  /// Files outside of `path` are not listed.
+  // qqq : should be typed error, apply err_with
+  // qqq : don't use 1-prameter Result
+  pub fn untracked_names< P >( path : P ) -> error::untyped::Result< Report >
+  where
+    P : AsRef< Path >,
+  {
+    let ( program, args ) = ( "git", [ "ls-files", "--others", "--exclude-standard" ] );
+
+    Run::former()
+    .bin_path( program )
+    .args( args.into_iter().map( OsString::from ).collect::< Vec< _ > >() )
+    .current_path( path.as_ref().to_path_buf() )
+    .run().map_err( | report | err!( report.to_string() ) )
+  }
+
+  /// Creates an annotated tag which points to `HEAD`.
+  ///
+  /// # Args :
+  ///
+  /// - `path` - the directory in which the command is executed
+  /// - `name` - the name of the tag
+  /// - `message` - the message of the tag
+  /// - `dry` - a flag that indicates whether to apply the changes or not
+  ///   - `true` - does not modify the Git state
+  ///   - `false` - creates the tag
+  ///
+  /// # Returns :
+  /// Returns a result containing a report indicating the result of the operation.
+  // qqq : should be typed error, apply err_with
+  // qqq : don't use 1-prameter Result
+  #[ cfg_attr( feature = "tracing", tracing::instrument( skip( path, message ), fields( path = %path.as_ref().display(), name = %name.as_ref() ) ) ) ]
+  pub fn tag< P, N, M >( path : P, name : N, message : M, dry : bool ) -> error::untyped::Result< Report >
+  where
+    P : AsRef< Path >,
+    N : AsRef< str >,
+    M : AsRef< str >,
+  {
+    let ( program, args ) = ( "git", [ "tag", "--annotate", name.as_ref(), "--message", message.as_ref() ] );
+
+    if dry
+    {
+      Ok
+      (
+        Report
+        {
+          command : format!( "{program} {}", args.join( " " ) ),
+          out : String::new(),
+          err : String::new(),
+          current_path : path.as_ref().to_path_buf(),
+          error : Ok( () ),
+        }
+      )
+    }
+    else
+    {
+      Run::former()
+      .bin_path( program )
+      .args( args.into_iter().map( OsString::from ).collect::< Vec< _ > >() )
+      .current_path( path.as_ref().to_path_buf() )
+      .run().map_err( | report | err!( report.to_string() ) )
+    }
+  }
+
+  /// Lists tags of the repository, one per line.
+  ///
+  /// # Args :
+  ///
+  /// - `path` - the directory in which the command is executed
+  /// - `pattern` - optional shell wildcard pattern, for example `willbe-v*`
+  ///
+  /// # Returns :
+  /// Returns a result containing a report with the list of tags in the `out` field.
+  // qqq : should be typed error, apply err_with
+  // qqq : don't use 1-prameter Result
+  pub fn tag_list< P >( path : P, pattern : Option< &str > ) -> error::untyped::Result< Report >
+  where
+    P : AsRef< Path >,
+  {
+    let ( program, args ) : ( _, Vec< _ > ) = ( "git", [ "tag", "--list" ].into_iter().chain( pattern ).collect() );
+
+    Run::former()
+    .bin_path( program )
+    .args( args.into_iter().map( OsString::from ).collect::< Vec< _ > >() )
+    .current_path( path.as_ref().to_path_buf() )
+    .run().map_err( | report | err!( report.to_string() ) )
+  }
+
+  /// Returns the path of the directory relative to the top directory of the repository.
+  ///
+  /// # Args :
+  ///
+  /// - `path` - the directory in which the command is executed
+  ///
+  /// # Returns :
+  /// Returns a result containing a report with the path followed by `/` in the `out` field, the path is empty for the top directory.
+  // qqq : should be typed error, apply err_with
+  // qqq : don't use 1-prameter Result
+  pub fn show_prefix< P >( path : P ) -> error::untyped::Result< Report >
+  where
+    P : AsRef< Path >,
+  {
+    let ( program, args ) = ( "git", [ "rev-parse", "--show-prefix" ] );
+
+    Run::former()
+    .bin_path( program )
+    .args( args.into_iter().map( OsString::from ).collect::< Vec< _ > >() )
+    .current_path( path.as_ref().to_path_buf() )
+    .run().map_err( | report | err!( report.to_string() ) )
+  }
+
+  /// Checks out a revision into a new detached worktree of the repository.
+  ///
+  /// # Args :
+  ///
+  /// - `path` - the directory in which the command is executed
+  /// - `worktree` - the directory of the worktree, it must not exist
+  /// - `revision` - the revision to check out, for example a tag
+  ///
+  /// # Returns :
+  /// Returns a result containing a report indicating the result of the operation.
+  // qqq : should be typed error, apply err_with
+  // qqq : don't use 1-prameter Result
+  pub fn worktree_add< P, W, R >( path : P, worktree : W, revision : R ) -> error::untyped::Result< Report >
+  where
+    P : AsRef< Path >,
+    W : AsRef< Path >,
+    R : AsRef< str >,
+  {
+    let program = "git";
+    let args = [ OsString::from( "worktree" ), "add".into(), "--detach".into(), "--quiet".into(), worktree.as_ref().into(), revision.as_ref().into() ];
+
+    Run::former()
+    .bin_path( program )
+    .args( args.into_iter().collect::< Vec< _ > >() )
+    .current_path( path.as_ref().to_path_buf() )
+    .run().map_err( | report | err!( report.to_string() ) )
+  }
+
+  /// Removes a worktree of the repository together with its directory, even if it has changes.
+  ///
+  /// # Args :
+  ///
+  /// - `path` - the directory in which the command is executed
+  /// - `worktree` - the directory of the worktree
+  ///
+  /// # Returns :
+  /// Returns a result containing a report indicating the result of the operation.
+  // qqq : should be typed error, apply err_with
+  // qqq : don't use 1-prameter Result
+  pub fn worktree_remove< P, W >( path : P, worktree : W ) -> error::untyped::Result< Report >
+  where
+    P : AsRef< Path >,
+    W : AsRef< Path >,
+  {
+    let program = "git";
+    let args = [ OsString::from( "worktree" ), "remove".into(), "--force".into(), worktree.as_ref().into() ];
+
+    Run::former()
+    .bin_path( program )
+    .args( args.into_iter().collect::< Vec< _ > >() )
+    .current_path( path.as_ref().to_path_buf() )
+    .run().map_err( | report | err!( report.to_string() ) )
+  }
 }
 
 //
@@ -245,4 +509,13 @@ crate::mod_interface!
   own use push;
   own use reset;
   own use ls_remote_url;
+  own use log;
+  own use rev_parse;
+  own use diff_names;
+  own use untracked_names;
+  own use tag;
+  own use tag_list;
+  own use show_prefix;
+  own use worktree_add;
+  own use worktree_remove;
 }
@@ -242,6 +242,8 @@ mod private
   /// * `package_map` - A reference to a `HashMap` mapping `String` keys to `Package` values.
   /// * `graph` - A reference to a `Graph` of nodes and edges, where nodes are of type `String` and edges are of type `String`.
   /// * `roots` - A slice of `String` representing the root nodes of the graph.
+  /// * `forced` - Names of packages which are kept even if they are the same as published ones.
+  /// * `registry` - The registry the local packages are compared with.
   ///
   /// # Returns
   ///
@@ -253,7 +255,9 @@ mod private
     package_map : &HashMap< String, Package< 'a > >,
     graph : &Graph< String, String >,
     roots : &[ String ],
+    forced : &[ String ],
     temp_path : Option< PathBuf >,
+    registry : &registry::Registry,
   )
   -> error::untyped::Result< Graph< String, String > >
   // qqq : use typed error!
@@ -285,7 +289,7 @@ mod private
           .allow_dirty( true )
           .form()
         )?;
-        if publish_need( package, temp_path.clone() ).unwrap()
+        if forced.contains( &graph[ n ] ) || publish_need( package, temp_path.clone(), registry ).unwrap()
         {
           nodes.insert( n );
         }
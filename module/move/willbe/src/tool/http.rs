@@ -7,37 +7,39 @@ mod private
   use std::
   {
     io::Read,
-    fmt::Write,
     time::Duration
   };
   use error::{ untyped::Context };
   use ureq::Agent;
 
   ///
-  /// Get data of remote package.
+  /// Get data by the url.
+  ///
+  /// Returns `None` if there is no resource at the url. Registries respond with `404`, `403` or `410` in this case.
   ///
   // qqq : typed error
-  pub fn download< 'a >( name : &'a str, version : &'a str ) -> error::untyped::Result< Vec< u8 > >
+  pub fn download( url : &str ) -> error::untyped::Result< Option< Vec< u8 > > >
   {
     let agent : Agent = ureq::AgentBuilder::new()
     .timeout_read( Duration::from_secs( 5 ) )
     .timeout_write( Duration::from_secs( 5 ) )
     .build();
-    let mut buf = String::new();
-    write!( &mut buf, "https://static.crates.io/crates/{0}/{0}-{1}.crate", name, version )?;
-
-    let resp = agent.get( &buf[ .. ] ).call().context( "Get data of remote package" )?;
 
-    let len : usize = resp.header( "Content-Length" )
-    .unwrap()
-    .parse()?;
+    let resp = match agent.get( url ).call()
+    {
+      Ok( resp ) => resp,
+      Err( ureq::Error::Status( 403 | 404 | 410, _ ) ) => return Ok( None ),
+      Err( e ) => return Err( e ).with_context( || format!( "Get data from {url}" ) ),
+    };
 
-    let mut bytes : Vec< u8 > = Vec::with_capacity( len );
+    let mut bytes : Vec< u8 > = resp.header( "Content-Length" )
+    .and_then( | len | len.parse().ok() )
+    .map_or_else( Vec::new, Vec::with_capacity );
     resp.into_reader()
     .take( u64::MAX )
     .read_to_end( &mut bytes )?;
 
-    Ok( bytes )
+    Ok( Some( bytes ) )
   }
 
 }
@@ -29,6 +29,10 @@ crate::mod_interface!
   layer macros;
   orphan use super::macros;
 
+  /// Format of reports printed by commands.
+  layer output;
+  orphan use super::output;
+
   /// Work with paths.
   layer path;
   orphan use super::path;
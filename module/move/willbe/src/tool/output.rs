@@ -0,0 +1,218 @@
+/// Internal namespace.
+mod private
+{
+  use crate::*;
+
+  use std::
+  {
+    fmt,
+    path::Path,
+    str::FromStr,
+  };
+  use serde::{ Serialize, Serializer };
+  use process_tools::process;
+  use error::untyped::{ Error, format_err };
+
+  /// Name of the property which selects a format of reports of a command.
+  pub const OUTPUT_PROPERTY : &str = "output";
+
+  /// Values accepted by the `output` property.
+  pub const OUTPUT_FORMATS : [ &str ; 3 ] = [ "text", "json", "toml" ];
+
+  /// Format in which commands print their reports.
+  ///
+  /// - `text` - human readable text, the default;
+  /// - `json` - report serialized into a pretty printed JSON document;
+  /// - `toml` - report serialized into a TOML document. Reports which are not tables are placed under the `report` key.
+  #[ derive( Debug, Default, Copy, Clone, PartialEq, Eq ) ]
+  pub enum OutputFormat
+  {
+    /// Human readable text.
+    #[ default ]
+    Text,
+    /// JSON document.
+    Json,
+    /// TOML document.
+    Toml,
+  }
+
+  impl OutputFormat
+  {
+    /// Returns `true` if reports should be printed as human readable text.
+    pub fn is_text( self ) -> bool
+    {
+      self == Self::Text
+    }
+  }
+
+  impl FromStr for OutputFormat
+  {
+    type Err = Error;
+
+    fn from_str( s : &str ) -> Result< Self, Self::Err >
+    {
+      match s
+      {
+        "text" => Ok( Self::Text ),
+        "json" => Ok( Self::Json ),
+        "toml" => Ok( Self::Toml ),
+        other => Err( format_err!( "Unexpected output format. Expected [text, json, toml]. Got: `{other}`" ) ),
+      }
+    }
+  }
+
+  impl fmt::Display for OutputFormat
+  {
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      match self
+      {
+        Self::Text => write!( f, "text" ),
+        Self::Json => write!( f, "json" ),
+        Self::Toml => write!( f, "toml" ),
+      }
+    }
+  }
+
+  /// Returns the format selected by the `output` property of a command.
+  ///
+  /// The format is `text` if the property is absent.
+  // qqq : typed error
+  pub fn output_format( props : &wca::Props ) -> error::untyped::Result< OutputFormat >
+  {
+    let format = props
+    .get_owned::< _, String >( OUTPUT_PROPERTY )
+    .map( | v | v.parse() )
+    .transpose()?
+    .unwrap_or_default();
+
+    Ok( format )
+  }
+
+  /// Formats a report according to the selected output format.
+  ///
+  /// `text` uses the `Display` implementation of the report, other formats serialize the report.
+  // qqq : typed error
+  pub fn report_format< R >( report : &R, format : OutputFormat ) -> error::untyped::Result< String >
+  where
+    R : fmt::Display + Serialize,
+  {
+    match format
+    {
+      OutputFormat::Text => Ok( report.to_string() ),
+      OutputFormat::Json => Ok( serde_json::to_string_pretty( report )? ),
+      OutputFormat::Toml =>
+      {
+        use toml_edit::easy::{ Value, value::Table };
+
+        let value = Value::try_from( report )?;
+        let value = if value.is_table() { value } else { Value::Table( Table::from_iter([ ( "report".to_string(), value ) ]) ) };
+
+        Ok( toml_edit::easy::to_string_pretty( &value )? )
+      }
+    }
+  }
+
+  /// Report of a command which failed before it could make a report of its own.
+  ///
+  /// Serialized as `{ error }`.
+  #[ derive( Debug, Serialize ) ]
+  pub struct ErrorReport
+  {
+    /// Description of the error.
+    pub error : String,
+  }
+
+  impl From< &Error > for ErrorReport
+  {
+    fn from( error : &Error ) -> Self
+    {
+      Self { error : error.to_string() }
+    }
+  }
+
+  impl fmt::Display for ErrorReport
+  {
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      write!( f, "{}", self.error )
+    }
+  }
+
+  /// Serializes an optional error as its description. Use together with `skip_serializing_if = "Option::is_none"`.
+  pub fn error_option_serialize< E, S >( error : &Option< E >, serializer : S ) -> Result< S::Ok, S::Error >
+  where
+    E : fmt::Display,
+    S : Serializer,
+  {
+    error.as_ref().map( ToString::to_string ).serialize( serializer )
+  }
+
+  #[ derive( Serialize ) ]
+  struct ProcessReportView< 'a >
+  {
+    command : &'a str,
+    current_path : &'a Path,
+    out : &'a str,
+    err : &'a str,
+    #[ serde( skip_serializing_if = "Option::is_none" ) ]
+    error : Option< String >,
+  }
+
+  impl< 'a > From< &'a process::Report > for ProcessReportView< 'a >
+  {
+    fn from( report : &'a process::Report ) -> Self
+    {
+      Self
+      {
+        command : &report.command,
+        current_path : &report.current_path,
+        out : &report.out,
+        err : &report.err,
+        error : report.error.as_ref().err().map( | e | e.to_string() ),
+      }
+    }
+  }
+
+  /// Serializes a report of a process as `{ command, current_path, out, err, error? }`.
+  ///
+  /// Intended to be used with `#[ serde( serialize_with = "..." ) ]`.
+  pub fn process_report_serialize< S >( report : &process::Report, serializer : S ) -> Result< S::Ok, S::Error >
+  where
+    S : Serializer,
+  {
+    ProcessReportView::from( report ).serialize( serializer )
+  }
+
+  /// Serializes an optional report of a process. Use together with `skip_serializing_if = "Option::is_none"`.
+  pub fn process_report_option_serialize< S >( report : &Option< process::Report >, serializer : S ) -> Result< S::Ok, S::Error >
+  where
+    S : Serializer,
+  {
+    report.as_ref().map( ProcessReportView::from ).serialize( serializer )
+  }
+
+  /// Serializes a list of reports of processes.
+  pub fn process_report_vec_serialize< S >( reports : &[ process::Report ], serializer : S ) -> Result< S::Ok, S::Error >
+  where
+    S : Serializer,
+  {
+    serializer.collect_seq( reports.iter().map( ProcessReportView::from ) )
+  }
+}
+
+//
+
+crate::mod_interface!
+{
+  own use OUTPUT_PROPERTY;
+  own use OUTPUT_FORMATS;
+  own use OutputFormat;
+  own use output_format;
+  own use report_format;
+  own use ErrorReport;
+  own use error_option_serialize;
+  own use process_report_serialize;
+  own use process_report_option_serialize;
+  own use process_report_vec_serialize;
+}
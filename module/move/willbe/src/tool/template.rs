@@ -260,7 +260,7 @@ mod private
   {
     /// Converts values to a serializable object.
     ///
-    /// Currently only `String`, `Number`, and `Bool` are supported.
+    /// Currently only `String`, `Number`, `Int`, `UInt` and `Bool` are supported.
     pub fn to_serializable( &self ) -> collection::BTreeMap< String, String >
     {
       self.0.iter().map
@@ -277,7 +277,9 @@ mod private
                 wca::Value::Number( val ) => val.to_string(),
                 wca::Value::Path( _ ) => "unsupported".to_string(),
                 wca::Value::Bool( val ) => val.to_string(),
-                wca::Value::List( _ ) => "unsupported".to_string(),
+                wca::Value::Int( val ) => val.to_string(),
+                wca::Value::UInt( val ) => val.to_string(),
+                _ => "unsupported".to_string(),
               }
             }
           )
@@ -97,6 +97,17 @@ mod private
     }
   }
 
+  /// Serialized as the `ListNodeReport` it prints.
+  impl serde::Serialize for TreePrinter
+  {
+    fn serialize< S >( &self, serializer : S ) -> Result< S::Ok, S::Error >
+    where
+      S : serde::Serializer,
+    {
+      self.info.serialize( serializer )
+    }
+  }
+
   impl std::fmt::Display for TreePrinter
   {
     fn fmt( &self, f : &mut Formatter< '_ > ) -> std::fmt::Result
@@ -133,7 +144,7 @@ mod private
   /// It holds essential information about the project dependencies. It is also capable
   /// of holding any nested dependencies in a recursive manner, allowing the modeling
   /// of complex dependency structures.
-  #[ derive( Debug, Clone, Eq, PartialEq ) ]
+  #[ derive( Debug, Clone, Eq, PartialEq, serde::Serialize ) ]
   pub struct ListNodeReport
   {
     /// This could be the name of the library or crate.
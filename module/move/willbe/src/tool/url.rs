@@ -11,27 +11,77 @@ mod private
   };
 
   /// Extracts the repository URL from a full URL.
+  ///
+  /// Any host is supported : `https://github.com/user/repo/tree/master` is `https://github.com/user/repo`,
+  /// `https://gitlab.example.com/group/subgroup/repo/-/tree/main` is `https://gitlab.example.com/group/subgroup/repo`.
+  /// SSH addresses ( `git@host:group/repo.git`, `ssh://git@host:22/group/repo.git` ) are converted to `https`.
   pub fn repo_url_extract( full_url : &str ) -> Option< String >
   {
-    let parts : Vec< &str > = full_url.split( '/' ).collect();
-
-    if parts.len() >= 4 && parts[ 0 ] == "https:" && parts[ 1 ] == "" && parts[ 2 ] == "github.com"
+    let url = full_url.trim();
+    let ( scheme, authority, path ) = if let Some( ( scheme, rest ) ) = url.split_once( "://" )
     {
-      let user = parts[ 3 ];
-      let repo = parts[ 4 ];
-      let repo_url = format!( "https://github.com/{}/{}", user, repo );
-      Some( repo_url )
+      let ( authority, path ) = rest.split_once( '/' )?;
+      match scheme
+      {
+        "http" | "https" => ( scheme, authority, path ),
+        // port of ssh is not the port of the web interface
+        _ => ( "https", authority.split( ':' ).next()?, path ),
+      }
     }
     else
     {
-      None
+      let ( authority, path ) = url.split_once( ':' )?;
+      if authority.contains( '/' ) { return None }
+      ( "https", authority, path )
+    };
+    let host = authority.rsplit( '@' ).next()?;
+    if host.is_empty() { return None }
+
+    let mut segments : Vec< &str > = path
+    .split( [ '/', '?', '#' ] )
+    .take_while( | s | *s != "-" )
+    .filter( | s | !s.is_empty() )
+    .collect();
+    if host == "github.com"
+    {
+      segments.truncate( 2 );
+    }
+    if let Some( last ) = segments.last_mut()
+    {
+      *last = last.trim_end_matches( ".git" );
+    }
+    if segments.len() < 2 || segments.iter().any( | s | s.is_empty() )
+    {
+      return None;
     }
+
+    Some( format!( "{scheme}://{host}/{}", segments.join( "/" ) ) )
   }
 
-  /// Extracts the username and repository name from a given URL.
+  /// Extracts the path of the repository on its host from a full URL of any host.
+  ///
+  /// `https://gitlab.example.com/group/subgroup/repo/-/tree/main` is `group/subgroup/repo`.
+  pub fn repo_path_extract( full_url : &str ) -> Option< String >
+  {
+    let url = repo_url_extract( full_url )?;
+    let ( _, rest ) = url.split_once( "://" )?;
+    let ( _, path ) = rest.split_once( '/' )?;
+
+    Some( path.to_string() )
+  }
+
+  /// Extracts the username and repository name from a given URL of GitHub : `Wandalen/wTools`.
+  ///
+  /// URLs of other hosts are rejected, because the names are used to build links and badges of GitHub.
   // qqq : use typed error
   pub fn git_info_extract( url : &String ) -> error::untyped::Result< String >
   {
+    let github = url.starts_with( "https://github.com/" ) || url.starts_with( "http://github.com/" );
+    if !github
+    {
+      return Err( format_err!( "Only repositories on GitHub are supported. Got: `{url}`" ) );
+    }
+
     let parts : Vec< &str > = url.split( '/' ).collect();
     if parts.len() >= 2
     {
@@ -47,5 +97,6 @@ mod private
 crate::mod_interface!
 {
   own use repo_url_extract;
+  own use repo_path_extract;
   own use git_info_extract;
 }
@@ -90,7 +90,7 @@ fn default_case()
   };
 
   // Act
-  _ = action::cicd_renew( &temp ).unwrap();
+  _ = action::cicd_renew( &temp, action::CiCdProvider::GitHub, false ).unwrap();
   dbg!( &file_path );
 
   // Assert
@@ -120,3 +120,85 @@ fn default_case()
 
 // aaa : for Petro : fix styles
 // aaa : ✅
+
+#[ test ]
+fn gitlab_provider()
+{
+  // Arrange
+  let temp = arrange( "single_module" );
+  let manifest = temp.path().join( "Cargo.toml" );
+  let content = std::fs::read_to_string( &manifest ).unwrap()
+  .replace( "https://github.com/Username/test", "git@gitlab.example.com:group/subgroup/test.git" );
+  std::fs::write( &manifest, content ).unwrap();
+
+  // Act
+  _ = action::cicd_renew( &temp, action::CiCdProvider::GitLab, false ).unwrap();
+
+  // Assert
+  let content = std::fs::read_to_string( temp.path().join( ".gitlab-ci.yml" ) ).unwrap();
+  assert!( content.contains( "for group/subgroup/test." ) );
+  let got : serde_yaml::Value = serde_yaml::from_str( &content ).unwrap();
+  let job = &got[ "test_test_module" ];
+  assert_eq!( job[ "extends" ], ".rust_test" );
+  assert_eq!( job[ "variables" ][ "MANIFEST_PATH" ], "test_module/Cargo.toml" );
+  assert_eq!( job[ "rules" ][ 0 ][ "changes" ][ 0 ], "test_module/**/*" );
+  assert!( !temp.path().join( ".github" ).join( "workflows" ).join( "module_test_module_push.yml" ).exists() );
+}
+
+#[ test ]
+// workflows of GitHub link to the repository on GitHub, a repository on another host is rejected
+fn gitlab_remote_github_provider()
+{
+  // Arrange
+  let temp = arrange( "single_module" );
+  let manifest = temp.path().join( "Cargo.toml" );
+  let content = std::fs::read_to_string( &manifest ).unwrap()
+  .replace( "https://github.com/Username/test", "git@gitlab.example.com:group/subgroup/test.git" );
+  std::fs::write( &manifest, content ).unwrap();
+
+  // Act
+  let result = action::cicd_renew( &temp, action::CiCdProvider::GitHub, false );
+
+  // Assert
+  assert!( result.is_err() );
+  assert!( !temp.path().join( ".github" ).join( "workflows" ).join( "module_test_module_push.yml" ).exists() );
+}
+
+#[ test ]
+fn makefile_provider()
+{
+  // Arrange
+  let temp = arrange( "single_module" );
+
+  // Act
+  _ = action::cicd_renew( &temp, action::CiCdProvider::Makefile, false ).unwrap();
+
+  // Assert
+  let content = std::fs::read_to_string( temp.path().join( "ci.mk" ) ).unwrap();
+  assert!( content.contains( "ci : test_test_module\n" ) );
+  assert!( content.contains( "test_test_module :\n\t$(CARGO) build --manifest-path test_module/Cargo.toml\n" ) );
+}
+
+#[ test ]
+fn check_mode()
+{
+  // Arrange
+  let temp = arrange( "single_module" );
+  let pipeline = temp.path().join( ".gitlab-ci.yml" );
+
+  // Act
+  let drift = action::cicd_renew( &temp, action::CiCdProvider::GitLab, true ).unwrap_err().to_string();
+
+  // Assert
+  assert!( !pipeline.exists() );
+  assert!( drift.contains( "+test_test_module :" ) );
+
+  // Act
+  _ = action::cicd_renew( &temp, action::CiCdProvider::GitLab, false ).unwrap();
+  let content = std::fs::read_to_string( &pipeline ).unwrap().replace( "rust:latest", "rust:1.70" );
+  std::fs::write( &pipeline, content ).unwrap();
+  let drift = action::cicd_renew( &temp, action::CiCdProvider::GitLab, true ).unwrap_err().to_string();
+
+  // Assert
+  assert!( drift.contains( "-  image : rust:1.70\n+  image : rust:latest\n" ) );
+}
@@ -0,0 +1,130 @@
+use super::*;
+use the_module::*;
+use helper::*;
+use std::fs;
+
+fn arrange( temp : &assert_fs::TempDir ) -> std::path::PathBuf
+{
+  let mut workspace = WorkspaceBuilder::new()
+  .member
+  (
+    ProjectBuilder::new( "a" )
+    .toml_file( "repository = \"https://example.com\"\ndescription = \"a\"\nlicense = \"MIT\"\n" )
+  )
+  .member
+  (
+    ProjectBuilder::new( "b" )
+    .toml_file
+    (
+      "description = \"b\"\n\n[dependencies]\n# keep me\nserde = { version = \"1\", features = [ \"derive\" ] }\na = { path = \"../a\" }\n\n[features]\nfull = [ \"a/x\" ]\n"
+    )
+  );
+  workspace.toml_content.push_str( "\n[workspace.package]\nlicense = \"MIT\"\n\n[workspace.dependencies]\nserde = \"1\"\n" );
+  let root = workspace.build( temp );
+  fs::write( root.join( "modules/a/Readme.md" ), "# a" ).unwrap();
+
+  root
+}
+
+#[ test ]
+fn lint_finds_problems()
+{
+  // Arrange
+  let temp = assert_fs::TempDir::new().unwrap();
+  let root = arrange( &temp );
+  let options = action::LintOptions::former()
+  .crate_dir( CrateDir::try_from( root.clone() ).unwrap() )
+  .form();
+
+  // Act
+  let ( report, _ ) = action::lint( options ).unwrap_err();
+
+  // Assert
+  let kinds : Vec< _ > = report.issues.iter().map( | i | ( i.package.as_str(), i.kind ) ).collect();
+  assert_eq!
+  (
+    kinds,
+    vec!
+    [
+      ( "b", the_module::entity::lint::LintKind::NotInherited ),
+      ( "b", the_module::entity::lint::LintKind::PathWithoutVersion ),
+      ( "b", the_module::entity::lint::LintKind::MissingField ),
+      ( "b", the_module::entity::lint::LintKind::MissingField ),
+      ( "b", the_module::entity::lint::LintKind::UnknownFeatureReference ),
+      ( "b", the_module::entity::lint::LintKind::MissingReadme ),
+    ]
+  );
+  assert!( report.issues.iter().all( | i | !i.fixed ) );
+}
+
+#[ test ]
+fn lint_fix_preserves_formatting()
+{
+  // Arrange
+  let temp = assert_fs::TempDir::new().unwrap();
+  let root = arrange( &temp );
+  let options = action::LintOptions::former()
+  .crate_dir( CrateDir::try_from( root.clone() ).unwrap() )
+  .fix( true )
+  .form();
+
+  // Act
+  let ( report, _ ) = action::lint( options ).unwrap_err();
+
+  // Assert
+  let fixed = report.issues.iter().filter( | i | i.fixed ).count();
+  assert_eq!( fixed, 3 );
+  assert_eq!( report.unresolved(), 3 );
+  let manifest = fs::read_to_string( root.join( "modules/b/Cargo.toml" ) ).unwrap();
+  assert!( manifest.contains( "# keep me" ) );
+  assert!( manifest.contains( "serde = { features = [ \"derive\" ], workspace = true }" ), "{manifest}" );
+  assert!( manifest.contains( "a = { path = \"../a\", version = \"~0.1.0\" }" ), "{manifest}" );
+  assert!( manifest.contains( "license = { workspace = true }" ), "{manifest}" );
+}
+
+#[ test ]
+fn lint_unknown_feature_references()
+{
+  // Arrange
+  let temp = assert_fs::TempDir::new().unwrap();
+  let workspace = WorkspaceBuilder::new()
+  .member( ProjectBuilder::new( "a" ).toml_file( "" ) )
+  .member
+  (
+    ProjectBuilder::new( "c" )
+    .toml_file
+    (
+      "[dependencies]\na = { path = \"../a\", version = \"0.1.0\" }\nserde = { version = \"1\", optional = true }\n\n[features]\ndefault = [ \"full\" ]\nfull = [ \"serde\", \"dep:serde\", \"serde?/derive\", \"a/x\", \"missing\", \"dep:nope\", \"nope/f\", \"nope?/f\" ]\n"
+    )
+  );
+  let root = workspace.build( &temp );
+  let options = action::LintOptions::former()
+  .crate_dir( CrateDir::try_from( root.join( "modules/c" ) ).unwrap() )
+  .form();
+
+  // Act
+  let ( report, _ ) = action::lint( options ).unwrap_err();
+
+  // Assert
+  let details : Vec< _ > = report.issues.iter()
+  .filter( | i | i.kind == the_module::entity::lint::LintKind::UnknownFeatureReference )
+  .map( | i | i.details.as_str() )
+  .collect();
+  assert_eq!
+  (
+    details,
+    vec!
+    [
+      // a feature of a local dependency
+      "feature `full` references `a/x`, but `a` has no feature `x`",
+      // x
+      "feature `full` references `missing`, but it is neither a feature nor a dependency",
+      // dep:x
+      "feature `full` references `dep:nope`, but `nope` is not a dependency",
+      // x/feature
+      "feature `full` references `nope/f`, but `nope` is not a dependency",
+      // x?/feature
+      "feature `full` references `nope?/f`, but `nope` is not a dependency",
+    ]
+  );
+}
@@ -215,6 +215,70 @@ mod package_with_remote_dependency
 
     assert_eq!( &[ "_package_with_remote_dep_b".to_string(), "_package_with_remote_dep_a".to_string() ], names.as_slice() );
   }
+
+  #[ test ]
+  fn graph_format()
+  {
+    // Arrange
+    let temp = arrange();
+    let args = ListOptions::former()
+      .path_to_manifest( crate_dir( &temp.join( "a" ) ) )
+      .format( ListFormat::Dot )
+      .info([ PackageAdditionalInfo::Version ])
+      .dependency_sources([ DependencySource::Local, DependencySource::Remote ])
+      .dependency_categories([ DependencyCategory::Primary ])
+      .form();
+
+    // Act
+    let output = action::list( args ).unwrap();
+
+    // Assert
+    let ListReport::Graph( graph ) = &output else { panic!( "Expected `Graph` format, but found another" ) };
+
+    assert_eq!( 3, graph.nodes.len() );
+    let a = graph.nodes.iter().find( | n | n.name == "_package_with_remote_dep_a" ).unwrap();
+    assert_eq!( DependencySource::Local, a.source );
+    assert_eq!( Some( "0.1.0" ), a.version.as_deref() );
+    let foo = graph.nodes.iter().find( | n | n.name == "foo" ).unwrap();
+    assert_eq!( DependencySource::Remote, foo.source );
+    assert_eq!( Some( "*" ), foo.version.as_deref() );
+
+    assert_eq!( 2, graph.edges.len() );
+    assert!( graph.edges.iter().all( | e | e.from == "_package_with_remote_dep_a" && e.kind == DependencyCategory::Primary ) );
+
+    // the report serialized by the global output format
+    let json = serde_json::to_value( &output ).unwrap();
+    assert_eq!( "normal", json[ "graph" ][ "edges" ][ 0 ][ "kind" ] );
+    assert_eq!( "remote", json[ "graph" ][ "nodes" ].as_array().unwrap().iter().find( | n | n[ "name" ] == "foo" ).unwrap()[ "source" ] );
+  }
+
+  #[ test ]
+  fn dot_and_mermaid_format()
+  {
+    // Arrange
+    let temp = arrange();
+    let args = | format | ListOptions::former()
+      .path_to_manifest( crate_dir( &temp.join( "a" ) ) )
+      .format( format )
+      .dependency_sources([ DependencySource::Local, DependencySource::Remote ])
+      .dependency_categories([ DependencyCategory::Primary ])
+      .form();
+
+    // Act
+    let dot = action::list( args( ListFormat::Dot ) ).unwrap().to_string();
+    let mermaid = action::list( args( ListFormat::Mermaid ) ).unwrap().to_string();
+
+    // Assert
+    assert!( dot.starts_with( "digraph dependencies {" ) );
+    assert!( dot.contains( r#""_package_with_remote_dep_a" -> "foo" [ label = "normal" ];"# ) );
+    assert!( dot.contains( r#""foo" [ label = "foo", shape = ellipse, style = dashed ];"# ) );
+    assert!( dot.contains( r#""_package_with_remote_dep_b" [ label = "_package_with_remote_dep_b", style = filled, fillcolor = lightblue ];"# ) );
+
+    assert!( mermaid.starts_with( "graph TD" ) );
+    assert!( mermaid.contains( r#"n0["_package_with_remote_dep_a"]:::local"# ) );
+    assert!( mermaid.contains( r#"(["foo"]):::remote"# ) );
+    assert!( mermaid.contains( "n0 -->|normal| n" ) );
+  }
 }
 
 // a -> b -> a
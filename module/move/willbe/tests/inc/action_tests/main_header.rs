@@ -25,7 +25,7 @@ fn tag_shout_stay()
   let temp = arrange( "single_module" );
 
   // Act
-  _ = action::readme_header_renew( AbsolutePath::try_from( temp.path() ).unwrap() ).unwrap();
+  _ = action::readme_header_renew( AbsolutePath::try_from( temp.path() ).unwrap(), false ).unwrap();
 
   let mut file = std::fs::File::open( temp.path().join( "Readme.md" ) ).unwrap();
 
@@ -45,7 +45,7 @@ fn branch_cell()
   let temp = arrange( "single_module" );
 
   // Act
-  _ = action::readme_header_renew( AbsolutePath::try_from( temp.path() ).unwrap() ).unwrap();
+  _ = action::readme_header_renew( AbsolutePath::try_from( temp.path() ).unwrap(), false ).unwrap();
 
   let mut file = std::fs::File::open( temp.path().join( "Readme.md" ) ).unwrap();
 
@@ -64,7 +64,7 @@ fn discord_cell()
   let temp = arrange( "single_module" );
 
   // Act
-  _ = action::readme_header_renew( AbsolutePath::try_from( temp.path() ).unwrap() ).unwrap();
+  _ = action::readme_header_renew( AbsolutePath::try_from( temp.path() ).unwrap(), false ).unwrap();
 
   let mut file = std::fs::File::open( temp.path().join( "Readme.md" ) ).unwrap();
 
@@ -83,7 +83,7 @@ fn gitpod_cell()
   let temp = arrange( "single_module" );
 
   // Act
-  _ = action::readme_header_renew( AbsolutePath::try_from( temp.path() ).unwrap() ).unwrap();
+  _ = action::readme_header_renew( AbsolutePath::try_from( temp.path() ).unwrap(), false ).unwrap();
 
   let mut file = std::fs::File::open( temp.path().join( "Readme.md" ) ).unwrap();
 
@@ -102,7 +102,7 @@ fn docs_cell()
   let temp = arrange( "single_module" );
 
   // Act
-  _ = action::readme_header_renew( AbsolutePath::try_from( temp.path() ).unwrap() ).unwrap();
+  _ = action::readme_header_renew( AbsolutePath::try_from( temp.path() ).unwrap(), false ).unwrap();
 
   let mut file = std::fs::File::open( temp.path().join( "Readme.md" ) ).unwrap();
 
@@ -121,7 +121,7 @@ fn without_fool_config()
   let temp = arrange( "single_module_without_master_branch_and_discord" );
 
   // Act
-  _ = action::readme_header_renew( AbsolutePath::try_from( temp.path() ).unwrap() ).unwrap();
+  _ = action::readme_header_renew( AbsolutePath::try_from( temp.path() ).unwrap(), false ).unwrap();
 
   let mut file = std::fs::File::open( temp.path().join( "Readme.md" ) ).unwrap();
 
@@ -141,13 +141,13 @@ fn idempotency()
   let temp = arrange( "single_module" );
 
   // Act
-  _ = action::readme_header_renew( AbsolutePath::try_from( temp.path() ).unwrap() ).unwrap();
+  _ = action::readme_header_renew( AbsolutePath::try_from( temp.path() ).unwrap(), false ).unwrap();
   let mut file = std::fs::File::open( temp.path().join( "Readme.md" ) ).unwrap();
   let mut actual1 = String::new();
   _ = file.read_to_string( &mut actual1 ).unwrap();
   drop( file );
 
-  _ = action::readme_header_renew( AbsolutePath::try_from( temp.path() ).unwrap() ).unwrap();
+  _ = action::readme_header_renew( AbsolutePath::try_from( temp.path() ).unwrap(), false ).unwrap();
   let mut file = std::fs::File::open( temp.path().join( "Readme.md" ) ).unwrap();
   let mut actual2 = String::new();
   _ = file.read_to_string( &mut actual2 ).unwrap();
@@ -164,5 +164,5 @@ fn without_needed_config()
   // Arrange
   let temp = arrange( "variadic_tag_configurations" );
   // Act
-  _ = action::readme_header_renew( AbsolutePath::try_from( temp.path() ).unwrap() ).unwrap();
+  _ = action::readme_header_renew( AbsolutePath::try_from( temp.path() ).unwrap(), false ).unwrap();
 }
\ No newline at end of file
@@ -0,0 +1,48 @@
+use super::*;
+use assert_fs::prelude::*;
+use the_module::
+{
+  action,
+  CrateDir,
+};
+
+fn arrange( source : &str ) -> assert_fs::TempDir
+{
+  let root_path = std::path::Path::new( env!( "CARGO_MANIFEST_DIR" ) );
+  let assets_relative_path = std::path::Path::new( ASSET_PATH );
+  let assets_path = root_path.join( assets_relative_path );
+
+  let temp = assert_fs::TempDir::new().unwrap();
+  temp.copy_from( assets_path.join( source ), &[ "**" ] ).unwrap();
+
+  // the header requires the name of the workspace
+  let manifest = temp.path().join( "Cargo.toml" );
+  let content = std::fs::read_to_string( &manifest ).unwrap()
+  .replace( "[workspace.metadata]\n", "[workspace.metadata]\nworkspace_name = \"test\"\n" );
+  std::fs::write( &manifest, content ).unwrap();
+
+  temp
+}
+
+#[ test ]
+fn check_mode()
+{
+  // Arrange
+  let temp = arrange( "single_module" );
+  let readme = temp.path().join( "Readme.md" );
+  let before = std::fs::read_to_string( &readme ).unwrap();
+
+  // Act
+  let ( _, e ) = action::readme_header_renew( CrateDir::try_from( temp.path() ).unwrap(), true ).unwrap_err();
+
+  // Assert
+  assert_eq!( std::fs::read_to_string( &readme ).unwrap(), before );
+  assert!( e.to_string().contains( "1 generated file(s) are out of date" ), "{e}" );
+
+  // Act
+  _ = action::readme_header_renew( CrateDir::try_from( temp.path() ).unwrap(), false ).unwrap();
+  let report = action::readme_header_renew( CrateDir::try_from( temp.path() ).unwrap(), true ).unwrap();
+
+  // Assert
+  assert!( report.to_string().contains( "File is up to date" ) );
+}
@@ -1,7 +1,10 @@
 use super::*;
 
 pub mod features;
+pub mod lint;
 pub mod list;
+pub mod main_header_check;
+pub mod publish_check;
 pub mod readme_health_table_renew;
 pub mod readme_modules_headers_renew;
 pub mod test;
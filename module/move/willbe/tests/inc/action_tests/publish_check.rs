@@ -0,0 +1,98 @@
+use crate::*;
+
+use the_module::*;
+use std::path::{ Path, PathBuf };
+use assert_fs::{ TempDir, prelude::* };
+use registry::Registry;
+use version::BumpLevel;
+
+const TEST_MODULE_PATH : &str = "../../test/";
+
+fn prepare( tmp : &TempDir, name : &str, extra_code : &str ) -> PathBuf
+{
+  let package_path = Path::new( env!( "CARGO_MANIFEST_DIR" ) ).join( TEST_MODULE_PATH ).join( "c" );
+  let dir = tmp.child( name );
+  dir.create_dir_all().unwrap();
+  dir.copy_from( &package_path, &[ "**" ] ).unwrap();
+  let lib = dir.join( "src" ).join( "lib.rs" );
+  let content = std::fs::read_to_string( &lib ).unwrap();
+  std::fs::write( &lib, format!( "{content}{extra_code}" ) ).unwrap();
+
+  dir.to_path_buf()
+}
+
+/// Packs the package into a local registry as the released version.
+fn release( tmp : &TempDir, dir : &Path ) -> Registry
+{
+  _ = cargo::pack( cargo::PackOptions::former().path( dir ).dry( false ).form() ).unwrap();
+  let registry_dir = tmp.child( "registry" );
+  registry_dir.create_dir_all().unwrap();
+  let file = "test_experimental_c-0.3.0.crate";
+  std::fs::copy( dir.join( "target" ).join( "package" ).join( file ), registry_dir.join( file ) ).unwrap();
+
+  Registry::Local( registry_dir.to_path_buf() )
+}
+
+#[ test ]
+fn breaking_change_requires_minor_bump()
+{
+  // Arrange
+  let tmp = TempDir::new().unwrap();
+  let registry = release( &tmp, &prepare( &tmp, "released", "\npub fn mul( left : usize, right : usize ) -> usize { left * right }\n" ) );
+  let local = prepare( &tmp, "local", "" );
+  let options = | version : &str | action::PublishCheckOptions::former()
+  .path( local.clone() )
+  .registry( registry.clone() )
+  .version( version.parse::< Version >().unwrap() )
+  .form();
+
+  // Act
+  let insufficient = action::publish_check( options( "0.3.1" ) ).unwrap();
+  let sufficient = action::publish_check( options( "0.4.0" ) ).unwrap();
+
+  // Assert
+  assert_eq!( vec![ "crate::mul".to_string() ], insufficient.diff.removed );
+  assert_eq!( BumpLevel::Major, insufficient.required );
+  assert_eq!( Some( BumpLevel::Minor ), insufficient.allowed );
+  assert!( !insufficient.is_sufficient() );
+  assert!( sufficient.is_sufficient() );
+  assert!( insufficient.to_string().contains( "Breaking changes :\n  - crate::mul" ) );
+}
+
+#[ test ]
+fn default_bump_allows_addition()
+{
+  // Arrange
+  let tmp = TempDir::new().unwrap();
+  let registry = release( &tmp, &prepare( &tmp, "released", "" ) );
+  let local = prepare( &tmp, "local", "\npub fn mul( left : usize, right : usize ) -> usize { left * right }\n" );
+
+  // Act
+  let report = action::publish_check( action::PublishCheckOptions::former().path( local ).registry( registry ).form() ).unwrap();
+
+  // Assert
+  assert_eq!( "0.4.0", report.planned_version.to_string() );
+  assert_eq!( vec![ "crate::mul".to_string() ], report.diff.added );
+  assert!( report.is_sufficient() );
+}
+
+#[ test ]
+fn released_version_is_taken_from_registry()
+{
+  // Arrange
+  let tmp = TempDir::new().unwrap();
+  let registry = release( &tmp, &prepare( &tmp, "released", "\npub fn mul( left : usize, right : usize ) -> usize { left * right }\n" ) );
+  let local = prepare( &tmp, "local", "" );
+  let manifest = local.join( "Cargo.toml" );
+  let content = std::fs::read_to_string( &manifest ).unwrap().replace( "version = \"0.3.0\"", "version = \"0.4.0\"" );
+  std::fs::write( &manifest, content ).unwrap();
+
+  // Act
+  let report = action::publish_check( action::PublishCheckOptions::former().path( local ).registry( registry ).form() ).unwrap();
+
+  // Assert
+  assert_eq!( "0.3.0", report.released_version.to_string() );
+  assert_eq!( "0.4.0", report.planned_version.to_string() );
+  assert_eq!( vec![ "crate::mul".to_string() ], report.diff.removed );
+  assert!( report.is_sufficient() );
+}
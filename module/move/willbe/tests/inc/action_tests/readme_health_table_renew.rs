@@ -23,7 +23,7 @@ fn without_any_toml_configurations_test()
   // Arrange
   let temp = arrange( "without_any_toml_configurations" );
   // Act
-  _  = action::readme_health_table_renew( &temp ).unwrap();
+  _  = action::readme_health_table_renew( &temp, false ).unwrap();
 }
 
 #[ test ]
@@ -33,7 +33,7 @@ fn tags_should_stay()
   let temp = arrange( "without_module_toml_configurations" );
 
   // Act
-  _  = action::readme_health_table_renew( &temp ).unwrap();
+  _  = action::readme_health_table_renew( &temp, false ).unwrap();
 
   // Assert
   let mut file = std::fs::File::open( temp.path().join( "readme.md" ) ).unwrap();
@@ -52,7 +52,7 @@ fn stability_experimental_by_default()
   let temp = arrange( "without_module_toml_configurations" );
 
   // Act
-  _  = action::readme_health_table_renew( &temp ).unwrap();
+  _  = action::readme_health_table_renew( &temp, false ).unwrap();
 
   // Assert
   let mut file = std::fs::File::open( temp.path().join( "readme.md" ) ).unwrap();
@@ -70,7 +70,7 @@ fn stability_and_repository_from_module_toml()
   let temp = arrange( "without_workspace_toml_configurations" );
 
   // Act
-  _  = action::readme_health_table_renew( &temp ).unwrap();
+  _  = action::readme_health_table_renew( &temp, false ).unwrap();
 
   // Assert
   let mut file = std::fs::File::open( temp.path().join( "readme.md" ) ).unwrap();
@@ -101,7 +101,7 @@ fn variadic_tag_configuration_test()
   let temp = arrange( "variadic_tag_configurations" );
 
   // Act
-  _  = action::readme_health_table_renew( &temp ).unwrap();
+  _  = action::readme_health_table_renew( &temp, false ).unwrap();
 
   // Assert
   let mut file = std::fs::File::open( temp.path().join( "readme.md" ) ).unwrap();
@@ -121,7 +121,7 @@ fn module_cell()
   let temp = arrange( "full_config" );
 
   // Act
-  _  = action::readme_health_table_renew( &temp ).unwrap();
+  _  = action::readme_health_table_renew( &temp, false ).unwrap();
 
   // Assert
   let mut file = std::fs::File::open( temp.path().join( "readme.md" ) ).unwrap();
@@ -139,7 +139,7 @@ fn stability_cell()
   let temp = arrange( "full_config" );
 
   // Act
-  _  = action::readme_health_table_renew( &temp ).unwrap();
+  _  = action::readme_health_table_renew( &temp, false ).unwrap();
 
   // Assert
   let mut file = std::fs::File::open( temp.path().join( "readme.md" ) ).unwrap();
@@ -157,7 +157,7 @@ fn branches_cell()
   let temp = arrange( "full_config" );
 
   // Act
-  _  = action::readme_health_table_renew( &temp ).unwrap();
+  _  = action::readme_health_table_renew( &temp, false ).unwrap();
 
   // Assert
   let mut file = std::fs::File::open( temp.path().join( "readme.md" ) ).unwrap();
@@ -175,7 +175,7 @@ fn docs_cell()
   let temp = arrange( "full_config" );
 
   // Act
-  _  = action::readme_health_table_renew( &temp ).unwrap();
+  _  = action::readme_health_table_renew( &temp, false ).unwrap();
 
   // Assert
   let mut file = std::fs::File::open( temp.path().join( "readme.md" ) ).unwrap();
@@ -193,7 +193,7 @@ fn sample_cell()
   let temp = arrange( "full_config" );
 
   // Act
-  _  = action::readme_health_table_renew( &temp ).unwrap();
+  _  = action::readme_health_table_renew( &temp, false ).unwrap();
 
   // Assert
   let mut file = std::fs::File::open( temp.path().join( "readme.md" ) ).unwrap();
@@ -203,3 +203,51 @@ fn sample_cell()
   // qqq : do not do like that. If it will fail how will I know what went wrong? What is the name of the package here?
   assert!( actual.contains( " [![Open in Gitpod](https://raster.shields.io/static/v1?label=&message=try&color=eee)](https://gitpod.io/#RUN_PATH=.,SAMPLE_FILE=.%2F_willbe_variadic_tag_configurations_full_config_c%2Fexamples%2F_willbe_variadic_tag_configurations_c_trivial.rs,RUN_POSTFIX=--example%20_willbe_variadic_tag_configurations_c_trivial/https://github.com/SomeName/SomeCrate/C)" ) );
 }
+
+#[ test ]
+fn check_mode()
+{
+  // Arrange
+  let temp = arrange( "without_module_toml_configurations" );
+  let readme = temp.path().join( "readme.md" );
+  let before = std::fs::read_to_string( &readme ).unwrap();
+
+  // Act
+  let drift = action::readme_health_table_renew( &temp, true ).unwrap_err().to_string();
+
+  // Assert
+  assert_eq!( std::fs::read_to_string( &readme ).unwrap(), before );
+  assert!( drift.contains( "1 generated file(s) are out of date" ) );
+  assert!( drift.contains( "--- a/" ) );
+  assert!( drift.contains( "+| Module | Stability |" ) );
+
+  // Act
+  action::readme_health_table_renew( &temp, false ).unwrap();
+
+  // Assert
+  action::readme_health_table_renew( &temp, true ).unwrap();
+}
+
+#[ test ]
+fn coverage_cell()
+{
+  // Arrange
+  let temp = arrange( "full_config" );
+  let readme = temp.path().join( "readme.md" );
+  let tag = std::fs::read_to_string( &readme ).unwrap().replace( "with_gitpod : 1", "with_gitpod : 0, with_coverage : 1" );
+  std::fs::write( &readme, tag ).unwrap();
+  // members of the workspace are all its directories
+  let manifest = std::fs::read_to_string( temp.path().join( "Cargo.toml" ) ).unwrap().replace( "members = [", "exclude = [ \"target\" ]\nmembers = [" );
+  std::fs::write( temp.path().join( "Cargo.toml" ), manifest ).unwrap();
+  let coverage_dir = temp.path().join( "target" ).join( the_module::coverage::COVERAGE_DIR );
+  std::fs::create_dir_all( &coverage_dir ).unwrap();
+  std::fs::write( coverage_dir.join( "_willbe_variadic_tag_configurations_full_config_c.lcov" ), "SF:src/lib.rs\nLF:10\nLH:9\nend_of_record\n" ).unwrap();
+
+  // Act
+  _  = action::readme_health_table_renew( &temp, false ).unwrap();
+
+  // Assert
+  let actual = std::fs::read_to_string( &readme ).unwrap();
+  assert!( actual.contains( "| Docs | Coverage |\n" ), "{actual}" );
+  assert!( actual.contains( " ![coverage](https://raster.shields.io/static/v1?label=&message=90%25&color=green) |\n" ), "{actual}" );
+}
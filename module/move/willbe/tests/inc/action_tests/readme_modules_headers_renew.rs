@@ -32,7 +32,7 @@ fn tags_should_stay()
   let temp = arrange( "single_module" );
 
   // Act
-  _ = action::readme_modules_headers_renew( CrateDir::try_from( temp.path() ).unwrap() ).unwrap();
+  _ = action::readme_modules_headers_renew( CrateDir::try_from( temp.path() ).unwrap(), false ).unwrap();
   let mut file = std::fs::File::open( temp.path().join( "test_module" ).join( "Readme.md" ) ).unwrap();
 
   let mut actual = String::new();
@@ -51,7 +51,7 @@ fn default_stability()
   let temp = arrange( "single_module" );
 
   // Act
-  _ = action::readme_modules_headers_renew( CrateDir::try_from( temp.path() ).unwrap() ).unwrap();
+  _ = action::readme_modules_headers_renew( CrateDir::try_from( temp.path() ).unwrap(), false ).unwrap();
   let mut file = std::fs::File::open( temp.path().join( "test_module" ).join( "Readme.md" ) ).unwrap();
 
   let mut actual = String::new();
@@ -70,7 +70,7 @@ fn docs()
   let temp = arrange( "single_module" );
 
   // Act
-  _ = action::readme_modules_headers_renew( CrateDir::try_from( temp.path() ).unwrap() ).unwrap();
+  _ = action::readme_modules_headers_renew( CrateDir::try_from( temp.path() ).unwrap(), false ).unwrap();
   let mut file = std::fs::File::open( temp.path().join( "test_module" ).join( "Readme.md" ) ).unwrap();
 
   let mut actual = String::new();
@@ -88,7 +88,7 @@ fn no_gitpod()
   let temp = arrange("single_module");
 
   // Act
-  _ = action::readme_modules_headers_renew( CrateDir::try_from( temp.path() ).unwrap() ).unwrap();
+  _ = action::readme_modules_headers_renew( CrateDir::try_from( temp.path() ).unwrap(), false ).unwrap();
   let mut file = std::fs::File::open(temp.path().join("test_module").join("Readme.md")).unwrap();
 
   let mut actual = String::new();
@@ -105,7 +105,7 @@ fn with_gitpod()
   let temp = arrange( "single_module_with_example" );
 
   // Act
-  _ = action::readme_modules_headers_renew( CrateDir::try_from( temp.path() ).unwrap() ).unwrap();
+  _ = action::readme_modules_headers_renew( CrateDir::try_from( temp.path() ).unwrap(), false ).unwrap();
   let mut file = std::fs::File::open( temp.path().join( "module" ).join( "test_module" ).join( "Readme.md" ) ).unwrap();
 
   let mut actual = String::new();
@@ -123,7 +123,7 @@ fn discord()
   let temp = arrange( "single_module" );
 
   // Act
-  _ = action::readme_modules_headers_renew( CrateDir::try_from( temp.path() ).unwrap() ).unwrap();
+  _ = action::readme_modules_headers_renew( CrateDir::try_from( temp.path() ).unwrap(), false ).unwrap();
   let mut file = std::fs::File::open( temp.path().join( "test_module" ).join( "Readme.md" ) ).unwrap();
 
   let mut actual = String::new();
@@ -141,7 +141,7 @@ fn status()
   let temp = arrange( "single_module" );
 
   // Act
-  _ = action::readme_modules_headers_renew( CrateDir::try_from( temp.path() ).unwrap() ).unwrap();
+  _ = action::readme_modules_headers_renew( CrateDir::try_from( temp.path() ).unwrap(), false ).unwrap();
   let mut file = std::fs::File::open( temp.path().join( "test_module" ).join( "Readme.md" ) ).unwrap();
 
   let mut actual = String::new();
@@ -159,13 +159,13 @@ fn idempotency()
   let temp = arrange( "single_module" );
 
   // Act
-  _ = action::readme_modules_headers_renew( CrateDir::try_from( temp.path() ).unwrap() ).unwrap();
+  _ = action::readme_modules_headers_renew( CrateDir::try_from( temp.path() ).unwrap(), false ).unwrap();
   let mut file = std::fs::File::open( temp.path().join( "test_module" ).join( "Readme.md" ) ).unwrap();
   let mut actual1 = String::new();
   _ = file.read_to_string( &mut actual1 ).unwrap();
   drop( file );
 
-  _ = action::readme_modules_headers_renew( CrateDir::try_from( temp.path() ).unwrap() ).unwrap();
+  _ = action::readme_modules_headers_renew( CrateDir::try_from( temp.path() ).unwrap(), false ).unwrap();
   let mut file = std::fs::File::open( temp.path().join( "test_module" ).join( "Readme.md" ) ).unwrap();
   let mut actual2 = String::new();
   _ = file.read_to_string( &mut actual2 ).unwrap();
@@ -180,7 +180,7 @@ fn with_many_members_and_varius_config()
 {
   let temp = arrange( "three_packages" );
 
-  _ = action::readme_modules_headers_renew( CrateDir::try_from( temp.path() ).unwrap() ).unwrap();
+  _ = action::readme_modules_headers_renew( CrateDir::try_from( temp.path() ).unwrap(), false ).unwrap();
 
   let mut file_b = std::fs::File::open( temp.path().join( "b" ).join( "Readme.md" ) ).unwrap();
   let mut file_c = std::fs::File::open( temp.path().join( "c" ).join( "Readme.md" ) ).unwrap();
@@ -207,5 +207,49 @@ fn without_needed_config()
   let temp = arrange( "variadic_tag_configurations" );
 
   // Act
-  _ = action::readme_modules_headers_renew( CrateDir::try_from( temp.path() ).unwrap() ).unwrap();
+  _ = action::readme_modules_headers_renew( CrateDir::try_from( temp.path() ).unwrap(), false ).unwrap();
+}
+
+#[ test ]
+// badges and links of module headers point to GitHub, a repository on another host is rejected
+fn gitlab_repository()
+{
+  // Arrange
+  let temp = arrange( "single_module" );
+  let readme = temp.path().join( "test_module" ).join( "Readme.md" );
+  let before = std::fs::read_to_string( &readme ).unwrap();
+  let manifest = temp.path().join( "test_module" ).join( "Cargo.toml" );
+  let content = std::fs::read_to_string( &manifest ).unwrap()
+  .replace( "https://github.com/Wandalen/wTools/tree/master/module/move/test_module", "https://gitlab.example.com/group/wTools/-/tree/master/module/move/test_module" );
+  std::fs::write( &manifest, content ).unwrap();
+
+  // Act
+  let result = action::readme_modules_headers_renew( CrateDir::try_from( temp.path() ).unwrap(), false );
+
+  // Assert
+  assert!( result.is_err() );
+  assert_eq!( std::fs::read_to_string( &readme ).unwrap(), before );
+}
+
+#[ test ]
+fn check_mode()
+{
+  // Arrange
+  let temp = arrange( "single_module" );
+  let readme = temp.path().join( "test_module" ).join( "Readme.md" );
+  let before = std::fs::read_to_string( &readme ).unwrap();
+
+  // Act
+  let ( _, e ) = action::readme_modules_headers_renew( CrateDir::try_from( temp.path() ).unwrap(), true ).unwrap_err();
+
+  // Assert
+  assert_eq!( std::fs::read_to_string( &readme ).unwrap(), before );
+  assert!( e.to_string().contains( "\n+ [![experimental]" ) );
+
+  // Act
+  _ = action::readme_modules_headers_renew( CrateDir::try_from( temp.path() ).unwrap(), false ).unwrap();
+  let report = action::readme_modules_headers_renew( CrateDir::try_from( temp.path() ).unwrap(), true ).unwrap();
+
+  // Assert
+  assert!( report.to_string().contains( "Up to date files :" ) );
 }
@@ -230,3 +230,126 @@ fn backtrace_should_be()
   assert!( !no_features.clone().unwrap_err().out.contains( "RUST_BACKTRACE" ) );
   assert!( no_features.clone().unwrap_err().out.contains( "stack backtrace" ) );
 }
+
+#[ test ]
+// if a variant runs longer than the timeout => it is killed and reported as a failure
+fn timeout()
+{
+  let temp = TempDir::new().unwrap();
+  let temp = &temp;
+
+  let project = ProjectBuilder::new( "timeout" )
+  .toml_file( "" )
+  .test_file( r#"
+    #[test]
+    fn hangs()
+    {
+      std::thread::sleep( std::time::Duration::from_secs( 600 ) );
+    }
+  "#)
+  .build( temp )
+  .unwrap();
+  let abs = AbsolutePath::try_from( project ).unwrap();
+
+  let args = TestsCommandOptions::former()
+  .dir( abs )
+  .channels([ Channel::Stable ])
+  .optimizations([ Optimization::Debug ])
+  .with_none_features( true )
+  .timeout( std::time::Duration::from_secs( 10 ) )
+  .form();
+
+  let start = std::time::Instant::now();
+  let rep = test( args, false ).unwrap_err().0;
+  println!( "========= OUTPUT =========\n{}\n==========================", rep );
+
+  let no_features = rep
+  .failure_reports[ 0 ]
+  .tests.get( &TestVariant::former().optimization( Optimization::Debug ).channel( Channel::Stable ).features( BTreeSet::default() ).form() )
+  .unwrap();
+
+  assert!( start.elapsed() < std::time::Duration::from_secs( 60 ) );
+  assert!( no_features.clone().unwrap_err().error.unwrap_err().to_string().contains( "timeout" ) );
+}
+
+#[ test ]
+// if a variant fails, but passes on a retry => it is reported as flaky with the failed run and the run succeeds
+fn flaky_retry()
+{
+  let temp = TempDir::new().unwrap();
+  let temp = &temp;
+
+  let project = ProjectBuilder::new( "flaky" )
+  .toml_file( "" )
+  .test_file( r#"
+    #[test]
+    fn fails_once()
+    {
+      let marker = std::path::Path::new( env!( "CARGO_MANIFEST_DIR" ) ).join( "marker" );
+      if !marker.exists()
+      {
+        std::fs::write( marker, "" ).unwrap();
+        panic!( "first run" );
+      }
+    }
+  "#)
+  .build( temp )
+  .unwrap();
+  let abs = AbsolutePath::try_from( project ).unwrap();
+
+  let args = TestsCommandOptions::former()
+  .dir( abs )
+  .channels([ Channel::Stable ])
+  .optimizations([ Optimization::Debug ])
+  .with_none_features( true )
+  .retries( 1u32 )
+  .form();
+
+  let rep = test( args, false ).unwrap();
+  println!( "========= OUTPUT =========\n{}\n==========================", rep );
+
+  let variant = TestVariant::former().optimization( Optimization::Debug ).channel( Channel::Stable ).features( BTreeSet::default() ).form();
+  assert!( rep.success_reports[ 0 ].tests.get( &variant ).unwrap().is_ok() );
+  let failed = rep.success_reports[ 0 ].flaky.get( &variant ).unwrap();
+  assert_eq!( 1, failed.len() );
+  assert!( failed[ 0 ].out.contains( "first run" ) || failed[ 0 ].err.contains( "first run" ) );
+}
+
+#[ test ]
+// variants of the first channel are instrumented, profiles of other toolchains could not be merged with them
+fn coverage_plan()
+{
+  let temp = TempDir::new().unwrap();
+  let temp = &temp;
+
+  let project = ProjectBuilder::new( "coverage_plan" )
+  .toml_file( "" )
+  .test_file( r#"
+  #[test]
+  fn should_pass() {
+    assert!(true);
+  }
+  "#)
+  .build( temp )
+  .unwrap();
+  let abs = AbsolutePath::try_from( project ).unwrap();
+
+  let args = TestsCommandOptions::former()
+  .dir( abs )
+  .channels([ Channel::Stable, Channel::Nightly ])
+  .optimizations([ Optimization::Debug ])
+  .with_none_features( true )
+  .coverage( true )
+  .form();
+
+  let rep = test( args, true ).unwrap().success_reports[ 0 ].clone();
+
+  let variant = | channel | TestVariant::former().optimization( Optimization::Debug ).channel( channel ).features( BTreeSet::default() ).form();
+  let stable = rep.tests.get( &variant( Channel::Stable ) ).unwrap().as_ref().unwrap();
+  let nightly = rep.tests.get( &variant( Channel::Nightly ) ).unwrap().as_ref().unwrap();
+  assert!( stable.command.starts_with( "RUSTFLAGS=\"-C instrument-coverage\" rustup run stable cargo test" ), "{}", stable.command );
+  assert!( nightly.command.starts_with( "rustup run nightly cargo test" ), "{}", nightly.command );
+  assert_eq!( "coverage_plan", rep.package_name.as_str() );
+  // nothing is run in dry mode
+  assert!( rep.coverage.is_none() );
+}
@@ -0,0 +1,84 @@
+use crate::*;
+
+use the_module::*;
+use inc::helper::{ ProjectBuilder, WorkspaceBuilder, git };
+use assert_fs::TempDir;
+use affected::AffectedReason;
+use package::PackageName;
+
+fn workspace( tmp : &TempDir ) -> std::path::PathBuf
+{
+  let path = WorkspaceBuilder::new()
+  .member( ProjectBuilder::new( "a" ).toml_file( "" ) )
+  .member( ProjectBuilder::new( "b" ).toml_file( "[dependencies]\na = { path = \"../a\" }" ) )
+  .member( ProjectBuilder::new( "c" ).toml_file( "" ) )
+  .build( tmp );
+  git( &path, &[ "init", "--quiet" ] );
+  git( &path, &[ "add", "." ] );
+  git( &path, &[ "commit", "--quiet", "--message", "init" ] );
+
+  path
+}
+
+#[ test ]
+fn nothing_changed()
+{
+  // Arrange
+  let tmp = TempDir::new().unwrap();
+  let path = workspace( &tmp );
+  let workspace = Workspace::try_from( CrateDir::try_from( path ).unwrap() ).unwrap();
+
+  // Act
+  let affected = affected::affected( &workspace, "HEAD" ).unwrap();
+
+  // Assert
+  assert!( affected.is_empty() );
+}
+
+#[ test ]
+fn changed_package_and_dependents()
+{
+  // Arrange
+  let tmp = TempDir::new().unwrap();
+  let path = workspace( &tmp );
+  std::fs::write( path.join( "modules/a/src/lib.rs" ), "pub fn f() {}\n" ).unwrap();
+  let workspace = Workspace::try_from( CrateDir::try_from( path ).unwrap() ).unwrap();
+
+  // Act
+  let affected = affected::affected( &workspace, "HEAD" ).unwrap();
+
+  // Assert
+  assert_eq!( 2, affected.0.len() );
+  assert_eq!
+  (
+    Some( &AffectedReason::Changed { files : vec![ "modules/a/src/lib.rs".into() ] } ),
+    affected.0.get( &PackageName::from( "a".to_string() ) )
+  );
+  assert_eq!
+  (
+    Some( &AffectedReason::Dependent { dependency : "a".to_string().into() } ),
+    affected.0.get( &PackageName::from( "b".to_string() ) )
+  );
+  assert!( !affected.contains( "c" ) );
+}
+
+#[ test ]
+fn untracked_file()
+{
+  // Arrange
+  let tmp = TempDir::new().unwrap();
+  let path = workspace( &tmp );
+  std::fs::write( path.join( "modules/c/src/new.rs" ), "pub fn f() {}\n" ).unwrap();
+  let workspace = Workspace::try_from( CrateDir::try_from( path ).unwrap() ).unwrap();
+
+  // Act
+  let affected = affected::affected( &workspace, "HEAD" ).unwrap();
+
+  // Assert
+  assert_eq!( 1, affected.0.len() );
+  assert_eq!
+  (
+    Some( &AffectedReason::Changed { files : vec![ "modules/c/src/new.rs".into() ] } ),
+    affected.0.get( &PackageName::from( "c".to_string() ) )
+  );
+}
@@ -0,0 +1,93 @@
+use crate::*;
+
+use the_module::changelog::
+{
+  Commit,
+  Change,
+  ChangeKind,
+  ChangelogSection,
+  section_insert,
+};
+
+fn commit( subject : &str, body : &str ) -> Commit
+{
+  Commit { hash : "0123456789abcdef".into(), subject : subject.into(), body : body.into() }
+}
+
+#[ test ]
+fn commits_parse()
+{
+  // Arrange
+  let out = "aaaa\x1ffeat: first\x1f\x1e\nbbbb\x1ffix: second\x1fdetails\x1e\n";
+
+  // Act
+  let commits = Commit::list_parse( out );
+
+  // Assert
+  assert_eq!
+  (
+    vec!
+    [
+      Commit { hash : "aaaa".into(), subject : "feat: first".into(), body : "".into() },
+      Commit { hash : "bbbb".into(), subject : "fix: second".into(), body : "details".into() },
+    ],
+    commits
+  );
+}
+
+#[ test ]
+fn conventional_kinds()
+{
+  assert_eq!( ChangeKind::Feature, Change::from( &commit( "feat: add a thing", "" ) ).kind );
+  assert_eq!( ChangeKind::Fix, Change::from( &commit( "fix(parser): handle quotes", "" ) ).kind );
+  assert_eq!( ChangeKind::Breaking, Change::from( &commit( "refactor!: drop old api", "" ) ).kind );
+  assert_eq!( ChangeKind::Breaking, Change::from( &commit( "feat: new api", "BREAKING CHANGE: old one is removed" ) ).kind );
+  assert_eq!( ChangeKind::Other, Change::from( &commit( "willbe : update readme", "" ) ).kind );
+}
+
+#[ test ]
+fn change_scope_and_description()
+{
+  // Act
+  let change = Change::from( &commit( "fix(parser): handle quotes", "" ) );
+
+  // Assert
+  assert_eq!( Some( "parser".to_string() ), change.scope );
+  assert_eq!( "handle quotes", change.description );
+  assert_eq!( "- **parser**: handle quotes (0123456)", change.to_string() );
+}
+
+#[ test ]
+fn section_insert_into_new_file()
+{
+  // Arrange
+  let section = ChangelogSection
+  {
+    title : "0.2.0".into(),
+    changes : vec![ Change::from( &commit( "feat: add a thing", "" ) ) ],
+  };
+
+  // Act
+  let content = section_insert( None, &section );
+
+  // Assert
+  assert_eq!( "# Changelog\n\n## 0.2.0\n\n### Features\n\n- add a thing (0123456)\n", content );
+}
+
+#[ test ]
+fn section_insert_replaces_unreleased()
+{
+  // Arrange
+  let existing = "# Changelog\n\n## Unreleased\n\n### Fixes\n\n- old (1111111)\n\n## 0.1.0\n\n### Features\n\n- initial (2222222)\n";
+  let section = ChangelogSection
+  {
+    title : "0.2.0".into(),
+    changes : vec![ Change::from( &commit( "fix: something", "" ) ) ],
+  };
+
+  // Act
+  let content = section_insert( Some( existing ), &section );
+
+  // Assert
+  assert_eq!( "# Changelog\n\n## 0.2.0\n\n### Fixes\n\n- something (0123456)\n\n## 0.1.0\n\n### Features\n\n- initial (2222222)\n", content );
+}
@@ -0,0 +1,60 @@
+use super::*;
+
+use the_module::coverage::{ self, Coverage };
+use assert_fs::TempDir;
+
+const LCOV : &str = "\
+SF:/ws/module/a/src/lib.rs
+FN:1,a::add
+DA:1,1
+DA:2,1
+DA:5,0
+LF:3
+LH:2
+end_of_record
+SF:/home/user/.cargo/registry/src/dep/lib.rs
+DA:1,0
+LF:1
+LH:0
+end_of_record
+SF:/ws/module/a/src/inner.rs
+DA:1,1
+LF:1
+LH:1
+end_of_record
+";
+
+#[ test ]
+fn lcov_filter_keeps_sources_of_package()
+{
+  // Act
+  let filtered = coverage::lcov_filter( LCOV, "/ws/module/a/src" );
+
+  // Assert
+  assert!( filtered.contains( "SF:/ws/module/a/src/lib.rs\nFN:1,a::add\n" ) );
+  assert!( filtered.contains( "SF:/ws/module/a/src/inner.rs\n" ) );
+  assert!( !filtered.contains( "registry" ) );
+  assert_eq!( 2, filtered.matches( "end_of_record" ).count() );
+  assert_eq!( ( 4, 3 ), coverage::lcov_lines( &filtered ) );
+  assert_eq!( ( 5, 3 ), coverage::lcov_lines( LCOV ) );
+}
+
+#[ test ]
+fn percent_and_badge()
+{
+  // Arrange
+  let temp = TempDir::new().unwrap();
+  let lcov = coverage::lcov_path( temp.path(), "a" );
+  std::fs::write( &lcov, coverage::lcov_filter( LCOV, "/ws/module/a/src" ) ).unwrap();
+
+  // Act
+  let coverage = Coverage::read( &lcov ).unwrap();
+
+  // Assert
+  assert_eq!( temp.path().join( "a.lcov" ), coverage.lcov );
+  assert_eq!( 75.0, coverage.percent() );
+  assert_eq!( "75.0 % ( 3 / 4 lines )", coverage.to_string() );
+  assert_eq!( "![coverage](https://raster.shields.io/static/v1?label=&message=75%25&color=yellow)", coverage::coverage_badge( &coverage ) );
+  let empty = Coverage { lcov : lcov.clone(), lines_found : 0, lines_hit : 0 };
+  assert_eq!( 100.0, empty.percent() );
+}
@@ -0,0 +1,120 @@
+use crate::*;
+
+use the_module::*;
+use inc::helper::{ ProjectBuilder, WorkspaceBuilder };
+use assert_fs::TempDir;
+use std::fs;
+
+#[ test ]
+fn declaration_line()
+{
+  // Arrange
+  let manifest = r#"[package]
+name = "a"
+
+[dependencies]
+b = { path = "../b" }
+"c-d".workspace = true
+
+[target.'cfg(unix)'.dev-dependencies]
+e = "1"
+
+[build-dependencies.f]
+version = "1"
+"#;
+
+  // Act & Assert
+  assert_eq!( dependency_usage::declaration_line( manifest, "dependencies", "b" ), Some( 5 ) );
+  assert_eq!( dependency_usage::declaration_line( manifest, "dependencies", "c-d" ), Some( 6 ) );
+  assert_eq!( dependency_usage::declaration_line( manifest, "dev-dependencies", "e" ), Some( 9 ) );
+  assert_eq!( dependency_usage::declaration_line( manifest, "build-dependencies", "f" ), Some( 11 ) );
+  assert_eq!( dependency_usage::declaration_line( manifest, "dev-dependencies", "b" ), None );
+}
+
+#[ test ]
+fn unused_and_duplicated()
+{
+  // Arrange
+  let temp = TempDir::new().unwrap();
+  let root = WorkspaceBuilder::new()
+  .member( ProjectBuilder::new( "used-lib" ).toml_file( "" ) )
+  .member( ProjectBuilder::new( "unused_lib" ).toml_file( "" ) )
+  .member( ProjectBuilder::new( "macro_lib" ).toml_file( "" ) )
+  .member( ProjectBuilder::new( "grouped_lib" ).toml_file( "" ) )
+  .member
+  (
+    ProjectBuilder::new( "a" )
+    .toml_file
+    (
+      "\n[dependencies]\nused-lib = { path = \"../used-lib\" }\nunused_lib = { path = \"../unused_lib\" }\nmacro_lib = { path = \"../macro_lib\" }\ngrouped_lib = { path = \"../grouped_lib\" }\n"
+    )
+    .lib_file( "use used_lib::*;\nuse ::{ std::fmt, grouped_lib as g };\n#[ macro_use ]\nextern crate macro_lib;\n// unused_lib\npub fn f() { let unused_lib = 1; }\n" )
+  )
+  .build( &temp );
+  // `w` is duplicated, but it is not reachable from `a`
+  let lock = r#"version = 3
+
+[[package]]
+name = "a"
+version = "0.1.0"
+dependencies = [
+ "used-lib 0.1.0",
+ "x 0.1.0",
+ "y",
+]
+
+[[package]]
+name = "used-lib"
+version = "0.1.0"
+dependencies = [
+ "x 0.2.0",
+]
+
+[[package]]
+name = "used-lib"
+version = "1.0.0"
+
+[[package]]
+name = "w"
+version = "0.1.0"
+
+[[package]]
+name = "w"
+version = "0.2.0"
+
+[[package]]
+name = "x"
+version = "0.1.0"
+
+[[package]]
+name = "x"
+version = "0.2.0"
+
+[[package]]
+name = "y"
+version = "1.2.0"
+dependencies = [
+ "used-lib 1.0.0 (registry+https://github.com/rust-lang/crates.io-index)",
+]
+"#;
+  fs::write( root.join( "Cargo.lock" ), lock ).unwrap();
+  let workspace = Workspace::try_from( CrateDir::try_from( root.clone() ).unwrap() ).unwrap();
+  let package = workspace.packages().find( | p | p.name() == "a" ).unwrap();
+
+  // Act
+  let unused = dependency_usage::unused( &package ).unwrap();
+  let duplicated = dependency_usage::duplicated( &workspace, workspace.packages() ).unwrap();
+
+  // Assert
+  assert_eq!( unused.len(), 1 );
+  assert_eq!( unused[ 0 ].name, "unused_lib" );
+  assert_eq!( unused[ 0 ].location.as_ref().unwrap().line, 8 );
+
+  let names : Vec< _ > = duplicated.iter().map( | d | d.name.as_str() ).collect();
+  assert_eq!( names, vec![ "used-lib", "x" ] );
+  assert_eq!( duplicated[ 0 ].locations.len(), 1 );
+  assert_eq!( duplicated[ 0 ].locations[ 0 ].line, 7 );
+  assert!( duplicated[ 0 ].locations[ 0 ].file.ends_with( "modules/a/Cargo.toml" ) );
+  assert!( duplicated[ 1 ].locations[ 0 ].file.ends_with( "Cargo.lock" ) );
+  assert_eq!( duplicated[ 1 ].locations.iter().map( | l | l.line ).collect::< Vec< _ > >(), vec![ 32, 36 ] );
+}
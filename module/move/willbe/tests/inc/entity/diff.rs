@@ -5,7 +5,7 @@ use std::path::{ Path, PathBuf };
 use assert_fs::{ TempDir, prelude::* };
 use crates_tools::CrateArchive;
 use package::Package;
-use diff::crate_diff;
+use diff::{ DiffOptions, crate_diff, crate_diff_with, manifest_diff };
 use the_module::version::{ Version, BumpOptions, bump };
 
 const TEST_MODULE_PATH : &str = "../../test/";
@@ -56,6 +56,8 @@ fn with_changes()
       crate_dir : CrateDir::try_from( right.clone() ).unwrap(),
       old_version : right_version.clone(),
       new_version : right_version.bump(),
+      level : None,
+      reason : None,
       dependencies : vec![],
       dry : false,
     };
@@ -70,6 +72,76 @@ fn with_changes()
   assert!( has_changes );
 }
 
+#[ test ]
+fn line_diff_with_context()
+{
+  // Arrange
+  let tmp = &TempDir::new().unwrap();
+  let package_path = package_path( "c" );
+  let left = prepare( tmp, "left", &package_path );
+  let lib = left.join( "src" ).join( "lib.rs" );
+  let content = std::fs::read_to_string( &lib ).unwrap().replace( "  left - right", "  left.saturating_sub( right )" );
+  std::fs::write( &lib, content ).unwrap();
+  let left = CrateArchive::read( crate_file_path( &left ) ).unwrap();
+  let right = CrateArchive::read( crate_file_path( &prepare( tmp, "right", &package_path ) ) ).unwrap();
+
+  // Act
+  let report = crate_diff_with( &left, &right, &DiffOptions::former().context( 1usize ).form() ).exclude( diff::PUBLISH_IGNORE_LIST );
+  let brief = crate_diff_with( &left, &right, &DiffOptions::former().lines( false ).form() ).exclude( diff::PUBLISH_IGNORE_LIST );
+  let large = crate_diff_with( &left, &right, &DiffOptions::former().max_size( 16usize ).form() ).exclude( diff::PUBLISH_IGNORE_LIST );
+
+  // Assert
+  let json = serde_json::to_value( &report ).unwrap();
+  let lib = &json[ "test_experimental_c-0.3.0/src/lib.rs" ];
+  assert_eq!( "modified", lib[ "status" ] );
+  assert_eq!( "@@ -7,3 +7,3 @@", lib[ "hunks" ][ 0 ][ "header" ] );
+  let changes : Vec< _ > = lib[ "hunks" ][ 0 ][ "changes" ].as_array().unwrap().iter().map( | c | format!( "{}{}", c[ "tag" ].as_str().unwrap(), c[ "line" ].as_str().unwrap() ) ).collect();
+  assert_eq!( vec![ "same{\n", "rem  left - right\n", "add  left.saturating_sub( right )\n", "same}\n" ], changes );
+  let brief = serde_json::to_value( &brief ).unwrap();
+  assert_eq!( serde_json::json!({ "status" : "modified" }), brief[ "test_experimental_c-0.3.0/src/lib.rs" ] );
+  let large = serde_json::to_value( &large ).unwrap();
+  assert_eq!( "larger than 16 bytes", large[ "test_experimental_c-0.3.0/src/lib.rs" ][ "reason" ] );
+}
+
+#[ test ]
+fn manifest_changes()
+{
+  // Arrange
+  let tmp = &TempDir::new().unwrap();
+  let package_path = package_path( "c" );
+  let dependencies = | dir : &Path, dependencies : &str |
+  {
+    let manifest = dir.join( "Cargo.toml" );
+    let content = std::fs::read_to_string( &manifest ).unwrap();
+    std::fs::write( &manifest, format!( "{content}\n{dependencies}" ) ).unwrap();
+  };
+  let left = prepare( tmp, "left", &package_path );
+  dependencies( &left, "[dependencies]\nserde = \"1.0.100\"\n[dev-dependencies]\ntempfile = { version = \"3\" }\n" );
+  let right = prepare( tmp, "right", &package_path );
+  dependencies( &right, "[dependencies]\nserde = \"1.0.0\"\nregex = \"1\"\n" );
+  let left = CrateArchive::read( crate_file_path( &left ) ).unwrap();
+  let right = CrateArchive::read( crate_file_path( &right ) ).unwrap();
+
+  // Act
+  let report = manifest_diff( &left, &right );
+
+  // Assert
+  assert!( report.has_changes() );
+  assert_eq!( None, report.version );
+  let changes : Vec< _ > = report.dependencies.iter().map( | c | ( c.section.as_str(), c.name.as_str(), c.old.as_deref(), c.new.as_deref() ) ).collect();
+  assert_eq!
+  (
+    vec!
+    [
+      ( "dependencies", "regex", Some( "1" ), None ),
+      ( "dependencies", "serde", Some( "1.0.0" ), Some( "1.0.100" ) ),
+      ( "dev-dependencies", "tempfile", None, Some( "3" ) ),
+    ],
+    changes
+  );
+  assert!( !manifest_diff( &left, &left ).has_changes() );
+}
+
 fn package_path< P : AsRef< Path > >( path : P ) -> PathBuf
 {
   let root_path = Path::new( env!( "CARGO_MANIFEST_DIR" ) ).join( TEST_MODULE_PATH );
@@ -2,7 +2,7 @@ use super::*;
 
 use the_module::
 {
-  features::{ features_powerset, estimate_with },
+  features::{ features_powerset, features_pairwise, features_closure, dedup_by_closure, estimate_with },
   collection::HashMap,
 };
 use serde::Deserialize;
@@ -266,3 +266,104 @@ fn estimate()
   assert_eq!( estimate_with( 5, 2, false, false, &[ "feature1".to_string(), "feature2".to_string() ], 2 ), 20 );
   assert_eq!( estimate_with( 5, 2, true, true, &[ "feature1".to_string(), "feature2".to_string() ], 2 ), 22 );
 }
+
+#[ test ]
+fn closure()
+{
+  let package = mock_package
+  (
+    vec!
+    [
+      ( "f1", vec![ "f2" ] ),
+      ( "f2", vec![ "dep:d" ] ),
+      ( "f3", vec![ "d/x" ] ),
+      ( "d", vec![ "dep:d" ] ),
+      ( "f4", vec![ "d?/x" ] ),
+    ]
+  );
+
+  let closure = | features : &[ &str ] | features_closure( &package.features.clone().into_iter().collect(), &features.iter().map( | f | f.to_string() ).collect() );
+
+  assert_eq!( closure( &[ "f1" ] ), [ "f1", "f2", "dep:d" ].into_iter().map( String::from ).collect() );
+  assert_eq!( closure( &[ "f3" ] ), [ "f3", "d/x", "d", "dep:d" ].into_iter().map( String::from ).collect() );
+  assert_eq!( closure( &[ "f4" ] ), [ "f4", "d?/x" ].into_iter().map( String::from ).collect() );
+}
+
+#[ test ]
+fn dedup_implied_features()
+{
+  let package = mock_package
+  (
+    vec!
+    [
+      ( "f1", vec![ "f2" ] ),
+      ( "f2", vec![] ),
+      ( "f3", vec![] ),
+    ]
+  );
+  let result = features_powerset
+  (
+    ( &package ).into(),
+    2,
+    &[],
+    &[],
+    &[],
+    true,
+    true,
+    100,
+  ).unwrap();
+  assert!( result.contains( &vec![ "f1".to_string(), "f2".to_string() ].into_iter().collect() ) );
+
+  let result = dedup_by_closure( ( &package ).into(), result );
+  dbg!( &result );
+
+  assert!( result.contains( &vec![ "f1".to_string() ].into_iter().collect() ) );
+  assert!( !result.contains( &vec![ "f1".to_string(), "f2".to_string() ].into_iter().collect() ) );
+  assert!( result.contains( &vec![ "f1".to_string(), "f3".to_string() ].into_iter().collect() ) );
+  assert!( !result.contains( &vec![ "f1".to_string(), "f2".to_string(), "f3".to_string() ].into_iter().collect() ) );
+  assert!( result.contains( &vec![ "f2".to_string(), "f3".to_string() ].into_iter().collect() ) );
+  assert_eq!( result.len(), 6 );
+}
+
+#[ test ]
+fn pairwise_covers_all_pairs()
+{
+  let names : Vec< String > = ( 1..=10 ).map( | i | format!( "f{i:02}" ) ).collect();
+  let package = mock_package( names.iter().map( | n | ( n.as_str(), vec![] ) ).collect() );
+  let enabled_features = vec![ "f01".to_string() ];
+
+  let result = features_pairwise
+  (
+    ( &package ).into(),
+    &[],
+    &[],
+    &enabled_features,
+    false,
+    false,
+    100,
+  ).unwrap();
+  dbg!( &result );
+
+  assert!( result.len() < 20 );
+  assert!( result.iter().all( | s | s.contains( "f01" ) ) );
+  for ( i, a ) in names.iter().enumerate().skip( 1 )
+  {
+    for b in names.iter().skip( i + 1 )
+    {
+      for ( on_a, on_b ) in [ ( false, false ), ( false, true ), ( true, false ), ( true, true ) ]
+      {
+        assert!( result.iter().any( | s | s.contains( a ) == on_a && s.contains( b ) == on_b ), "{a}={on_a} {b}={on_b}" );
+      }
+    }
+  }
+}
+
+#[ test ]
+fn pairwise_cap()
+{
+  let package = mock_package( vec![ ( "f1", vec![] ), ( "f2", vec![] ), ( "f3", vec![] ) ] );
+
+  let result = features_pairwise( ( &package ).into(), &[], &[], &[], false, false, 2 );
+
+  assert!( result.is_err() );
+}
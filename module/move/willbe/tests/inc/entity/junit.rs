@@ -0,0 +1,82 @@
+use crate::*;
+
+use the_module::*;
+use assert_fs::TempDir;
+use collection::BTreeSet;
+use channel::Channel;
+use optimization::Optimization;
+use process_tools::process::Report;
+use test::{ TestReport, TestsReport, TestVariant };
+
+fn variant( features : &[ &str ] ) -> TestVariant
+{
+  TestVariant::former()
+  .optimization( Optimization::Debug )
+  .channel( Channel::Stable )
+  .features( features.iter().map( | f | f.to_string() ).collect::< BTreeSet< _ > >() )
+  .form()
+}
+
+fn process_report( out : &str ) -> Report
+{
+  Report
+  {
+    command : "cargo test".into(),
+    current_path : std::path::PathBuf::new(),
+    out : out.into(),
+    err : String::new(),
+    error : Ok( () ),
+  }
+}
+
+fn report() -> TestsReport
+{
+  let mut package = TestReport::default();
+  package.package_name = "a".to_string().into();
+  package.tests.insert( variant( &[] ), Ok( process_report( "ok" ) ) );
+  package.tests.insert( variant( &[ "f1", "f2" ] ), Err( process_report( "assertion <left> & <right> failed" ) ) );
+  let mut report = TestsReport::default();
+  report.failure_reports.push( package );
+
+  report
+}
+
+#[ test ]
+fn variant_file_stem()
+{
+  assert_eq!( "stable_debug_no_features", junit::variant_file_stem( &variant( &[] ) ) );
+  assert_eq!( "stable_debug_f1+f2", junit::variant_file_stem( &variant( &[ "f1", "f2" ] ) ) );
+  assert_eq!( "stable_debug_a_b", junit::variant_file_stem( &variant( &[ "a/b" ] ) ) );
+}
+
+#[ test ]
+fn junit_xml()
+{
+  // Act
+  let xml = junit::junit_xml( &report() );
+
+  // Assert
+  assert!( xml.contains( r#"<testsuites name="willbe" tests="2" failures="1">"# ) );
+  assert!( xml.contains( r#"<testsuite name="a" tests="2" failures="1" errors="0" skipped="0">"# ) );
+  assert!( xml.contains( r#"<testcase name="debug stable" classname="a"/>"# ) );
+  assert!( xml.contains( r#"<failure message="cargo test">&gt; cargo test"# ) );
+  assert!( xml.contains( "assertion &lt;left&gt; &amp; &lt;right&gt; failed" ) );
+}
+
+#[ test ]
+fn write_artifacts()
+{
+  // Arrange
+  let tmp = TempDir::new().unwrap();
+  let dir = tmp.path().join( "report" );
+
+  // Act
+  let artifacts = junit::write_artifacts( &report(), &dir ).unwrap();
+
+  // Assert
+  assert_eq!( dir.join( "junit.xml" ), artifacts.junit );
+  assert!( artifacts.junit.is_file() );
+  assert_eq!( 2, artifacts.logs.len() );
+  let log = std::fs::read_to_string( dir.join( "logs/a/stable_debug_f1+f2.log" ) ).unwrap();
+  assert_eq!( "> cargo test\nassertion <left> & <right> failed", log );
+}
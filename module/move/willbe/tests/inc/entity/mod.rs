@@ -1,6 +1,16 @@
 use super::*;
 
+pub mod affected;
+pub mod changelog;
+pub mod coverage;
 pub mod dependencies;
+pub mod dependency_usage;
 pub mod diff;
 pub mod features;
+pub mod junit;
+pub mod packed_crate;
+pub mod public_api;
+pub mod publish_journal;
+pub mod registry;
+pub mod test_cache;
 pub mod version;
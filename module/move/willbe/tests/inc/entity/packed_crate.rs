@@ -0,0 +1,88 @@
+use crate::*;
+
+use the_module::*;
+use std::path::{ Path, PathBuf };
+use inc::helper::git;
+use assert_fs::{ TempDir, prelude::* };
+
+const TEST_MODULE_PATH : &str = "../../test/";
+
+#[ test ]
+fn tagged_without_tag()
+{
+  // Arrange
+  let tmp = &TempDir::new().unwrap();
+  let dir = prepare( tmp );
+
+  // Act
+  let archive = packed_crate::tagged( "test_experimental_c", "0.3.0", CrateDir::try_from( dir.as_path() ).unwrap() ).unwrap();
+
+  // Assert
+  assert!( archive.is_none() );
+}
+
+#[ test ]
+fn tagged_with_tag()
+{
+  // Arrange
+  let tmp = &TempDir::new().unwrap();
+  let dir = prepare( tmp );
+  git( &dir, &[ "tag", "--annotate", "test_experimental_c-v0.3.0", "--message", "release" ] );
+  let lib = dir.join( "src" ).join( "lib.rs" );
+  std::fs::write( &lib, "pub fn changed_after_release() {}\n" ).unwrap();
+  git( &dir, &[ "commit", "--quiet", "--all", "--message", "change" ] );
+
+  // Act
+  let archive = packed_crate::tagged( "test_experimental_c", "0.3.0", CrateDir::try_from( dir.as_path() ).unwrap() ).unwrap().unwrap();
+
+  // Assert
+  let files : Vec< _ > = archive.list().into_iter().map( | p | p.to_string_lossy().replace( '\\', "/" ) ).collect();
+  assert!( files.contains( &"test_experimental_c-0.3.0/Cargo.toml".to_string() ) );
+  assert!( files.contains( &"test_experimental_c-0.3.0/Cargo.toml.orig".to_string() ) );
+  let content = archive.content_bytes( "test_experimental_c-0.3.0/src/lib.rs" ).unwrap();
+  let content = String::from_utf8_lossy( content );
+  assert!( content.contains( "pub fn add" ) );
+  assert!( !content.contains( "changed_after_release" ) );
+}
+
+#[ test ]
+fn tagged_follows_manifest_exclude()
+{
+  // Arrange
+  let tmp = &TempDir::new().unwrap();
+  let dir = prepare( tmp );
+  let manifest = dir.join( "Cargo.toml" );
+  let content = std::fs::read_to_string( &manifest ).unwrap();
+  std::fs::write( &manifest, format!( "{content}exclude = [ \"data/*\" ]\n" ) ).unwrap();
+  std::fs::create_dir( dir.join( "data" ) ).unwrap();
+  std::fs::write( dir.join( "data" ).join( "large.bin" ), "data" ).unwrap();
+  git( &dir, &[ "add", "." ] );
+  git( &dir, &[ "commit", "--quiet", "--message", "exclude data" ] );
+  git( &dir, &[ "tag", "--annotate", "test_experimental_c-v0.3.0", "--message", "release" ] );
+
+  // Act
+  let archive = packed_crate::tagged( "test_experimental_c", "0.3.0", CrateDir::try_from( dir.as_path() ).unwrap() ).unwrap().unwrap();
+
+  // Assert
+  let files : Vec< _ > = archive.list().into_iter().map( | p | p.to_string_lossy().replace( '\\', "/" ) ).collect();
+  assert!( files.contains( &"test_experimental_c-0.3.0/src/lib.rs".to_string() ) );
+  assert!( !files.iter().any( | f | f.contains( "data/" ) ) );
+  // the manifest is normalized like the published one
+  let manifest = String::from_utf8_lossy( archive.content_bytes( "test_experimental_c-0.3.0/Cargo.toml" ).unwrap() ).to_string();
+  assert!( manifest.starts_with( "# THIS FILE IS AUTOMATICALLY GENERATED BY CARGO" ) );
+  // the worktree is removed
+  let worktrees = std::process::Command::new( "git" ).args( [ "worktree", "list" ] ).current_dir( &dir ).output().unwrap();
+  assert_eq!( 1, String::from_utf8_lossy( &worktrees.stdout ).lines().count() );
+}
+
+fn prepare( tmp : &TempDir ) -> PathBuf
+{
+  let package_path = Path::new( env!( "CARGO_MANIFEST_DIR" ) ).join( TEST_MODULE_PATH ).join( "c" );
+  tmp.copy_from( &package_path, &[ "**" ] ).unwrap();
+  let dir = tmp.to_path_buf();
+  git( &dir, &[ "init", "--quiet" ] );
+  git( &dir, &[ "add", "." ] );
+  git( &dir, &[ "commit", "--quiet", "--message", "init" ] );
+
+  dir
+}
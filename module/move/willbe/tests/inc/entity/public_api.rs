@@ -0,0 +1,140 @@
+use crate::*;
+
+use the_module::*;
+use std::path::{ Path, PathBuf };
+use assert_fs::{ TempDir, prelude::* };
+use crates_tools::CrateArchive;
+use package::Package;
+use public_api::public_api_diff;
+use version::BumpLevel;
+
+const TEST_MODULE_PATH : &str = "../../test/";
+
+#[ test ]
+fn no_api_changes()
+{
+  // Arrange
+  let tmp = &TempDir::new().unwrap();
+  let left = archive( &prepare( tmp, "left", "" ) );
+  let right = archive( &prepare( tmp, "right", "" ) );
+
+  // Act
+  let diff = public_api_diff( &left, &right );
+
+  // Assert
+  assert!( diff.is_empty() );
+  assert_eq!( BumpLevel::Patch, diff.level() );
+}
+
+#[ test ]
+fn addition_is_minor()
+{
+  // Arrange
+  let tmp = &TempDir::new().unwrap();
+  let left = archive( &prepare( tmp, "left", "\npub fn mul( left : usize, right : usize ) -> usize { left * right }\n" ) );
+  let right = archive( &prepare( tmp, "right", "" ) );
+
+  // Act
+  let diff = public_api_diff( &left, &right );
+
+  // Assert
+  assert_eq!( vec![ "crate::mul".to_string() ], diff.added );
+  assert_eq!( BumpLevel::Minor, diff.level() );
+}
+
+#[ test ]
+fn signature_change_is_major()
+{
+  // Arrange
+  let tmp = &TempDir::new().unwrap();
+  let left_dir = prepare( tmp, "left", "" );
+  let lib = left_dir.join( "src" ).join( "lib.rs" );
+  let content = std::fs::read_to_string( &lib ).unwrap().replace( "pub fn sub( left : usize, right : usize )", "pub fn sub( left : u64, right : u64 )" );
+  std::fs::write( &lib, content.replace( "-> usize\n{\n  left - right", "-> u64\n{\n  left - right" ) ).unwrap();
+  let left = archive( &left_dir );
+  let right = archive( &prepare( tmp, "right", "" ) );
+
+  // Act
+  let diff = public_api_diff( &left, &right );
+
+  // Assert
+  assert_eq!( vec![ "crate::sub".to_string() ], diff.changed );
+  assert_eq!( BumpLevel::Major, diff.level() );
+}
+
+#[ test ]
+fn feature_removal_is_major()
+{
+  // Arrange
+  let tmp = &TempDir::new().unwrap();
+  let features = | dir : &Path, features : &str |
+  {
+    let manifest = dir.join( "Cargo.toml" );
+    let content = std::fs::read_to_string( &manifest ).unwrap();
+    std::fs::write( &manifest, format!( "{content}\n[features]\n{features}" ) ).unwrap();
+  };
+  let left_dir = prepare( tmp, "left", "" );
+  features( &left_dir, "default = []\nfull = []\n" );
+  let right_dir = prepare( tmp, "right", "" );
+  features( &right_dir, "default = []\nenabled = []\n" );
+
+  // Act
+  let diff = public_api_diff( &archive( &left_dir ), &archive( &right_dir ) );
+
+  // Assert
+  assert_eq!( vec![ "feature `enabled`".to_string() ], diff.removed );
+  assert_eq!( vec![ "feature `full`".to_string() ], diff.added );
+  assert_eq!( BumpLevel::Major, diff.level() );
+}
+
+#[ test ]
+fn feature_content_change_is_major()
+{
+  // Arrange
+  let tmp = &TempDir::new().unwrap();
+  let features = | dir : &Path, features : &str |
+  {
+    let manifest = dir.join( "Cargo.toml" );
+    let content = std::fs::read_to_string( &manifest ).unwrap();
+    std::fs::write( &manifest, format!( "{content}\n[features]\n{features}" ) ).unwrap();
+  };
+  let left_dir = prepare( tmp, "left", "" );
+  features( &left_dir, "default = [ \"enabled\" ]\nenabled = []\nfull = [ \"enabled\" ]\n" );
+  let right_dir = prepare( tmp, "right", "" );
+  features( &right_dir, "default = [ \"full\" ]\nenabled = []\nfull = [ \"enabled\" ]\n" );
+
+  // Act
+  let diff = public_api_diff( &archive( &left_dir ), &archive( &right_dir ) );
+
+  // Assert
+  assert_eq!( vec![ "feature `default`".to_string() ], diff.changed );
+  assert!( diff.added.is_empty() && diff.removed.is_empty() );
+  assert_eq!( BumpLevel::Major, diff.level() );
+}
+
+fn prepare( tmp : &TempDir, name : &str, extra_code : &str ) -> PathBuf
+{
+  let package_path = Path::new( env!( "CARGO_MANIFEST_DIR" ) ).join( TEST_MODULE_PATH ).join( "c" );
+  let dir = tmp.child( name );
+  dir.create_dir_all().unwrap();
+  dir.copy_from( &package_path, &[ "**" ] ).unwrap();
+  let lib = dir.join( "src" ).join( "lib.rs" );
+  let content = std::fs::read_to_string( &lib ).unwrap();
+  std::fs::write( &lib, format!( "{content}{extra_code}" ) ).unwrap();
+
+  dir.to_path_buf()
+}
+
+fn archive( manifest_dir_path : &Path ) -> CrateArchive
+{
+  _ = cargo::pack( cargo::PackOptions::former().path( manifest_dir_path ).dry( false ).form() ).expect( "Failed to package a package" );
+
+  let absolute = CrateDir::try_from( manifest_dir_path ).unwrap();
+  let package = Package::try_from( absolute ).unwrap();
+  let path = manifest_dir_path
+  .join( "target" )
+  .join( "package" )
+  .join( format!( "{}-{}.crate", package.name().unwrap(), package.version().unwrap() ) );
+
+  CrateArchive::read( path ).unwrap()
+}
@@ -0,0 +1,205 @@
+use crate::*;
+
+use the_module::*;
+use inc::helper::{ ProjectBuilder, WorkspaceBuilder, git };
+use assert_fs::TempDir;
+use publish_journal::{ JournalEntry, PublishJournal, PublishStage };
+
+fn workspace( tmp : &TempDir ) -> std::path::PathBuf
+{
+  let path = WorkspaceBuilder::new()
+  .member( ProjectBuilder::new( "a" ).toml_file( "" ) )
+  .member( ProjectBuilder::new( "b" ).toml_file( "[dependencies]\na = { path = \"../a\" }" ) )
+  .build( tmp );
+  git( &path, &[ "init", "--quiet" ] );
+  git( &path, &[ "add", "." ] );
+  git( &path, &[ "commit", "--quiet", "--message", "init" ] );
+
+  path
+}
+
+fn head( path : &std::path::Path ) -> String
+{
+  let output = std::process::Command::new( "git" ).args([ "rev-parse", "HEAD" ]).current_dir( path ).output().unwrap();
+  String::from_utf8( output.stdout ).unwrap().trim().to_string()
+}
+
+fn entry( name : &str, stage : PublishStage, commit : Option< String > ) -> JournalEntry
+{
+  JournalEntry
+  {
+    name : name.into(),
+    crate_dir : format!( "modules/{name}" ).into(),
+    old_version : "0.1.0".into(),
+    new_version : "0.2.0".into(),
+    stage,
+    commit,
+  }
+}
+
+/// Commits a bump of the package as `.publish` does and records it in the journal.
+fn bump_commit( path : &std::path::Path, journal : &mut PublishJournal, name : &str )
+{
+  let manifest = path.join( "modules" ).join( name ).join( "Cargo.toml" );
+  let content = std::fs::read_to_string( &manifest ).unwrap().replace( "0.1.0", "0.2.0" );
+  std::fs::write( &manifest, content ).unwrap();
+  git( path, &[ "commit", "--quiet", "--all", "--message", &format!( "{name}-v0.2.0" ) ] );
+  journal.commit_set( name, head( path ) ).unwrap();
+}
+
+#[ test ]
+fn store_and_load()
+{
+  // Arrange
+  let tmp = TempDir::new().unwrap();
+  let file = tmp.path().join( "target" ).join( publish_journal::JOURNAL_FILE );
+  let mut journal = PublishJournal::new( &file, tmp.path() );
+  journal.entries.push( entry( "a", PublishStage::Planned, None ) );
+  journal.entries.push( entry( "b", PublishStage::Planned, None ) );
+
+  // Act
+  journal.commit_set( "a", "abc".into() ).unwrap();
+  journal.stage_set( "a", PublishStage::Published ).unwrap();
+  let loaded = PublishJournal::load( &file ).unwrap().unwrap();
+
+  // Assert
+  assert_eq!( loaded.entries, vec![ entry( "a", PublishStage::Published, Some( "abc".into() ) ), entry( "b", PublishStage::Planned, None ) ] );
+  assert_eq!( PublishStage::Planned, loaded.stage( "c" ) );
+  assert_eq!( vec![ "a", "b" ], loaded.unfinished().map( | e | e.name.as_str() ).collect::< Vec< _ > >() );
+  assert!( journal.stage_set( "c", PublishStage::Pushed ).is_err() );
+
+  // Act
+  loaded.remove().unwrap();
+
+  // Assert
+  assert!( PublishJournal::load( &file ).unwrap().is_none() );
+}
+
+#[ test ]
+fn rollback_resets_unpublished_commits()
+{
+  // Arrange
+  let tmp = TempDir::new().unwrap();
+  let path = workspace( &tmp );
+  let init = head( &path );
+  let mut journal = PublishJournal::new( path.join( "target" ).join( publish_journal::JOURNAL_FILE ), &path );
+  journal.entries.push( entry( "a", PublishStage::Planned, None ) );
+  journal.entries.push( entry( "b", PublishStage::Planned, None ) );
+  bump_commit( &path, &mut journal, "a" );
+  bump_commit( &path, &mut journal, "b" );
+  let bumped = head( &path );
+
+  // Act
+  let reports = journal.rollback( true ).unwrap();
+
+  // Assert
+  assert_eq!( 2, reports.len() );
+  assert_eq!( "git reset --hard HEAD~1", reports[ 0 ].command );
+  assert_eq!( bumped, head( &path ) );
+  assert_eq!( PublishStage::Committed, journal.stage( "a" ) );
+
+  // Act
+  let crate_dir = CrateDir::try_from( path.clone() ).unwrap();
+  let report = action::publish_rollback( action::PublishRollbackOptions::former().crate_dir( crate_dir ).dry( false ).form() ).unwrap();
+
+  // Assert
+  assert_eq!( init, head( &path ) );
+  assert_eq!( vec![ "a", "b" ], report.rolled_back.iter().map( | e | e.name.as_str() ).collect::< Vec< _ > >() );
+  assert!( std::fs::read_to_string( path.join( "modules/a/Cargo.toml" ) ).unwrap().contains( "0.1.0" ) );
+  // nothing reached the registry, so there is nothing to resume
+  assert!( PublishJournal::load( journal.path() ).unwrap().is_none() );
+}
+
+#[ test ]
+fn rollback_keeps_published()
+{
+  // Arrange
+  let tmp = TempDir::new().unwrap();
+  let path = workspace( &tmp );
+  let mut journal = PublishJournal::new( path.join( "target" ).join( publish_journal::JOURNAL_FILE ), &path );
+  journal.entries.push( entry( "a", PublishStage::Planned, None ) );
+  journal.entries.push( entry( "b", PublishStage::Planned, None ) );
+  bump_commit( &path, &mut journal, "a" );
+  journal.stage_set( "a", PublishStage::Published ).unwrap();
+  let published = head( &path );
+  bump_commit( &path, &mut journal, "b" );
+
+  // Act
+  let report = action::publish_rollback( action::PublishRollbackOptions::former().crate_dir( CrateDir::try_from( path.clone() ).unwrap() ).dry( false ).form() ).unwrap();
+
+  // Assert
+  assert_eq!( published, head( &path ) );
+  assert_eq!( vec![ "a" ], report.published.iter().map( | e | e.name.as_str() ).collect::< Vec< _ > >() );
+  let journal = PublishJournal::load( journal.path() ).unwrap().unwrap();
+  assert_eq!( PublishStage::Published, journal.stage( "a" ) );
+  assert_eq!( PublishStage::Planned, journal.stage( "b" ) );
+}
+
+#[ test ]
+// packages published at the same time commit in the order they finish, not in the order of the journal
+fn rollback_interleaved_commits()
+{
+  // Arrange
+  let tmp = TempDir::new().unwrap();
+  let path = workspace( &tmp );
+  let init = head( &path );
+  let mut journal = PublishJournal::new( path.join( "target" ).join( publish_journal::JOURNAL_FILE ), &path );
+  journal.entries.push( entry( "a", PublishStage::Planned, None ) );
+  journal.entries.push( entry( "b", PublishStage::Planned, None ) );
+  bump_commit( &path, &mut journal, "b" );
+  bump_commit( &path, &mut journal, "a" );
+
+  // Act
+  let reports = journal.rollback( false ).unwrap();
+
+  // Assert
+  assert_eq!( 2, reports.len() );
+  assert_eq!( init, head( &path ) );
+  assert_eq!( PublishStage::Planned, journal.stage( "a" ) );
+  assert_eq!( PublishStage::Planned, journal.stage( "b" ) );
+}
+
+#[ test ]
+fn rollback_keeps_published_inside_of_block()
+{
+  // Arrange
+  let tmp = TempDir::new().unwrap();
+  let path = workspace( &tmp );
+  let mut journal = PublishJournal::new( path.join( "target" ).join( publish_journal::JOURNAL_FILE ), &path );
+  journal.entries.push( entry( "a", PublishStage::Planned, None ) );
+  journal.entries.push( entry( "b", PublishStage::Planned, None ) );
+  bump_commit( &path, &mut journal, "b" );
+  bump_commit( &path, &mut journal, "a" );
+  journal.stage_set( "a", PublishStage::Published ).unwrap();
+  let published = head( &path );
+
+  // Act
+  let e = journal.rollback( false ).unwrap_err();
+
+  // Assert
+  assert!( e.to_string().contains( "Commits of `b` are not on top of the branch" ) );
+  assert_eq!( published, head( &path ) );
+  assert_eq!( PublishStage::Committed, journal.stage( "b" ) );
+}
+
+#[ test ]
+fn rollback_requires_commit_on_top()
+{
+  // Arrange
+  let tmp = TempDir::new().unwrap();
+  let path = workspace( &tmp );
+  let mut journal = PublishJournal::new( path.join( "target" ).join( publish_journal::JOURNAL_FILE ), &path );
+  journal.entries.push( entry( "a", PublishStage::Planned, None ) );
+  bump_commit( &path, &mut journal, "a" );
+  std::fs::write( path.join( "modules/a/src/lib.rs" ), "pub fn f() {}\n" ).unwrap();
+  git( &path, &[ "commit", "--quiet", "--all", "--message", "unrelated" ] );
+  let unrelated = head( &path );
+
+  // Act
+  let e = journal.rollback( false ).unwrap_err();
+
+  // Assert
+  assert!( e.to_string().contains( "not on top of the branch" ) );
+  assert_eq!( unrelated, head( &path ) );
+  assert_eq!( PublishStage::Committed, journal.stage( "a" ) );
+}
@@ -0,0 +1,90 @@
+use crate::*;
+
+use the_module::*;
+use std::path::Path;
+use assert_fs::{ TempDir, prelude::* };
+use registry::{ Registry, RegistryError };
+
+const TEST_MODULE_PATH : &str = "../../test/";
+
+#[ test ]
+fn local_registry_archive()
+{
+  // Arrange
+  let tmp = &TempDir::new().unwrap();
+  let package_dir = tmp.child( "c" );
+  package_dir.copy_from( Path::new( env!( "CARGO_MANIFEST_DIR" ) ).join( TEST_MODULE_PATH ).join( "c" ), &[ "**" ] ).unwrap();
+  _ = cargo::pack( cargo::PackOptions::former().path( package_dir.path() ).dry( false ).form() ).unwrap();
+  let registry_dir = tmp.child( "registry" );
+  registry_dir.create_dir_all().unwrap();
+  let file = "test_experimental_c-0.3.0.crate";
+  std::fs::copy( package_dir.join( "target" ).join( "package" ).join( file ), registry_dir.join( file ) ).unwrap();
+  let registry = Registry::Local( registry_dir.to_path_buf() );
+
+  // Act
+  let archive = registry.archive( "test_experimental_c", "0.3.0" ).unwrap();
+
+  // Assert
+  assert!( archive.content_bytes( "test_experimental_c-0.3.0/src/lib.rs" ).is_some() );
+}
+
+#[ test ]
+fn local_registry_not_found()
+{
+  // Arrange
+  let tmp = &TempDir::new().unwrap();
+  let registry = Registry::Local( tmp.to_path_buf() );
+
+  // Act
+  let result = registry.download( "test_experimental_c", "0.3.0" );
+
+  // Assert
+  assert!( matches!( result, Err( RegistryError::NotFound( .. ) ) ) );
+}
+
+#[ test ]
+fn sparse_registry_url()
+{
+  // Act
+  let registry = Registry::sparse( "sparse+https://registry.example.com/index" ).unwrap();
+
+  // Assert
+  assert_eq!( Registry::Sparse( "https://registry.example.com/index/".into() ), registry );
+  assert!( Registry::sparse( "registry.example.com/index" ).is_err() );
+}
+
+#[ test ]
+// packages are uploaded to a registry with a sparse index, a local directory can not be uploaded to
+fn upload_registry()
+{
+  // Arrange
+  let sparse = Registry::sparse( "https://registry.example.com/index" ).unwrap();
+  let local = Registry::Local( "registry".into() );
+
+  // Act
+  let registries = [ &sparse, &local, &Registry::CratesIo ].map( Registry::upload_registry );
+  let indices = [ &sparse, &local, &Registry::CratesIo ].map( Registry::upload_index );
+
+  // Assert
+  assert_eq!( [ sparse.clone(), Registry::CratesIo, Registry::CratesIo ], registries );
+  assert_eq!( [ Some( "sparse+https://registry.example.com/index/".to_string() ), None, None ], indices );
+}
+
+#[ test ]
+fn local_registry_indexed()
+{
+  // Arrange
+  let tmp = &TempDir::new().unwrap();
+  let registry = Registry::Local( tmp.to_path_buf() );
+  tmp.child( "a-0.1.0.crate" ).write_binary( b"" ).unwrap();
+
+  // Act
+  let indexed = registry.indexed( "a", "0.1.0" ).unwrap();
+  let not_indexed = registry.indexed( "a", "0.2.0" ).unwrap();
+
+  // Assert
+  assert!( indexed );
+  assert!( !not_indexed );
+  // a local directory is not waited for
+  assert!( registry.index_wait( "a", "0.2.0", std::time::Duration::ZERO ).is_ok() );
+}
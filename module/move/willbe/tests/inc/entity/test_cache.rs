@@ -0,0 +1,102 @@
+use crate::*;
+
+use the_module::*;
+use inc::helper::{ ProjectBuilder, WorkspaceBuilder };
+use assert_fs::TempDir;
+use collection::{ BTreeSet, HashSet };
+use channel::Channel;
+use optimization::Optimization;
+use test::TestVariant;
+use test_cache::TestCache;
+
+fn variant() -> TestVariant
+{
+  variant_of( Channel::Stable )
+}
+
+fn variant_of( channel : Channel ) -> TestVariant
+{
+  TestVariant::former().optimization( Optimization::Debug ).channel( channel ).features( BTreeSet::default() ).form()
+}
+
+fn keys( path : &std::path::Path ) -> ( TestCache, String, String )
+{
+  let workspace = Workspace::try_from( CrateDir::try_from( path ).unwrap() ).unwrap();
+  let cache = TestCache::new( &workspace, workspace.packages(), &HashSet::from([ Channel::Stable ]) ).unwrap();
+  let a = cache.key( &CrateDir::try_from( path.join( "modules/a" ) ).unwrap(), &variant() ).unwrap();
+  let b = cache.key( &CrateDir::try_from( path.join( "modules/b" ) ).unwrap(), &variant() ).unwrap();
+
+  ( cache, a, b )
+}
+
+#[ test ]
+fn key_changes_with_sources_of_dependencies()
+{
+  // Arrange
+  let tmp = TempDir::new().unwrap();
+  let path = WorkspaceBuilder::new()
+  .member( ProjectBuilder::new( "a" ).toml_file( "[dependencies]\nb = { path = \"../b\" }" ) )
+  .member( ProjectBuilder::new( "b" ).toml_file( "" ) )
+  .build( &tmp );
+  let ( _, a_before, b_before ) = keys( &path );
+
+  // Act
+  let ( _, a_same, b_same ) = keys( &path );
+  std::fs::write( path.join( "modules/b/src/lib.rs" ), "pub fn f() {}\n" ).unwrap();
+  let ( _, a_after, b_after ) = keys( &path );
+
+  // Assert
+  assert_eq!( a_before, a_same );
+  assert_eq!( b_before, b_same );
+  assert_ne!( a_before, a_after );
+  assert_ne!( b_before, b_after );
+}
+
+#[ test ]
+fn key_requires_toolchain_of_channel()
+{
+  // Arrange
+  let tmp = TempDir::new().unwrap();
+  let path = WorkspaceBuilder::new()
+  .member( ProjectBuilder::new( "a" ).toml_file( "" ) )
+  .member( ProjectBuilder::new( "b" ).toml_file( "" ) )
+  .build( &tmp );
+  let ( cache, _, _ ) = keys( &path );
+  let a = CrateDir::try_from( path.join( "modules/a" ) ).unwrap();
+
+  // Act
+  let stable = cache.key( &a, &variant_of( Channel::Stable ) );
+  let nightly = cache.key( &a, &variant_of( Channel::Nightly ) );
+
+  // Assert
+  assert!( stable.is_some() );
+  assert!( nightly.is_none() );
+}
+
+#[ test ]
+fn insert_and_prune()
+{
+  // Arrange
+  let tmp = TempDir::new().unwrap();
+  let path = WorkspaceBuilder::new()
+  .member( ProjectBuilder::new( "a" ).toml_file( "" ) )
+  .member( ProjectBuilder::new( "b" ).toml_file( "" ) )
+  .build( &tmp );
+  let ( cache, a, _ ) = keys( &path );
+  let workspace = Workspace::try_from( CrateDir::try_from( path.as_path() ).unwrap() ).unwrap();
+  let dir = TestCache::dir( &workspace );
+
+  // Act
+  let missed = cache.contains( &a );
+  cache.insert( &a, &variant() ).unwrap();
+  let stored = cache.contains( &a );
+  let kept = test_cache::prune( &dir, std::time::Duration::from_secs( 60 * 60 ) ).unwrap();
+  let removed = test_cache::prune( &dir, std::time::Duration::ZERO ).unwrap();
+
+  // Assert
+  assert!( !missed );
+  assert!( stored );
+  assert_eq!( ( 0, 1 ), ( kept.removed, kept.kept ) );
+  assert_eq!( ( 1, 0 ), ( removed.removed, removed.kept ) );
+  assert!( !cache.contains( &a ) );
+}
@@ -11,7 +11,7 @@ use the_module::
   version::Version,
   path::AbsolutePath,
   package::Package,
-  version::{ BumpOptions, bump, revert },
+  version::{ BumpOptions, BumpLevel, bump, revert },
 };
 
 const TEST_MODULE_PATH : &str = "../../test/";
@@ -87,6 +87,71 @@ fn major_with_minor()
   assert_eq!( "1.2.0", &new_version.to_string() );
 }
 
+#[ test ]
+fn bump_with_level_zero_zero()
+{
+  // Arrange
+  let version = Version::from_str( "0.0.1" ).unwrap();
+
+  // Act
+  let major = version.clone().bump_with( BumpLevel::Major );
+  let patch = version.bump_with( BumpLevel::Patch );
+
+  // Assert
+  assert_eq!( "0.0.2", &major.to_string() );
+  assert_eq!( "0.0.2", &patch.to_string() );
+}
+
+#[ test ]
+fn bump_with_level_zero_minor()
+{
+  // Arrange
+  let version = Version::from_str( "0.3.1" ).unwrap();
+
+  // Act
+  let major = version.clone().bump_with( BumpLevel::Major );
+  let minor = version.clone().bump_with( BumpLevel::Minor );
+  let patch = version.bump_with( BumpLevel::Patch );
+
+  // Assert
+  assert_eq!( "0.4.0", &major.to_string() );
+  assert_eq!( "0.3.2", &minor.to_string() );
+  assert_eq!( "0.3.2", &patch.to_string() );
+}
+
+#[ test ]
+fn bump_with_level_stable()
+{
+  // Arrange
+  let version = Version::from_str( "1.2.3" ).unwrap();
+
+  // Act
+  let major = version.clone().bump_with( BumpLevel::Major );
+  let minor = version.clone().bump_with( BumpLevel::Minor );
+  let patch = version.bump_with( BumpLevel::Patch );
+
+  // Assert
+  assert_eq!( "2.0.0", &major.to_string() );
+  assert_eq!( "1.3.0", &minor.to_string() );
+  assert_eq!( "1.2.4", &patch.to_string() );
+}
+
+#[ test ]
+fn level_allowed()
+{
+  // Arrange
+  let version = | v : &str | Version::from_str( v ).unwrap();
+
+  // Act & Assert
+  assert_eq!( Some( BumpLevel::Major ), version( "0.0.1" ).level_allowed( &version( "0.0.2" ) ) );
+  assert_eq!( Some( BumpLevel::Major ), version( "0.3.1" ).level_allowed( &version( "0.4.0" ) ) );
+  assert_eq!( Some( BumpLevel::Minor ), version( "0.3.1" ).level_allowed( &version( "0.3.2" ) ) );
+  assert_eq!( Some( BumpLevel::Major ), version( "1.2.3" ).level_allowed( &version( "2.0.0" ) ) );
+  assert_eq!( Some( BumpLevel::Minor ), version( "1.2.3" ).level_allowed( &version( "1.3.0" ) ) );
+  assert_eq!( Some( BumpLevel::Patch ), version( "1.2.3" ).level_allowed( &version( "1.2.4" ) ) );
+  assert_eq!( None, version( "1.2.3" ).level_allowed( &version( "1.2.3" ) ) );
+}
+
 #[ test ]
 fn major_with_patches()
 {
@@ -139,6 +204,8 @@ default-features = true
     crate_dir : c_temp_crate_dir.clone(),
     old_version : version.clone(),
     new_version : bumped_version.clone(),
+    level : None,
+    reason : None,
     dependencies : vec![ root_manifest_dir_absolute_path.clone() ],
     dry : false,
   };
@@ -210,6 +277,8 @@ default-features = true
     crate_dir : c_temp_crate_dir.clone(),
     old_version : version.clone(),
     new_version : bumped_version.clone(),
+    level : None,
+    reason : None,
     dependencies : vec![ root_manifest_dir_absolute_path.clone() ],
     dry : false,
   };
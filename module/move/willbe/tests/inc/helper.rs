@@ -5,6 +5,7 @@ use std::
 {
   fs::{ self, File },
   io::Write,
+  process::Command,
 };
 
 pub const BINARY_NAME : &'static str = "will";
@@ -114,3 +115,15 @@ impl WorkspaceBuilder
     project_path.into()
   }
 }
+
+/// Runs git in the directory with a fixed identity and without signing, panics on failure.
+pub fn git< P : AsRef< Path > >( dir : P, args : &[ &str ] )
+{
+  let status = Command::new( "git" )
+  .args([ "-c", "user.name=test", "-c", "user.email=test@example.com", "-c", "commit.gpgsign=false", "-c", "tag.gpgsign=false" ])
+  .args( args )
+  .current_dir( dir.as_ref() )
+  .status()
+  .unwrap();
+  assert!( status.success() );
+}
@@ -1,4 +1,6 @@
 use super::*;
 
 pub mod graph_test;
+pub mod output_test;
 pub mod query_test;
+pub mod url_test;
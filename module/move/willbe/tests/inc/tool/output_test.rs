@@ -0,0 +1,100 @@
+use super::*;
+use the_module::output::
+{
+  OutputFormat,
+  output_format,
+};
+use the_module::action::list::ListReport;
+use std::{ collections::HashMap, str::FromStr };
+
+#[ test ]
+fn output_format_from_str()
+{
+  assert_eq!( OutputFormat::from_str( "text" ).unwrap(), OutputFormat::Text );
+  assert_eq!( OutputFormat::from_str( "json" ).unwrap(), OutputFormat::Json );
+  assert_eq!( OutputFormat::from_str( "toml" ).unwrap(), OutputFormat::Toml );
+  assert!( OutputFormat::from_str( "yaml" ).is_err() );
+}
+
+#[ test ]
+fn output_format_from_props()
+{
+  // Arrange
+  let props = wca::Props( HashMap::from([ ( "output".to_string(), wca::Value::String( "json".into() ) ) ]) );
+
+  // Act
+  let format = output_format( &props ).unwrap();
+
+  // Assert
+  assert_eq!( OutputFormat::Json, format );
+}
+
+#[ test ]
+fn output_format_default()
+{
+  // Arrange
+  let props = wca::Props( HashMap::new() );
+
+  // Act
+  let format = output_format( &props ).unwrap();
+
+  // Assert
+  assert_eq!( OutputFormat::Text, format );
+}
+
+#[ test ]
+fn output_property_is_validated()
+{
+  // Arrange
+  let ca = the_module::command::ca().perform();
+
+  // Act
+  let result = ca.perform( ".publish.check output:yaml" );
+
+  // Assert
+  assert!( result.is_err() );
+}
+
+#[ test ]
+fn list_report_json()
+{
+  // Arrange
+  let report = ListReport::List( vec![ "a".to_string(), "b".to_string() ] );
+
+  // Act
+  let json = serde_json::to_value( &report ).unwrap();
+
+  // Assert
+  assert_eq!( serde_json::json!( { "list" : [ "a", "b" ] } ), json );
+}
+
+#[ test ]
+fn error_report_json()
+{
+  // Arrange
+  let error = the_module::error::untyped::format_err!( "Failed to find workspace" );
+  let report = the_module::output::ErrorReport::from( &error );
+
+  // Act
+  let json = serde_json::to_value( &report ).unwrap();
+
+  // Assert
+  assert_eq!( "Failed to find workspace", report.to_string() );
+  assert_eq!( serde_json::json!( { "error" : "Failed to find workspace" } ), json );
+}
+
+#[ test ]
+fn headers_renew_reports_json()
+{
+  // Arrange
+  let main = the_module::action::MainHeaderRenewReport::default();
+  let modules = the_module::action::ModulesHeadersRenewReport::default();
+
+  // Act
+  let main = serde_json::to_value( &main ).unwrap();
+  let modules = serde_json::to_value( &modules ).unwrap();
+
+  // Assert
+  assert_eq!( Some( &serde_json::json!( false ) ), main.get( "success" ) );
+  assert_eq!( Some( &serde_json::json!( [] ) ), modules.get( "touched_files" ) );
+}
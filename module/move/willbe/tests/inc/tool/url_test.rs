@@ -0,0 +1,29 @@
+use super::*;
+use the_module::url;
+
+#[ test ]
+fn repo_url_extract()
+{
+  assert_eq!( url::repo_url_extract( "https://github.com/Wandalen/wTools/tree/master/module" ), Some( "https://github.com/Wandalen/wTools".into() ) );
+  assert_eq!( url::repo_url_extract( "https://gitlab.example.com/group/subgroup/repo/-/tree/main" ), Some( "https://gitlab.example.com/group/subgroup/repo".into() ) );
+  assert_eq!( url::repo_url_extract( "https://git.example.com:8443/team/repo.git" ), Some( "https://git.example.com:8443/team/repo".into() ) );
+  assert_eq!( url::repo_url_extract( "git@gitlab.example.com:group/repo.git" ), Some( "https://gitlab.example.com/group/repo".into() ) );
+  assert_eq!( url::repo_url_extract( "ssh://git@gitlab.example.com:22/group/repo.git" ), Some( "https://gitlab.example.com/group/repo".into() ) );
+  assert_eq!( url::repo_url_extract( "https://github.com/Wandalen" ), None );
+  assert_eq!( url::repo_url_extract( "not an url" ), None );
+}
+
+#[ test ]
+fn git_info_extract()
+{
+  assert_eq!( url::git_info_extract( &"https://github.com/Wandalen/wTools".into() ).unwrap(), "Wandalen/wTools" );
+  assert!( url::git_info_extract( &"https://gitlab.example.com/group/subgroup/repo".into() ).is_err() );
+}
+
+#[ test ]
+fn repo_path_extract()
+{
+  assert_eq!( url::repo_path_extract( "https://github.com/Wandalen/wTools/tree/master" ), Some( "Wandalen/wTools".into() ) );
+  assert_eq!( url::repo_path_extract( "git@gitlab.example.com:group/subgroup/repo.git" ), Some( "group/subgroup/repo".into() ) );
+  assert_eq!( url::repo_path_extract( "not an url" ), None );
+}